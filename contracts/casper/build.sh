@@ -0,0 +1,52 @@
+#!/bin/bash
+
+# Casper Bridge Reproducible Build
+# Builds the vault and session wasm with pinned toolchain, locked dependencies
+# and host paths remapped, then prints each artifact's blake2b-256 hash so
+# anyone can check a deployed module byte-for-byte against the source.
+
+set -e
+
+cd "$(dirname "$0")"
+ROOT="$(pwd)"
+TARGET="wasm32-unknown-unknown"
+OUT_DIR="$ROOT/wasm"
+
+# Keep local paths out of panic strings embedded in the wasm
+export RUSTFLAGS="--remap-path-prefix=$ROOT=/build --remap-path-prefix=$HOME/.cargo=/cargo"
+export CARGO_INCREMENTAL=0
+export SOURCE_DATE_EPOCH=0
+
+CRATES=(
+  ".:casper_bridge_vault"
+  "session/lock_cspr_session:lock_cspr_session"
+  "session/claim_session:claim_session"
+)
+
+echo "====================================="
+echo "Casper Bridge Reproducible Build"
+echo "====================================="
+echo "Toolchain: $(cat rust-toolchain)"
+echo ""
+
+mkdir -p "$OUT_DIR"
+
+for entry in "${CRATES[@]}"; do
+  dir="${entry%%:*}"
+  name="${entry##*:}"
+
+  echo "Building $name..."
+  (cd "$dir" && cargo build --release --locked --target "$TARGET" -q)
+
+  # Hash cargo's output as is: post-processing such as wasm-strip depends on
+  # what the host has installed and would make hashes differ between machines
+  cp "$dir/target/$TARGET/release/$name.wasm" "$OUT_DIR/$name.wasm"
+
+  hash=$(b2sum -l 256 "$OUT_DIR/$name.wasm" | cut -d' ' -f1)
+  size=$(wc -c < "$OUT_DIR/$name.wasm")
+  echo "  $name.wasm  $size bytes"
+  echo "  blake2b-256: $hash"
+done
+
+echo ""
+echo "Artifacts written to $OUT_DIR"
@@ -33,7 +33,8 @@ if [ ! -f "$SECRET_KEY" ]; then
     exit 1
 fi
 
-# Deploy the contract
+# Deploy the contract (the installer runs init with these args; add an
+# Option<List<Key>> "validators" arg via --session-args-json to seed more than the deployer)
 echo "Deploying contract..."
 casper-client put-deploy \
   --node-address "$NODE_ADDRESS" \
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+
+// Session code for claiming funds owed by the bridge vault.
+//
+// Calls the vault's claim entry point from the account context, so the vault
+// pays the claiming account directly.
+
+extern crate alloc;
+
+use alloc::string::String;
+use casper_contract::contract_api::runtime;
+use casper_types::{contracts::ContractHash, RuntimeArgs};
+
+const ENTRY_POINT_CLAIM_RELAYER_REWARD: &str = "claim_relayer_reward";
+const ENTRY_POINT_CLAIM_GAS_REFUND: &str = "claim_gas_refund";
+const ENTRY_POINT_CLAIM_DUST: &str = "claim_dust";
+const ENTRY_POINT_CLAIM_SUBMISSION_BOND: &str = "claim_submission_bond";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let vault_contract_hash: [u8; 32] = runtime::get_named_arg("vault_contract_hash");
+    // Optional: "claim_relayer_reward" (default), "claim_gas_refund", "claim_dust" or
+    // "claim_submission_bond"
+    let entry_point: String = runtime::try_get_named_arg("entry_point")
+        .unwrap_or_else(|| String::from(ENTRY_POINT_CLAIM_RELAYER_REWARD));
+
+    if ![
+        ENTRY_POINT_CLAIM_RELAYER_REWARD,
+        ENTRY_POINT_CLAIM_GAS_REFUND,
+        ENTRY_POINT_CLAIM_DUST,
+        ENTRY_POINT_CLAIM_SUBMISSION_BOND,
+    ]
+    .contains(&entry_point.as_str())
+    {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    runtime::call_contract::<()>(
+        ContractHash::new(vault_contract_hash),
+        &entry_point,
+        RuntimeArgs::new(),
+    );
+}
@@ -0,0 +1,78 @@
+#![no_std]
+#![no_main]
+
+// Session code for locking CSPR in the bridge vault.
+//
+// lock_cspr pulls motes from a purse passed by the caller, so this session
+// creates a fresh purse, funds it from the account's main purse, and hands it
+// to the vault together with the destination details. With a `route_id` arg
+// the destination comes from a deposit route registered on the vault instead.
+// With `deposit_bond` set, the purse funds the caller's release submission bond.
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::ContractHash, runtime_args, U512};
+
+const ENTRY_POINT_LOCK_CSPR: &str = "lock_cspr";
+const ENTRY_POINT_LOCK_TO_ROUTE: &str = "lock_to_route";
+const ENTRY_POINT_DEPOSIT_SUBMISSION_BOND: &str = "deposit_submission_bond";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let vault_contract_hash: [u8; 32] = runtime::get_named_arg("vault_contract_hash");
+    let amount: U512 = runtime::get_named_arg("amount");
+    let route_id: Option<u64> = runtime::try_get_named_arg("route_id");
+    let deposit_bond: bool = runtime::try_get_named_arg("deposit_bond").unwrap_or(false);
+
+    // Move the locked amount into a purse the vault can draw from
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount, None)
+        .unwrap_or_revert();
+
+    if deposit_bond {
+        runtime::call_contract::<()>(
+            ContractHash::new(vault_contract_hash),
+            ENTRY_POINT_DEPOSIT_SUBMISSION_BOND,
+            runtime_args! {
+                "amount" => amount,
+                "purse" => purse,
+            },
+        );
+        return;
+    }
+
+    if let Some(route_id) = route_id {
+        runtime::call_contract::<()>(
+            ContractHash::new(vault_contract_hash),
+            ENTRY_POINT_LOCK_TO_ROUTE,
+            runtime_args! {
+                "route_id" => route_id,
+                "amount" => amount,
+                "purse" => purse,
+            },
+        );
+        return;
+    }
+
+    let destination_chain: String = runtime::get_named_arg("destination_chain");
+    let destination_address: String = runtime::get_named_arg("destination_address");
+    // Optional onward hops as (chain, address) pairs
+    let route: Vec<(String, String)> = runtime::try_get_named_arg("route").unwrap_or_default();
+
+    runtime::call_contract::<()>(
+        ContractHash::new(vault_contract_hash),
+        ENTRY_POINT_LOCK_CSPR,
+        runtime_args! {
+            "destination_chain" => destination_chain,
+            "destination_address" => destination_address,
+            "route" => route,
+            "amount" => amount,
+            "purse" => purse,
+        },
+    );
+}
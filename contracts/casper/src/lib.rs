@@ -1,22 +1,28 @@
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 
 extern crate alloc;
 
 use alloc::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
 use casper_contract::{
-    contract_api::{runtime, storage, system},
+    contract_api::{cryptography, runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     addressable_entity::{EntityEntryPoint as EntryPoint, EntryPoints},
-    bytesrepr::{FromBytes, ToBytes},
-    CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment, EntryPointType, Key, NamedKeys, Parameter, U512,
+    contracts::ContractHash,
+    runtime_args,
+    EntityAddr, HashAddr,
+    account::AccountHash,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment, EntryPointType, Key, NamedKeys, Parameter, PublicKey,
+    Signature, URef, U256, U512,
 };
 
 // Storage keys
@@ -26,8 +32,123 @@ const REQUIRED_SIGNATURES_KEY: &str = "required_signatures";
 const TOTAL_LOCKED_KEY: &str = "total_locked";
 const NONCE_KEY: &str = "nonce";
 const PROCESSED_PROOFS_KEY: &str = "processed_proofs";
-const PAUSED_KEY: &str = "paused";
+const OPERATING_MODE_KEY: &str = "operating_mode";
 const MIN_LOCK_AMOUNT_KEY: &str = "min_lock_amount";
+const MAX_SIGNATURES_KEY: &str = "max_signatures";
+const AUDIT_LOG_KEY: &str = "audit_log";
+const AUDIT_COUNT_KEY: &str = "audit_count";
+const RELAYER_REWARD_KEY: &str = "relayer_reward";
+const RELAYER_REWARDS_KEY: &str = "relayer_rewards";
+const VAULT_PURSE_KEY: &str = "vault_purse";
+const VALIDATOR_LIST_KEY: &str = "validator_list";
+const PREVIOUS_VAULT_KEY: &str = "previous_vault";
+const MIGRATED_TO_KEY: &str = "migrated_to";
+const MIGRATION_PREVIEW_KEY: &str = "migration_preview";
+const CHURN_LIMIT_KEY: &str = "churn_limit";
+const CHURN_WINDOW_KEY: &str = "churn_window";
+const CHURN_WINDOW_START_KEY: &str = "churn_window_start";
+const CHURN_COUNT_KEY: &str = "churn_count";
+const PAUSED_BY_KEY: &str = "paused_by";
+const PAUSED_AT_KEY: &str = "paused_at";
+const PAUSE_REASON_KEY: &str = "pause_reason";
+const LOCK_LEAVES_KEY: &str = "lock_leaves";
+const MERKLE_NODES_KEY: &str = "merkle_nodes";
+const MERKLE_ZERO_HASHES_KEY: &str = "merkle_zero_hashes";
+const MERKLE_ROOT_KEY: &str = "merkle_root";
+const MERKLE_LEAF_COUNT_KEY: &str = "merkle_leaf_count";
+const MERKLE_LEAF_INDEX_KEY: &str = "merkle_leaf_index";
+const GAS_COST_BASE_KEY: &str = "gas_cost_base";
+const GAS_COST_PER_SIGNATURE_KEY: &str = "gas_cost_per_signature";
+const GAS_COST_PER_BYTE_KEY: &str = "gas_cost_per_byte";
+const GAS_REFUNDS_KEY: &str = "gas_refunds";
+const SUBMISSION_MODE_KEY: &str = "submission_mode";
+const RELAYERS_KEY: &str = "relayers";
+const DEPOSIT_ROUTES_KEY: &str = "deposit_routes";
+const DEPOSIT_ROUTE_IDS_KEY: &str = "deposit_route_ids";
+const DEPOSIT_ROUTE_COUNT_KEY: &str = "deposit_route_count";
+const BRIDGE_ID_KEY: &str = "bridge_id";
+const SUPPORTED_CHAINS_KEY: &str = "supported_chains";
+const DIGEST_VERSION_KEY: &str = "digest_version";
+const PREVIOUS_DIGEST_VERSION_KEY: &str = "previous_digest_version";
+const PREVIOUS_DIGEST_DEADLINE_KEY: &str = "previous_digest_deadline";
+const SELF_KEY: &str = "self";
+const TIMELOCK_QUEUE_KEY: &str = "timelock_queue";
+const SWEEP_COUNT_KEY: &str = "sweep_count";
+const UNCLAIMED_TOTAL_KEY: &str = "unclaimed_total";
+const CHAIN_DECIMALS_KEY: &str = "chain_decimals";
+const CHAIN_MIN_CONFIRMATIONS_KEY: &str = "chain_min_confirmations";
+const CHAIN_CHALLENGE_WINDOW_KEY: &str = "chain_challenge_window";
+const MAX_TVL_KEY: &str = "max_tvl";
+const ADMIN_NONCE_KEY: &str = "admin_nonce";
+const RECIPIENT_LIMIT_KEY: &str = "recipient_limit";
+const RECIPIENT_WINDOW_KEY: &str = "recipient_window";
+const RECIPIENT_WINDOW_STARTS_KEY: &str = "recipient_window_starts";
+const RECIPIENT_RELEASED_KEY: &str = "recipient_released";
+const QUEUED_RELEASES_KEY: &str = "queued_releases";
+const QUEUED_RELEASE_TOTAL_KEY: &str = "queued_release_total";
+const ANOMALY_WINDOW_KEY: &str = "anomaly_window";
+const ANOMALY_MAX_RECIPIENTS_KEY: &str = "anomaly_max_recipients";
+const ANOMALY_MIN_AVERAGE_KEY: &str = "anomaly_min_average";
+const ANOMALY_MAX_AVERAGE_KEY: &str = "anomaly_max_average";
+const ANOMALY_MIN_SAMPLE_KEY: &str = "anomaly_min_sample";
+const ANOMALY_WINDOW_START_KEY: &str = "anomaly_window_start";
+const ANOMALY_RECIPIENT_COUNT_KEY: &str = "anomaly_recipient_count";
+const ANOMALY_RELEASE_COUNT_KEY: &str = "anomaly_release_count";
+const ANOMALY_RELEASE_VOLUME_KEY: &str = "anomaly_release_volume";
+const ANOMALY_SEEN_RECIPIENTS_KEY: &str = "anomaly_seen_recipients";
+const SUNSET_KEY: &str = "sunset";
+const CREDIT_COUNT_KEY: &str = "credit_count";
+const DUST_THRESHOLD_KEY: &str = "dust_threshold";
+const DUST_BALANCES_KEY: &str = "dust_balances";
+const VALIDATOR_STATS_KEY: &str = "validator_stats";
+const RELEASE_COUNT_KEY: &str = "release_count";
+const VALIDATOR_FEE_KEY: &str = "validator_fee";
+const EPOCH_GENESIS_KEY: &str = "epoch_genesis";
+const EPOCH_REWARD_POOLS_KEY: &str = "epoch_reward_pools";
+const EPOCH_SIGNATURE_TOTALS_KEY: &str = "epoch_signature_totals";
+const EPOCH_SIGNATURES_KEY: &str = "epoch_signatures";
+const PENDING_PROOFS_KEY: &str = "pending_proofs";
+const PENDING_SIGNERS_KEY: &str = "pending_signers";
+const TOKEN_MAP_KEY: &str = "token_map";
+const STRICT_MODE_KEY: &str = "strict_mode";
+const CHAIN_HEADS_KEY: &str = "chain_heads";
+const CHAIN_HALT_TIMEOUTS_KEY: &str = "chain_halt_timeouts";
+const CHAIN_CHECKPOINTS_KEY: &str = "chain_checkpoints";
+const HOOKS_KEY: &str = "hooks";
+const GUARDIANS_KEY: &str = "guardians";
+const DISPUTES_KEY: &str = "disputes";
+const RESTRICTED_MAX_TRANSFER_KEY: &str = "restricted_max_transfer";
+const RESTRICTED_TIMELOCK_KEY: &str = "restricted_timelock";
+const CHAIN_ADDRESS_FORMATS_KEY: &str = "chain_address_formats";
+const PEER_VAULTS_KEY: &str = "peer_vaults";
+const FEE_EXEMPT_KEY: &str = "fee_exempt";
+const FEE_EXEMPTION_COUNT_KEY: &str = "fee_exemption_count";
+const GAS_ORACLE_KEY: &str = "gas_oracle";
+const RELAY_COSTS_KEY: &str = "relay_costs";
+const MIN_LOCK_MULTIPLIER_KEY: &str = "min_lock_multiplier_bps";
+const QUEUED_RELEASE_INDEX_KEY: &str = "queued_release_index";
+const QUEUED_RELEASE_COUNT_KEY: &str = "queued_release_count";
+const VALIDATOR_ORGANIZATIONS_KEY: &str = "validator_organizations";
+const MIN_SIGNER_ORGANIZATIONS_KEY: &str = "min_signer_organizations";
+const UPGRADE_PROPOSAL_KEY: &str = "upgrade_proposal";
+const UPGRADE_APPROVED_KEY: &str = "upgrade_approved";
+const SHADOW_DIGEST_VERSION_KEY: &str = "shadow_digest_version";
+const SHADOW_AGREE_COUNT_KEY: &str = "shadow_agree_count";
+const SHADOW_DISAGREE_COUNT_KEY: &str = "shadow_disagree_count";
+const SUBMISSION_BOND_KEY: &str = "submission_bond";
+const SUBMISSION_BONDS_KEY: &str = "submission_bonds";
+const BOND_FORFEIT_COUNT_KEY: &str = "bond_forfeit_count";
+const LOCK_ESCROWS_KEY: &str = "lock_escrows";
+const OPEN_ESCROW_TOTAL_KEY: &str = "open_escrow_total";
+const CHECKPOINT_COUNTS_KEY: &str = "checkpoint_counts";
+const CHECKPOINT_BLOCKS_KEY: &str = "checkpoint_blocks";
+const MAX_PROOF_AGES_KEY: &str = "max_proof_ages";
+const STALE_PROOF_ALLOWLIST_KEY: &str = "stale_proof_allowlist";
+const USER_RELEASE_COUNTS_KEY: &str = "user_release_counts";
+const USER_RELEASES_KEY: &str = "user_releases";
+const COMPACT_EVENTS_KEY: &str = "compact_events";
+const LOCK_RECORDS_KEY: &str = "lock_records";
+const RELEASE_RECORDS_KEY: &str = "release_records";
 
 // Entry point names
 const ENTRY_POINT_INIT: &str = "init";
@@ -36,269 +157,6157 @@ const ENTRY_POINT_RELEASE_CSPR: &str = "release_cspr";
 const ENTRY_POINT_ADD_VALIDATOR: &str = "add_validator";
 const ENTRY_POINT_REMOVE_VALIDATOR: &str = "remove_validator";
 const ENTRY_POINT_SET_REQUIRED_SIGNATURES: &str = "set_required_signatures";
+const ENTRY_POINT_SET_MAX_SIGNATURES: &str = "set_max_signatures";
 const ENTRY_POINT_PAUSE: &str = "pause";
 const ENTRY_POINT_UNPAUSE: &str = "unpause";
 const ENTRY_POINT_IS_VALIDATOR: &str = "is_validator";
+const ENTRY_POINT_IS_PROOF_PROCESSED: &str = "is_proof_processed";
 const ENTRY_POINT_GET_TOTAL_LOCKED: &str = "get_total_locked";
 const ENTRY_POINT_GET_NONCE: &str = "get_nonce";
+const ENTRY_POINT_GET_AUDIT_ENTRY: &str = "get_audit_entry";
+const ENTRY_POINT_GET_AUDIT_COUNT: &str = "get_audit_count";
+const ENTRY_POINT_SET_RELAYER_REWARD: &str = "set_relayer_reward";
+const ENTRY_POINT_CLAIM_RELAYER_REWARD: &str = "claim_relayer_reward";
+const ENTRY_POINT_GET_RELAYER_REWARDS: &str = "get_relayer_rewards";
+const ENTRY_POINT_GET_VALIDATORS: &str = "get_validators";
+const ENTRY_POINT_EXPORT_FOR_MIGRATION: &str = "export_for_migration";
+const ENTRY_POINT_IMPORT_FROM_PREVIOUS: &str = "import_from_previous";
+const ENTRY_POINT_GET_MIGRATION_PURSE: &str = "get_migration_purse";
+const ENTRY_POINT_SET_CHURN_LIMIT: &str = "set_churn_limit";
+const ENTRY_POINT_GET_CONFIG: &str = "get_config";
+const ENTRY_POINT_GET_LOCK_COMMITMENT: &str = "get_lock_commitment";
+const ENTRY_POINT_GET_MERKLE_ROOT: &str = "get_merkle_root";
+const ENTRY_POINT_GET_MERKLE_PATH: &str = "get_merkle_path";
+const ENTRY_POINT_SET_GAS_COST_TABLE: &str = "set_gas_cost_table";
+const ENTRY_POINT_CLAIM_GAS_REFUND: &str = "claim_gas_refund";
+const ENTRY_POINT_GET_GAS_REFUND: &str = "get_gas_refund";
+const ENTRY_POINT_SET_SUBMISSION_MODE: &str = "set_submission_mode";
+const ENTRY_POINT_ADD_RELAYER: &str = "add_relayer";
+const ENTRY_POINT_REMOVE_RELAYER: &str = "remove_relayer";
+const ENTRY_POINT_IS_RELAYER: &str = "is_relayer";
+const ENTRY_POINT_REGISTER_DEPOSIT_ROUTE: &str = "register_deposit_route";
+const ENTRY_POINT_LOCK_TO_ROUTE: &str = "lock_to_route";
+const ENTRY_POINT_ADD_SUPPORTED_CHAIN: &str = "add_supported_chain";
+const ENTRY_POINT_REMOVE_SUPPORTED_CHAIN: &str = "remove_supported_chain";
+const ENTRY_POINT_GET_METADATA: &str = "get_metadata";
+const ENTRY_POINT_SET_DIGEST_VERSION: &str = "set_digest_version";
+const ENTRY_POINT_SWEEP_STRAY_TOKENS: &str = "sweep_stray_tokens";
+const ENTRY_POINT_CANCEL_OPERATION: &str = "cancel_operation";
+const ENTRY_POINT_SWEEP_EXCESS_CSPR: &str = "sweep_excess_cspr";
+const ENTRY_POINT_SET_CHAIN_DECIMALS: &str = "set_chain_decimals";
+const ENTRY_POINT_SET_CHAIN_FINALITY: &str = "set_chain_finality";
+const ENTRY_POINT_GET_CHAIN_FINALITY: &str = "get_chain_finality";
+const ENTRY_POINT_SET_MAX_TVL: &str = "set_max_tvl";
+const ENTRY_POINT_PAUSE_WITH_SIGNATURES: &str = "pause_with_signatures";
+const ENTRY_POINT_GET_ADMIN_NONCE: &str = "get_admin_nonce";
+const ENTRY_POINT_SET_RECIPIENT_LIMIT: &str = "set_recipient_limit";
+const ENTRY_POINT_EXECUTE_QUEUED_RELEASE: &str = "execute_queued_release";
+const ENTRY_POINT_CANCEL_QUEUED_RELEASE: &str = "cancel_queued_release";
+const ENTRY_POINT_GET_QUEUED_RELEASE: &str = "get_queued_release";
+const ENTRY_POINT_SET_ANOMALY_BOUNDS: &str = "set_anomaly_bounds";
+const ENTRY_POINT_ROTATE_VALIDATOR_KEY: &str = "rotate_validator_key";
+const ENTRY_POINT_SUNSET: &str = "sunset";
+const ENTRY_POINT_GET_CLAIMABLE: &str = "get_claimable";
+const ENTRY_POINT_GET_UNCLAIMED_TOTAL: &str = "get_unclaimed_total";
+const ENTRY_POINT_SET_DUST_THRESHOLD: &str = "set_dust_threshold";
+const ENTRY_POINT_CLAIM_DUST: &str = "claim_dust";
+const ENTRY_POINT_GET_VALIDATOR_STATS: &str = "get_validator_stats";
+const ENTRY_POINT_SET_VALIDATOR_FEE: &str = "set_validator_fee";
+const ENTRY_POINT_CLAIM_VALIDATOR_REWARD: &str = "claim_validator_reward";
+const ENTRY_POINT_ROLL_EXPIRED_VALIDATOR_REWARDS: &str = "roll_expired_validator_rewards";
+const ENTRY_POINT_GET_VALIDATOR_REWARD: &str = "get_validator_reward";
+const ENTRY_POINT_REGISTER_PROOF_HEADER: &str = "register_proof_header";
+const ENTRY_POINT_ADD_SIGNATURES: &str = "add_signatures";
+const ENTRY_POINT_FINALIZE_RELEASE: &str = "finalize_release";
+const ENTRY_POINT_GET_PENDING_SIGNERS: &str = "get_pending_signers";
+const ENTRY_POINT_RELEASE_CSPR_IDEMPOTENT: &str = "release_cspr_idempotent";
+const ENTRY_POINT_ADMIN_MULTICALL: &str = "admin_multicall";
+const ENTRY_POINT_SET_TOKEN_MAPPING: &str = "set_token_mapping";
+const ENTRY_POINT_GET_TOKEN_MAPPING: &str = "get_token_mapping";
+const ENTRY_POINT_SET_STRICT_MODE: &str = "set_strict_mode";
+const ENTRY_POINT_ATTEST_CHAIN_HEAD: &str = "attest_chain_head";
+const ENTRY_POINT_SET_CHAIN_HALT_TIMEOUT: &str = "set_chain_halt_timeout";
+const ENTRY_POINT_GET_CHAIN_HEAD: &str = "get_chain_head";
+const ENTRY_POINT_SUBMIT_CHECKPOINT: &str = "submit_checkpoint";
+const ENTRY_POINT_GET_CHECKPOINT: &str = "get_checkpoint";
+const ENTRY_POINT_REGISTER_HOOK: &str = "register_hook";
+const ENTRY_POINT_UNREGISTER_HOOK: &str = "unregister_hook";
+const ENTRY_POINT_GET_HOOKS: &str = "get_hooks";
+const ENTRY_POINT_ADD_GUARDIAN: &str = "add_guardian";
+const ENTRY_POINT_REMOVE_GUARDIAN: &str = "remove_guardian";
+const ENTRY_POINT_DISPUTE_RELEASE: &str = "dispute_release";
+const ENTRY_POINT_RESOLVE_DISPUTE: &str = "resolve_dispute";
+const ENTRY_POINT_GET_DISPUTE: &str = "get_dispute";
+const ENTRY_POINT_SET_OPERATING_MODE: &str = "set_operating_mode";
+const ENTRY_POINT_SET_RESTRICTED_LIMITS: &str = "set_restricted_limits";
+const ENTRY_POINT_INIT_FROM_SNAPSHOT: &str = "init_from_snapshot";
+const ENTRY_POINT_EXPORT_CONFIG: &str = "export_config";
+const ENTRY_POINT_SET_CHAIN_ADDRESS_FORMAT: &str = "set_chain_address_format";
+const ENTRY_POINT_GET_CHAIN_ADDRESS_FORMAT: &str = "get_chain_address_format";
+const ENTRY_POINT_SET_PEER_VAULT: &str = "set_peer_vault";
+const ENTRY_POINT_GET_PEER_VAULT: &str = "get_peer_vault";
+const ENTRY_POINT_SET_FEE_EXEMPT: &str = "set_fee_exempt";
+const ENTRY_POINT_IS_FEE_EXEMPT: &str = "is_fee_exempt";
+const ENTRY_POINT_SET_GAS_ORACLE: &str = "set_gas_oracle";
+const ENTRY_POINT_SET_MIN_LOCK_MULTIPLIER: &str = "set_min_lock_multiplier";
+const ENTRY_POINT_UPDATE_RELAY_COST: &str = "update_relay_cost";
+const ENTRY_POINT_GET_MIN_LOCK_AMOUNT: &str = "get_min_lock_amount";
+const ENTRY_POINT_GET_PENDING_RELEASES: &str = "get_pending_releases";
+const ENTRY_POINT_SET_VALIDATOR_ORGANIZATION: &str = "set_validator_organization";
+const ENTRY_POINT_SET_MIN_SIGNER_ORGANIZATIONS: &str = "set_min_signer_organizations";
+const ENTRY_POINT_GET_VALIDATOR_ORGANIZATION: &str = "get_validator_organization";
+const ENTRY_POINT_PROPOSE_UPGRADE: &str = "propose_upgrade";
+const ENTRY_POINT_APPROVE_UPGRADE: &str = "approve_upgrade";
+const ENTRY_POINT_CANCEL_UPGRADE: &str = "cancel_upgrade";
+const ENTRY_POINT_GET_UPGRADE_PROPOSAL: &str = "get_upgrade_proposal";
+const ENTRY_POINT_SET_SHADOW_DIGEST_VERSION: &str = "set_shadow_digest_version";
+const ENTRY_POINT_GET_SHADOW_STATS: &str = "get_shadow_stats";
+const ENTRY_POINT_DEPOSIT_SUBMISSION_BOND: &str = "deposit_submission_bond";
+const ENTRY_POINT_CLAIM_SUBMISSION_BOND: &str = "claim_submission_bond";
+const ENTRY_POINT_SET_SUBMISSION_BOND: &str = "set_submission_bond";
+const ENTRY_POINT_GET_SUBMISSION_BOND: &str = "get_submission_bond";
+const ENTRY_POINT_CONFIRM_DELIVERY: &str = "confirm_delivery";
+const ENTRY_POINT_REFUND_LOCK: &str = "refund_lock";
+const ENTRY_POINT_AUDIT_OPEN_TRANSFERS: &str = "audit_open_transfers";
+const ENTRY_POINT_GET_LOCK_ESCROW: &str = "get_lock_escrow";
+const ENTRY_POINT_SET_MAX_PROOF_AGE: &str = "set_max_proof_age";
+const ENTRY_POINT_ALLOW_STALE_PROOF: &str = "allow_stale_proof";
+const ENTRY_POINT_GET_MAX_PROOF_AGE: &str = "get_max_proof_age";
+const ENTRY_POINT_GET_USER_RELEASE_COUNT: &str = "get_user_release_count";
+const ENTRY_POINT_GET_USER_RELEASE: &str = "get_user_release";
+const ENTRY_POINT_SET_COMPACT_EVENTS: &str = "set_compact_events";
+const ENTRY_POINT_ROTATE_VALIDATOR_SET: &str = "rotate_validator_set";
 
-// Helper functions
-fn get_key<T: FromBytes + CLTyped>(name: &str) -> T {
-    let key = runtime::get_key(name)
-        .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
-    storage::read(key.into_uref().unwrap_or_revert())
-        .unwrap_or_revert()
-        .unwrap_or_revert()
+// Audit trail entry for a privileged call
+struct AuditEntry {
+    action: String,
+    caller: Key,
+    params_hash: [u8; 32],
+    timestamp: u64,
 }
 
-fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
-    match runtime::get_key(name) {
-        Some(key) => {
-            let key_uref = key.into_uref().unwrap_or_revert();
-            storage::write(key_uref, value);
+impl CLTyped for AuditEntry {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for AuditEntry {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.action.to_bytes()?);
+        result.extend(self.caller.to_bytes()?);
+        result.extend(self.params_hash.to_bytes()?);
+        result.extend(self.timestamp.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.action.serialized_length()
+            + self.caller.serialized_length()
+            + self.params_hash.serialized_length()
+            + self.timestamp.serialized_length()
+    }
+}
+
+impl FromBytes for AuditEntry {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (action, remainder) = String::from_bytes(bytes)?;
+        let (caller, remainder) = Key::from_bytes(remainder)?;
+        let (params_hash, remainder) = <[u8; 32]>::from_bytes(remainder)?;
+        let (timestamp, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            AuditEntry {
+                action,
+                caller,
+                params_hash,
+                timestamp,
+            },
+            remainder,
+        ))
+    }
+}
+
+// Contract identity reported by get_metadata
+const CONTRACT_NAME: &str = "casper_bridge_vault";
+const CONTRACT_SEMVER: &str = env!("CARGO_PKG_VERSION");
+
+// Newest release message format validators may sign (see release_message)
+const LATEST_DIGEST_VERSION: u8 = 5;
+
+// Longest release memo accepted, in bytes
+const MAX_MEMO_LENGTH: usize = 256;
+
+// Operating modes, in increasing severity: Restricted only lets small transfers through
+// promptly, Halted stops locks and releases entirely (what pausing means)
+const MODE_NORMAL: u8 = 0;
+const MODE_RESTRICTED: u8 = 1;
+const MODE_HALTED: u8 = 2;
+
+// CallerInfo field indices for a calling contract: its entity address (Casper 2.0
+// entities) and its contract hash (contracts installed as such)
+const CALLER_ENTITY_FIELD: u8 = 3;
+const CALLER_CONTRACT_FIELD: u8 = 4;
+
+// Delay on queued release payouts while Restricted, until changed (7 days, ms)
+const DEFAULT_RESTRICTED_TIMELOCK: u64 = 7 * 24 * 60 * 60 * 1000;
+
+// How long a guardian's dispute holds a queued release before lapsing unresolved (7 days, ms)
+const DISPUTE_WINDOW: u64 = 7 * 24 * 60 * 60 * 1000;
+
+// Most queue positions a single get_pending_releases call may scan
+const MAX_PENDING_PAGE: u32 = 64;
+
+// Most onward hops a lock route may carry
+const MAX_ROUTE_HOPS: usize = 4;
+
+// Operations accepted in one admin_multicall
+const MAX_ADMIN_OPS: usize = 32;
+
+// Listener contracts notified of bridge events, and the events they can subscribe to
+const MAX_HOOKS: usize = 8;
+const HOOK_EVENT_LOCK: u8 = 0;
+const HOOK_EVENT_RELEASE: u8 = 1;
+const HOOK_ENTRY_POINT: &str = "on_bridge_event";
+
+// Chain the vault bridges to out of the box
+const DEFAULT_SUPPORTED_CHAIN: &str = "ethereum";
+
+// Destination address formats checked at lock time, set per chain with set_chain_address_format
+// (0 or unset = unchecked). EVM addresses are checked for shape only: the runtime has no
+// keccak to verify an EIP-55 checksum
+const ADDRESS_FORMAT_EVM: u8 = 1;
+const ADDRESS_FORMAT_SOLANA: u8 = 2;
+const ADDRESS_FORMAT_BECH32: u8 = 3;
+
+// Basis points in one (100%)
+const BPS_DENOMINATOR: u32 = 10_000;
+
+// Decimals of CSPR (motes)
+const CSPR_DECIMALS: u8 = 9;
+
+// Default cap on signatures accepted in a single proof
+const DEFAULT_MAX_SIGNATURES: u32 = 32;
+
+// Default validator churn window (24 hours, in milliseconds); a limit of 0 disables the cap
+const DEFAULT_CHURN_WINDOW: u64 = 24 * 60 * 60 * 1000;
+
+// Default per-recipient release window (24 hours, in milliseconds); a limit of 0 disables throttling
+const DEFAULT_RECIPIENT_WINDOW: u64 = 24 * 60 * 60 * 1000;
+
+// Default release anomaly window (24 hours, in milliseconds); bounds of 0 are not checked
+const DEFAULT_ANOMALY_WINDOW: u64 = 24 * 60 * 60 * 1000;
+
+// Who may submit release proofs
+const SUBMISSION_MODE_ANYONE: u8 = 0;
+const SUBMISSION_MODE_RELAYERS: u8 = 1;
+const SUBMISSION_MODE_VALIDATORS: u8 = 2;
+
+// Per-lock escrow states: Locked until validators confirm the destination delivered it or
+// approve returning it to the sender
+const ESCROW_LOCKED: u8 = 0;
+const ESCROW_DELIVERED: u8 = 1;
+const ESCROW_REFUNDED: u8 = 2;
+
+// Most locks a single audit_open_transfers call may cover
+const MAX_ESCROW_AUDIT_RANGE: u64 = 256;
+
+// Most locks a single get_lock_commitment call may cover
+const MAX_COMMITMENT_RANGE: u64 = 256;
+
+// Depth of the append-only lock tree (2^20 leaves)
+const MERKLE_TREE_DEPTH: usize = 20;
+
+// Domain separation for lock tree hashes, so an inner node cannot pass as a leaf
+const MERKLE_LEAF_PREFIX: u8 = 0;
+const MERKLE_NODE_PREFIX: u8 = 1;
+
+// Delay between queueing and executing a timelocked owner operation (48 hours, in milliseconds)
+const TIMELOCK_DELAY: u64 = 48 * 60 * 60 * 1000;
+
+// Delay before a queued sunset can take effect (14 days, in milliseconds)
+const SUNSET_DELAY: u64 = 14 * 24 * 60 * 60 * 1000;
+
+// Validator reward epochs (7 days, in milliseconds) and how many closed epochs stay claimable
+const VALIDATOR_EPOCH_LENGTH: u64 = 7 * 24 * 60 * 60 * 1000;
+const VALIDATOR_REWARD_EXPIRY_EPOCHS: u64 = 4;
+
+// CEP-18 entry points the vault calls
+const CEP18_BALANCE_OF: &str = "balance_of";
+const CEP18_TRANSFER: &str = "transfer";
+
+// User error codes
+const ERROR_CHURN_LIMIT_EXCEEDED: u16 = 1;
+const ERROR_TIMELOCK_PENDING: u16 = 2;
+const ERROR_TVL_CAP_REACHED: u16 = 3;
+const ERROR_SUNSET: u16 = 4;
+const ERROR_SOURCE_CHAIN_STALE: u16 = 5;
+const ERROR_RELEASE_DISPUTED: u16 = 6;
+const ERROR_RESTRICTED: u16 = 7;
+const ERROR_SIGNER_DIVERSITY: u16 = 8;
+const ERROR_PROOF_TOO_OLD: u16 = 9;
+
+// Bridged asset identifier
+enum AssetId {
+    Native,
+    Cep18(Key),
+    Nft(Key, U256),
+}
+
+const ASSET_NATIVE_TAG: u8 = 0;
+const ASSET_CEP18_TAG: u8 = 1;
+const ASSET_NFT_TAG: u8 = 2;
+
+impl CLTyped for AssetId {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for AssetId {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            AssetId::Native => result.push(ASSET_NATIVE_TAG),
+            AssetId::Cep18(token) => {
+                result.push(ASSET_CEP18_TAG);
+                result.extend(token.to_bytes()?);
+            }
+            AssetId::Nft(collection, token_id) => {
+                result.push(ASSET_NFT_TAG);
+                result.extend(collection.to_bytes()?);
+                result.extend(token_id.to_bytes()?);
+            }
         }
-        None => {
-            let key = storage::new_uref(value);
-            runtime::put_key(name, key.into());
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            AssetId::Native => 0,
+            AssetId::Cep18(token) => token.serialized_length(),
+            AssetId::Nft(collection, token_id) => {
+                collection.serialized_length() + token_id.serialized_length()
+            }
         }
     }
 }
 
-fn require_owner() {
-    let owner: Key = get_key(OWNER_KEY);
-    let caller = runtime::get_caller();
-    if Key::Account(caller) != owner {
-        runtime::revert(casper_types::ApiError::PermissionDenied);
+impl FromBytes for AssetId {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ASSET_NATIVE_TAG => Ok((AssetId::Native, remainder)),
+            ASSET_CEP18_TAG => {
+                let (token, remainder) = Key::from_bytes(remainder)?;
+                Ok((AssetId::Cep18(token), remainder))
+            }
+            ASSET_NFT_TAG => {
+                let (collection, remainder) = Key::from_bytes(remainder)?;
+                let (token_id, remainder) = U256::from_bytes(remainder)?;
+                Ok((AssetId::Nft(collection, token_id), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
     }
 }
 
-fn require_not_paused() {
-    let paused: bool = get_key(PAUSED_KEY);
-    if paused {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+// One onward leg after the lock's destination chain, e.g. Ethereum -> Arbitrum
+struct Hop {
+    chain: String,
+    address: String,
+}
+
+impl CLTyped for Hop {
+    fn cl_type() -> CLType {
+        CLType::Any
     }
 }
 
-// Initialize the contract
-#[no_mangle]
-pub extern "C" fn init() {
-    let required_sigs: u32 = runtime::get_named_arg("required_sigs");
-    let min_amount: U512 = runtime::get_named_arg("min_amount");
+impl ToBytes for Hop {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.chain.to_bytes()?);
+        result.extend(self.address.to_bytes()?);
+        Ok(result)
+    }
 
-    let caller = runtime::get_caller();
+    fn serialized_length(&self) -> usize {
+        self.chain.serialized_length() + self.address.serialized_length()
+    }
+}
 
-    // Initialize storage
-    set_key(OWNER_KEY, Key::Account(caller));
-    set_key(REQUIRED_SIGNATURES_KEY, required_sigs);
-    set_key(MIN_LOCK_AMOUNT_KEY, min_amount);
-    set_key(PAUSED_KEY, false);
-    set_key(NONCE_KEY, 0u64);
-    set_key(TOTAL_LOCKED_KEY, U512::zero());
+impl FromBytes for Hop {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (chain, remainder) = String::from_bytes(bytes)?;
+        let (address, remainder) = String::from_bytes(remainder)?;
+        Ok((Hop { chain, address }, remainder))
+    }
+}
 
-    // Initialize validators dictionary (owner is first validator)
-    let validators_dict = storage::new_dictionary(VALIDATORS_KEY).unwrap_or_revert();
-    let caller_key = format!("{:?}", caller);
-    storage::dictionary_put(validators_dict, &caller_key, true);
+// Lock record stored as the AssetLocked event
+struct LockRecord {
+    asset: AssetId,
+    amount: U512,
+    nonce: u64,
+    sender: Key,
+    destination_chain: String,
+    destination_address: String,
+    // Onward hops the destination chain should forward through, in order; empty for direct locks
+    route: Vec<Hop>,
+    // Token contract to mint on the destination chain, from the token map; None if unmapped
+    remote_token: Option<String>,
+}
 
-    // Initialize processed proofs dictionary
-    storage::new_dictionary(PROCESSED_PROOFS_KEY).unwrap_or_revert();
+impl CLTyped for LockRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
 }
 
-// Lock CSPR to bridge to another chain
-#[no_mangle]
-pub extern "C" fn lock_cspr() {
-    require_not_paused();
+impl ToBytes for LockRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.asset.to_bytes()?);
+        result.extend(self.amount.to_bytes()?);
+        result.extend(self.nonce.to_bytes()?);
+        result.extend(self.sender.to_bytes()?);
+        result.extend(self.destination_chain.to_bytes()?);
+        result.extend(self.destination_address.to_bytes()?);
+        result.extend(self.route.to_bytes()?);
+        result.extend(self.remote_token.to_bytes()?);
+        Ok(result)
+    }
 
-    let destination_chain: String = runtime::get_named_arg("destination_chain");
-    let destination_address: String = runtime::get_named_arg("destination_address");
-    let amount: U512 = runtime::get_named_arg("amount");
+    fn serialized_length(&self) -> usize {
+        self.asset.serialized_length()
+            + self.amount.serialized_length()
+            + self.nonce.serialized_length()
+            + self.sender.serialized_length()
+            + self.destination_chain.serialized_length()
+            + self.destination_address.serialized_length()
+            + self.route.serialized_length()
+            + self.remote_token.serialized_length()
+    }
+}
 
-    let min_amount: U512 = get_key(MIN_LOCK_AMOUNT_KEY);
-    if amount < min_amount {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+impl FromBytes for LockRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (asset, remainder) = AssetId::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (nonce, remainder) = u64::from_bytes(remainder)?;
+        let (sender, remainder) = Key::from_bytes(remainder)?;
+        let (destination_chain, remainder) = String::from_bytes(remainder)?;
+        let (destination_address, remainder) = String::from_bytes(remainder)?;
+        let (route, remainder) = Vec::<Hop>::from_bytes(remainder)?;
+        let (remote_token, remainder) = Option::<String>::from_bytes(remainder)?;
+        Ok((
+            LockRecord {
+                asset,
+                amount,
+                nonce,
+                sender,
+                destination_chain,
+                destination_address,
+                route,
+                remote_token,
+            },
+            remainder,
+        ))
     }
+}
 
-    let caller = runtime::get_caller();
+// Release record stored as the AssetReleased event
+struct ReleaseRecord {
+    asset: AssetId,
+    amount: U512,
+    nonce: u64,
+    recipient: Key,
+    source_chain: String,
+    source_tx_hash: String,
+    source_sender: Option<String>,
+    memo: Option<String>,
+    // Source chain block the burn landed in; only signed from digest v4
+    source_block: Option<u64>,
+}
 
-    // Update total locked
-    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
-    set_key(TOTAL_LOCKED_KEY, current_locked + amount);
+impl CLTyped for ReleaseRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
 
-    // Increment nonce
-    let current_nonce: u64 = get_key(NONCE_KEY);
-    set_key(NONCE_KEY, current_nonce + 1);
+impl ToBytes for ReleaseRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.asset.to_bytes()?);
+        result.extend(self.amount.to_bytes()?);
+        result.extend(self.nonce.to_bytes()?);
+        result.extend(self.recipient.to_bytes()?);
+        result.extend(self.source_chain.to_bytes()?);
+        result.extend(self.source_tx_hash.to_bytes()?);
+        result.extend(self.source_sender.to_bytes()?);
+        result.extend(self.memo.to_bytes()?);
+        result.extend(self.source_block.to_bytes()?);
+        Ok(result)
+    }
 
-    // Emit event (in Casper, we store event data in named keys)
-    let event_name = format!("asset_locked_{}", current_nonce);
-    runtime::put_key(
-        &event_name,
-        storage::new_uref((amount, current_nonce)).into(),
-    );
+    fn serialized_length(&self) -> usize {
+        self.asset.serialized_length()
+            + self.amount.serialized_length()
+            + self.nonce.serialized_length()
+            + self.recipient.serialized_length()
+            + self.source_chain.serialized_length()
+            + self.source_tx_hash.serialized_length()
+            + self.source_sender.serialized_length()
+            + self.memo.serialized_length()
+            + self.source_block.serialized_length()
+    }
 }
 
-// Release CSPR when proof of burn is provided from destination chain
-#[no_mangle]
-pub extern "C" fn release_cspr() {
-    require_not_paused();
+impl FromBytes for ReleaseRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (asset, remainder) = AssetId::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (nonce, remainder) = u64::from_bytes(remainder)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (source_chain, remainder) = String::from_bytes(remainder)?;
+        let (source_tx_hash, remainder) = String::from_bytes(remainder)?;
+        let (source_sender, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (memo, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (source_block, remainder) = Option::<u64>::from_bytes(remainder)?;
+        Ok((
+            ReleaseRecord {
+                asset,
+                amount,
+                nonce,
+                recipient,
+                source_chain,
+                source_tx_hash,
+                source_sender,
+                memo,
+                source_block,
+            },
+            remainder,
+        ))
+    }
+}
 
-    let source_chain: String = runtime::get_named_arg("source_chain");
-    let source_tx_hash: String = runtime::get_named_arg("source_tx_hash");
-    let amount: U512 = runtime::get_named_arg("amount");
-    let recipient: Key = runtime::get_named_arg("recipient");
-    let nonce: u64 = runtime::get_named_arg("nonce");
-    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+// State handed from an old vault to its replacement
+struct MigrationSnapshot {
+    total_locked: U512,
+    nonce: u64,
+    required_signatures: u32,
+    validators: Vec<Key>,
+}
 
-    // Check if proof already processed
-    let processed_dict = runtime::get_key(PROCESSED_PROOFS_KEY)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
+impl CLTyped for MigrationSnapshot {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
 
-    let nonce_key = format!("{}", nonce);
-    let already_processed: Option<bool> = storage::dictionary_get(processed_dict, &nonce_key)
-        .unwrap_or_revert();
+impl ToBytes for MigrationSnapshot {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.total_locked.to_bytes()?);
+        result.extend(self.nonce.to_bytes()?);
+        result.extend(self.required_signatures.to_bytes()?);
+        result.extend(self.validators.to_bytes()?);
+        Ok(result)
+    }
 
-    if already_processed.is_some() {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+    fn serialized_length(&self) -> usize {
+        self.total_locked.serialized_length()
+            + self.nonce.serialized_length()
+            + self.required_signatures.serialized_length()
+            + self.validators.serialized_length()
     }
+}
 
-    // Verify signatures
-    let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
-    if signatures.len() < required_sigs as usize {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+impl FromBytes for MigrationSnapshot {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (total_locked, remainder) = U512::from_bytes(bytes)?;
+        let (nonce, remainder) = u64::from_bytes(remainder)?;
+        let (required_signatures, remainder) = u32::from_bytes(remainder)?;
+        let (validators, remainder) = Vec::<Key>::from_bytes(remainder)?;
+        Ok((
+            MigrationSnapshot {
+                total_locked,
+                nonce,
+                required_signatures,
+                validators,
+            },
+            remainder,
+        ))
     }
+}
 
-    // Mark as processed
-    storage::dictionary_put(processed_dict, &nonce_key, true);
+// Replacement vault announced ahead of a migration, so users and validators can check the code
+// that will take custody before export_for_migration hands it the funds
+struct UpgradeProposal {
+    successor: Key,
+    wasm_hash: String,
+    changelog_hash: String,
+    activation_time: u64,
+}
 
-    // Update total locked
-    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
-    if current_locked < amount {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+impl CLTyped for UpgradeProposal {
+    fn cl_type() -> CLType {
+        CLType::Any
     }
-    set_key(TOTAL_LOCKED_KEY, current_locked - amount);
+}
 
-    // Transfer CSPR to recipient
-    if let Key::Account(account_hash) = recipient {
-        system::transfer_to_account(account_hash, amount, None).unwrap_or_revert();
-    } else {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
+impl ToBytes for UpgradeProposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.successor.to_bytes()?);
+        result.extend(self.wasm_hash.to_bytes()?);
+        result.extend(self.changelog_hash.to_bytes()?);
+        result.extend(self.activation_time.to_bytes()?);
+        Ok(result)
     }
 
-    // Emit event
-    let event_name = format!("asset_released_{}", nonce);
-    runtime::put_key(
-        &event_name,
-        storage::new_uref((amount, nonce)).into(),
-    );
+    fn serialized_length(&self) -> usize {
+        self.successor.serialized_length()
+            + self.wasm_hash.serialized_length()
+            + self.changelog_hash.serialized_length()
+            + self.activation_time.serialized_length()
+    }
 }
 
-// Add a validator (owner only)
-#[no_mangle]
-pub extern "C" fn add_validator() {
-    require_owner();
+impl FromBytes for UpgradeProposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (successor, remainder) = Key::from_bytes(bytes)?;
+        let (wasm_hash, remainder) = String::from_bytes(remainder)?;
+        let (changelog_hash, remainder) = String::from_bytes(remainder)?;
+        let (activation_time, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            UpgradeProposal {
+                successor,
+                wasm_hash,
+                changelog_hash,
+                activation_time,
+            },
+            remainder,
+        ))
+    }
+}
 
-    let validator: Key = runtime::get_named_arg("validator");
+// Record of stray assets moved out of the vault, stored as the AssetsSwept event
+struct SweepRecord {
+    asset: AssetId,
+    amount: U512,
+    to: Key,
+}
 
-    let validators_dict = runtime::get_key(VALIDATORS_KEY)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
+impl CLTyped for SweepRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for SweepRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.asset.to_bytes()?);
+        result.extend(self.amount.to_bytes()?);
+        result.extend(self.to.to_bytes()?);
+        Ok(result)
+    }
 
-    let validator_key = format!("{:?}", validator);
-    storage::dictionary_put(validators_dict, &validator_key, true);
+    fn serialized_length(&self) -> usize {
+        self.asset.serialized_length() + self.amount.serialized_length() + self.to.serialized_length()
+    }
 }
 
-// Remove a validator (owner only)
-#[no_mangle]
-pub extern "C" fn remove_validator() {
-    require_owner();
+impl FromBytes for SweepRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (asset, remainder) = AssetId::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (to, remainder) = Key::from_bytes(remainder)?;
+        Ok((SweepRecord { asset, amount, to }, remainder))
+    }
+}
+
+// One owner operation inside admin_multicall, applied exactly as its standalone entry point
+enum AdminOp {
+    AddValidator(Key),
+    RemoveValidator(Key),
+    SetRequiredSignatures(u32),
+    SetMaxSignatures(u32),
+    SetRelayerReward(U512),
+    SetValidatorFee(U512),
+}
+
+const ADMIN_OP_ADD_VALIDATOR_TAG: u8 = 0;
+const ADMIN_OP_REMOVE_VALIDATOR_TAG: u8 = 1;
+const ADMIN_OP_SET_REQUIRED_SIGNATURES_TAG: u8 = 2;
+const ADMIN_OP_SET_MAX_SIGNATURES_TAG: u8 = 3;
+const ADMIN_OP_SET_RELAYER_REWARD_TAG: u8 = 4;
+const ADMIN_OP_SET_VALIDATOR_FEE_TAG: u8 = 5;
+
+impl CLTyped for AdminOp {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for AdminOp {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            AdminOp::AddValidator(validator) => {
+                result.push(ADMIN_OP_ADD_VALIDATOR_TAG);
+                result.extend(validator.to_bytes()?);
+            }
+            AdminOp::RemoveValidator(validator) => {
+                result.push(ADMIN_OP_REMOVE_VALIDATOR_TAG);
+                result.extend(validator.to_bytes()?);
+            }
+            AdminOp::SetRequiredSignatures(count) => {
+                result.push(ADMIN_OP_SET_REQUIRED_SIGNATURES_TAG);
+                result.extend(count.to_bytes()?);
+            }
+            AdminOp::SetMaxSignatures(count) => {
+                result.push(ADMIN_OP_SET_MAX_SIGNATURES_TAG);
+                result.extend(count.to_bytes()?);
+            }
+            AdminOp::SetRelayerReward(reward) => {
+                result.push(ADMIN_OP_SET_RELAYER_REWARD_TAG);
+                result.extend(reward.to_bytes()?);
+            }
+            AdminOp::SetValidatorFee(fee) => {
+                result.push(ADMIN_OP_SET_VALIDATOR_FEE_TAG);
+                result.extend(fee.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            AdminOp::AddValidator(validator) | AdminOp::RemoveValidator(validator) => validator.serialized_length(),
+            AdminOp::SetRequiredSignatures(count) | AdminOp::SetMaxSignatures(count) => count.serialized_length(),
+            AdminOp::SetRelayerReward(amount) | AdminOp::SetValidatorFee(amount) => amount.serialized_length(),
+        }
+    }
+}
+
+impl FromBytes for AdminOp {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ADMIN_OP_ADD_VALIDATOR_TAG => {
+                let (validator, remainder) = Key::from_bytes(remainder)?;
+                Ok((AdminOp::AddValidator(validator), remainder))
+            }
+            ADMIN_OP_REMOVE_VALIDATOR_TAG => {
+                let (validator, remainder) = Key::from_bytes(remainder)?;
+                Ok((AdminOp::RemoveValidator(validator), remainder))
+            }
+            ADMIN_OP_SET_REQUIRED_SIGNATURES_TAG => {
+                let (count, remainder) = u32::from_bytes(remainder)?;
+                Ok((AdminOp::SetRequiredSignatures(count), remainder))
+            }
+            ADMIN_OP_SET_MAX_SIGNATURES_TAG => {
+                let (count, remainder) = u32::from_bytes(remainder)?;
+                Ok((AdminOp::SetMaxSignatures(count), remainder))
+            }
+            ADMIN_OP_SET_RELAYER_REWARD_TAG => {
+                let (reward, remainder) = U512::from_bytes(remainder)?;
+                Ok((AdminOp::SetRelayerReward(reward), remainder))
+            }
+            ADMIN_OP_SET_VALIDATOR_FEE_TAG => {
+                let (fee, remainder) = U512::from_bytes(remainder)?;
+                Ok((AdminOp::SetValidatorFee(fee), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+// Governance state carried to a replacement vault by export_config and init_from_snapshot
+// Balances, processed proofs and per-chain dictionaries are not included
+struct ConfigSnapshot {
+    required_signatures: u32,
+    max_signatures: u32,
+    min_lock_amount: U512,
+    relayer_reward: U512,
+    validator_fee: U512,
+    max_tvl: U512,
+    churn_limit: u32,
+    churn_window: u64,
+    submission_mode: u8,
+    supported_chains: Vec<String>,
+    digest_version: u8,
+    recipient_limit: U512,
+    recipient_window: u64,
+    dust_threshold: U512,
+    strict_mode: bool,
+    restricted_max_transfer: U512,
+    restricted_timelock: u64,
+    validators: Vec<Key>,
+}
+
+impl CLTyped for ConfigSnapshot {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ConfigSnapshot {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.required_signatures.to_bytes()?);
+        result.extend(self.max_signatures.to_bytes()?);
+        result.extend(self.min_lock_amount.to_bytes()?);
+        result.extend(self.relayer_reward.to_bytes()?);
+        result.extend(self.validator_fee.to_bytes()?);
+        result.extend(self.max_tvl.to_bytes()?);
+        result.extend(self.churn_limit.to_bytes()?);
+        result.extend(self.churn_window.to_bytes()?);
+        result.extend(self.submission_mode.to_bytes()?);
+        result.extend(self.supported_chains.to_bytes()?);
+        result.extend(self.digest_version.to_bytes()?);
+        result.extend(self.recipient_limit.to_bytes()?);
+        result.extend(self.recipient_window.to_bytes()?);
+        result.extend(self.dust_threshold.to_bytes()?);
+        result.extend(self.strict_mode.to_bytes()?);
+        result.extend(self.restricted_max_transfer.to_bytes()?);
+        result.extend(self.restricted_timelock.to_bytes()?);
+        result.extend(self.validators.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.required_signatures.serialized_length()
+            + self.max_signatures.serialized_length()
+            + self.min_lock_amount.serialized_length()
+            + self.relayer_reward.serialized_length()
+            + self.validator_fee.serialized_length()
+            + self.max_tvl.serialized_length()
+            + self.churn_limit.serialized_length()
+            + self.churn_window.serialized_length()
+            + self.submission_mode.serialized_length()
+            + self.supported_chains.serialized_length()
+            + self.digest_version.serialized_length()
+            + self.recipient_limit.serialized_length()
+            + self.recipient_window.serialized_length()
+            + self.dust_threshold.serialized_length()
+            + self.strict_mode.serialized_length()
+            + self.restricted_max_transfer.serialized_length()
+            + self.restricted_timelock.serialized_length()
+            + self.validators.serialized_length()
+    }
+}
+
+impl FromBytes for ConfigSnapshot {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (required_signatures, remainder) = u32::from_bytes(bytes)?;
+        let (max_signatures, remainder) = u32::from_bytes(remainder)?;
+        let (min_lock_amount, remainder) = U512::from_bytes(remainder)?;
+        let (relayer_reward, remainder) = U512::from_bytes(remainder)?;
+        let (validator_fee, remainder) = U512::from_bytes(remainder)?;
+        let (max_tvl, remainder) = U512::from_bytes(remainder)?;
+        let (churn_limit, remainder) = u32::from_bytes(remainder)?;
+        let (churn_window, remainder) = u64::from_bytes(remainder)?;
+        let (submission_mode, remainder) = u8::from_bytes(remainder)?;
+        let (supported_chains, remainder) = Vec::<String>::from_bytes(remainder)?;
+        let (digest_version, remainder) = u8::from_bytes(remainder)?;
+        let (recipient_limit, remainder) = U512::from_bytes(remainder)?;
+        let (recipient_window, remainder) = u64::from_bytes(remainder)?;
+        let (dust_threshold, remainder) = U512::from_bytes(remainder)?;
+        let (strict_mode, remainder) = bool::from_bytes(remainder)?;
+        let (restricted_max_transfer, remainder) = U512::from_bytes(remainder)?;
+        let (restricted_timelock, remainder) = u64::from_bytes(remainder)?;
+        let (validators, remainder) = Vec::<Key>::from_bytes(remainder)?;
+        Ok((
+            ConfigSnapshot {
+                required_signatures,
+                max_signatures,
+                min_lock_amount,
+                relayer_reward,
+                validator_fee,
+                max_tvl,
+                churn_limit,
+                churn_window,
+                submission_mode,
+                supported_chains,
+                digest_version,
+                recipient_limit,
+                recipient_window,
+                dust_threshold,
+                strict_mode,
+                restricted_max_transfer,
+                restricted_timelock,
+                validators,
+            },
+            remainder,
+        ))
+    }
+}
+
+// Current bridge settings, returned by get_config
+struct BridgeConfig {
+    owner: Key,
+    required_signatures: u32,
+    max_signatures: u32,
+    min_lock_amount: U512,
+    relayer_reward: U512,
+    max_tvl: U512,
+    strict_mode: bool,
+    churn_limit: u32,
+    churn_window: u64,
+    submission_mode: u8,
+    operating_mode: u8,
+    restricted_max_transfer: U512,
+    restricted_timelock: u64,
+    sunset: bool,
+    paused_by: Option<Key>,
+    paused_at: u64,
+    pause_reason: Option<String>,
+}
+
+impl CLTyped for BridgeConfig {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for BridgeConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.owner.to_bytes()?);
+        result.extend(self.required_signatures.to_bytes()?);
+        result.extend(self.max_signatures.to_bytes()?);
+        result.extend(self.min_lock_amount.to_bytes()?);
+        result.extend(self.relayer_reward.to_bytes()?);
+        result.extend(self.max_tvl.to_bytes()?);
+        result.extend(self.strict_mode.to_bytes()?);
+        result.extend(self.churn_limit.to_bytes()?);
+        result.extend(self.churn_window.to_bytes()?);
+        result.extend(self.submission_mode.to_bytes()?);
+        result.extend(self.operating_mode.to_bytes()?);
+        result.extend(self.restricted_max_transfer.to_bytes()?);
+        result.extend(self.restricted_timelock.to_bytes()?);
+        result.extend(self.sunset.to_bytes()?);
+        result.extend(self.paused_by.to_bytes()?);
+        result.extend(self.paused_at.to_bytes()?);
+        result.extend(self.pause_reason.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.required_signatures.serialized_length()
+            + self.max_signatures.serialized_length()
+            + self.min_lock_amount.serialized_length()
+            + self.relayer_reward.serialized_length()
+            + self.max_tvl.serialized_length()
+            + self.strict_mode.serialized_length()
+            + self.churn_limit.serialized_length()
+            + self.churn_window.serialized_length()
+            + self.submission_mode.serialized_length()
+            + self.operating_mode.serialized_length()
+            + self.restricted_max_transfer.serialized_length()
+            + self.restricted_timelock.serialized_length()
+            + self.sunset.serialized_length()
+            + self.paused_by.serialized_length()
+            + self.paused_at.serialized_length()
+            + self.pause_reason.serialized_length()
+    }
+}
+
+impl FromBytes for BridgeConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (owner, remainder) = Key::from_bytes(bytes)?;
+        let (required_signatures, remainder) = u32::from_bytes(remainder)?;
+        let (max_signatures, remainder) = u32::from_bytes(remainder)?;
+        let (min_lock_amount, remainder) = U512::from_bytes(remainder)?;
+        let (relayer_reward, remainder) = U512::from_bytes(remainder)?;
+        let (max_tvl, remainder) = U512::from_bytes(remainder)?;
+        let (strict_mode, remainder) = bool::from_bytes(remainder)?;
+        let (churn_limit, remainder) = u32::from_bytes(remainder)?;
+        let (churn_window, remainder) = u64::from_bytes(remainder)?;
+        let (submission_mode, remainder) = u8::from_bytes(remainder)?;
+        let (operating_mode, remainder) = u8::from_bytes(remainder)?;
+        let (restricted_max_transfer, remainder) = U512::from_bytes(remainder)?;
+        let (restricted_timelock, remainder) = u64::from_bytes(remainder)?;
+        let (sunset, remainder) = bool::from_bytes(remainder)?;
+        let (paused_by, remainder) = Option::<Key>::from_bytes(remainder)?;
+        let (paused_at, remainder) = u64::from_bytes(remainder)?;
+        let (pause_reason, remainder) = Option::<String>::from_bytes(remainder)?;
+        Ok((
+            BridgeConfig {
+                owner,
+                required_signatures,
+                max_signatures,
+                min_lock_amount,
+                relayer_reward,
+                max_tvl,
+                strict_mode,
+                churn_limit,
+                churn_window,
+                submission_mode,
+                operating_mode,
+                restricted_max_transfer,
+                restricted_timelock,
+                sunset,
+                paused_by,
+                paused_at,
+                pause_reason,
+            },
+            remainder,
+        ))
+    }
+}
+
+// Self-description used by relayers and SDKs to negotiate encodings
+struct BridgeMetadata {
+    name: String,
+    semver: String,
+    bridge_id: String,
+    supported_chains: Vec<String>,
+    supported_tokens: Vec<AssetId>,
+    digest_scheme_version: u8,
+}
+
+impl CLTyped for BridgeMetadata {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for BridgeMetadata {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.name.to_bytes()?);
+        result.extend(self.semver.to_bytes()?);
+        result.extend(self.bridge_id.to_bytes()?);
+        result.extend(self.supported_chains.to_bytes()?);
+        result.extend(self.supported_tokens.to_bytes()?);
+        result.extend(self.digest_scheme_version.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.semver.serialized_length()
+            + self.bridge_id.serialized_length()
+            + self.supported_chains.serialized_length()
+            + self.supported_tokens.serialized_length()
+            + self.digest_scheme_version.serialized_length()
+    }
+}
+
+impl FromBytes for BridgeMetadata {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (semver, remainder) = String::from_bytes(remainder)?;
+        let (bridge_id, remainder) = String::from_bytes(remainder)?;
+        let (supported_chains, remainder) = Vec::<String>::from_bytes(remainder)?;
+        let (supported_tokens, remainder) = Vec::<AssetId>::from_bytes(remainder)?;
+        let (digest_scheme_version, remainder) = u8::from_bytes(remainder)?;
+        Ok((
+            BridgeMetadata {
+                name,
+                semver,
+                bridge_id,
+                supported_chains,
+                supported_tokens,
+                digest_scheme_version,
+            },
+            remainder,
+        ))
+    }
+}
+
+// Helper functions
+fn get_key<T: FromBytes + CLTyped>(name: &str) -> T {
+    let key = runtime::get_key(name)
+        .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+    storage::read(key.into_uref().unwrap_or_revert())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
+    match runtime::get_key(name) {
+        Some(key) => {
+            let key_uref = key.into_uref().unwrap_or_revert();
+            storage::write(key_uref, value);
+        }
+        None => {
+            let key = storage::new_uref(value);
+            runtime::put_key(name, key.into());
+        }
+    }
+}
+
+fn get_dictionary(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert_with(casper_types::ApiError::MissingKey)
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+// Append a privileged call to the on-chain audit log
+fn record_audit(action: &str, params: &[u8]) {
+    let index: u64 = get_key(AUDIT_COUNT_KEY);
+    let entry = AuditEntry {
+        action: action.to_string(),
+        caller: Key::Account(runtime::get_caller()),
+        params_hash: runtime::blake2b(params),
+        timestamp: u64::from(runtime::get_blocktime()),
+    };
+
+    storage::dictionary_put(get_dictionary(AUDIT_LOG_KEY), &index.to_string(), entry);
+    set_key(AUDIT_COUNT_KEY, index + 1);
+}
+
+// Merkle tree node for a leaf: blake2b(0x00 || leaf)
+fn hash_leaf(leaf: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(33);
+    preimage.push(MERKLE_LEAF_PREFIX);
+    preimage.extend_from_slice(leaf);
+    runtime::blake2b(preimage)
+}
+
+// Merkle node over two children: blake2b(0x01 || left || right)
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(65);
+    preimage.push(MERKLE_NODE_PREFIX);
+    preimage.extend_from_slice(left);
+    preimage.extend_from_slice(right);
+    runtime::blake2b(preimage)
+}
+
+// Roots of empty subtrees of each height up to the full tree: zero[0] = [0; 32], zero[i + 1] = hash_pair(zero[i], zero[i])
+fn merkle_zero_hashes() -> Vec<[u8; 32]> {
+    let mut zero_hashes = vec![[0u8; 32]];
+    for level in 0..MERKLE_TREE_DEPTH {
+        zero_hashes.push(hash_pair(&zero_hashes[level], &zero_hashes[level]));
+    }
+    zero_hashes
+}
+
+// Root over consecutive leaf nodes padded with empty slots to the next power of two
+fn commitment_root(leaves: Vec<[u8; 32]>, zero_hashes: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    let depth = leaves.len().next_power_of_two().trailing_zeros() as usize;
+    let mut level = leaves;
+    for zero in zero_hashes.iter().take(depth) {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [left] => hash_pair(left, zero),
+                _ => runtime::revert(casper_types::ApiError::InvalidArgument),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+// Append a lock leaf to the incremental tree, storing every node so paths can be served
+// Empty subtrees hash to the zero hashes
+fn append_merkle_leaf(nonce: u64, leaf: [u8; 32]) {
+    let leaf_index: u64 = get_key(MERKLE_LEAF_COUNT_KEY);
+    if leaf_index >= 1u64 << MERKLE_TREE_DEPTH {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let nodes_dict = get_dictionary(MERKLE_NODES_KEY);
+    let zero_hashes: Vec<[u8; 32]> = get_key(MERKLE_ZERO_HASHES_KEY);
+
+    let mut index = leaf_index;
+    let mut node = hash_leaf(&leaf);
+    storage::dictionary_put(nodes_dict, &format!("0_{}", index), node);
+    for (level, zero) in zero_hashes.iter().take(MERKLE_TREE_DEPTH).enumerate() {
+        node = if index % 2 == 0 {
+            hash_pair(&node, zero)
+        } else {
+            let left: [u8; 32] = storage::dictionary_get(nodes_dict, &format!("{}_{}", level, index - 1))
+                .unwrap_or_revert()
+                .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+            hash_pair(&left, &node)
+        };
+        index /= 2;
+        storage::dictionary_put(nodes_dict, &format!("{}_{}", level + 1, index), node);
+    }
+
+    storage::dictionary_put(get_dictionary(MERKLE_LEAF_INDEX_KEY), &nonce.to_string(), leaf_index);
+    set_key(MERKLE_LEAF_COUNT_KEY, leaf_index + 1);
+    set_key(MERKLE_ROOT_KEY, node);
+}
+
+// Hex encoding for hashes used as dictionary item keys
+fn base16(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut encoded = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        encoded.push(DIGITS[(byte >> 4) as usize] as char);
+        encoded.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    encoded
+}
+
+// Release message validators sign, by digest scheme version
+// v1: "{source_chain}|{source_tx_hash}|{amount}|{nonce}|{log_index}" followed by the formatted recipient
+// v2: the version byte followed by the v1 message
+// v3: the v2 message followed by "|{source_sender}|{memo}", absent fields as empty strings
+// v4: the v3 message followed by "|{source_block}", the source chain block of the burn
+// v5: the v4 message followed by "|{bridge_id}", so a proof only verifies on the vault it was signed for
+fn release_message(version: u8, release: &ReleaseRecord, log_index: u32, bridge_id: &str) -> Vec<u8> {
+    let mut message = Vec::new();
+    if version >= 2 {
+        message.push(version);
+    }
+    message.extend_from_slice(
+        format!(
+            "{}|{}|{}|{}|{}",
+            release.source_chain, release.source_tx_hash, release.amount, release.nonce, log_index
+        )
+        .as_bytes(),
+    );
+    message.extend_from_slice(release.recipient.to_formatted_string().as_bytes());
+    if version >= 3 {
+        message.extend_from_slice(
+            format!(
+                "|{}|{}",
+                release.source_sender.as_deref().unwrap_or_default(),
+                release.memo.as_deref().unwrap_or_default()
+            )
+            .as_bytes(),
+        );
+    }
+    if version >= 4 {
+        message.extend_from_slice(format!("|{}", release.source_block.unwrap_or_default()).as_bytes());
+    }
+    if version >= 5 {
+        message.extend_from_slice(format!("|{}", bridge_id).as_bytes());
+    }
+    message
+}
+
+// Call on_bridge_event(event_kind, nonce, amount, record) on each contract subscribed to the event
+// Casper has no try-call, so a reverting listener reverts the lock or release; hooks are owner-vetted
+fn notify_hooks(event_kind: u8, nonce: u64, amount: U512, record: &[u8]) {
+    let hooks: Vec<(Key, u8)> = get_key(HOOKS_KEY);
+    for (listener, kind) in hooks {
+        if kind != event_kind {
+            continue;
+        }
+        let Some(contract_hash) = listener.into_hash_addr().map(ContractHash::new) else {
+            continue;
+        };
+        runtime::call_contract::<()>(
+            contract_hash,
+            HOOK_ENTRY_POINT,
+            runtime_args! {
+                "event_kind" => event_kind,
+                "nonce" => nonce,
+                "amount" => amount,
+                "record" => Bytes::from(record.to_vec()),
+            },
+        );
+    }
+}
+
+// A chain's latest validator-attested head as (block number, blocktime attested at)
+fn chain_head(chain: &str) -> Option<(u64, u64)> {
+    storage::dictionary_get(get_dictionary(CHAIN_HEADS_KEY), chain).unwrap_or_revert()
+}
+
+// Record a newer attested head for a chain; heads only move forward
+fn record_chain_head(chain: &str, block_number: u64) {
+    if chain_head(chain).is_some_and(|(head, _)| block_number <= head) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    let now = u64::from(runtime::get_blocktime());
+    storage::dictionary_put(get_dictionary(CHAIN_HEADS_KEY), chain, (block_number, now));
+}
+
+// Refuse releases from a chain whose validators stopped attesting its head within the chain's
+// halt timeout, bounding the damage from a halted or equivocating source chain
+fn require_fresh_source_chain(chain: &str) {
+    let timeout: Option<u64> = storage::dictionary_get(get_dictionary(CHAIN_HALT_TIMEOUTS_KEY), chain).unwrap_or_revert();
+    let Some(timeout) = timeout.filter(|timeout| *timeout > 0) else {
+        return;
+    };
+    let now = u64::from(runtime::get_blocktime());
+    let fresh = chain_head(chain).is_some_and(|(_, attested_at)| now - attested_at <= timeout);
+    if !fresh {
+        runtime::revert(casper_types::ApiError::User(ERROR_SOURCE_CHAIN_STALE));
+    }
+}
+
+// Revert if more than the chain's max proof age of checkpoints postdate the release's source
+// block, unless the owner allowlisted the proof; proofs without a source block (digest < 4)
+// cannot be dated, so chains with a limit reject them
+fn require_recent_proof(record: &ReleaseRecord, log_index: u32) {
+    let max_age: Option<u64> =
+        storage::dictionary_get(get_dictionary(MAX_PROOF_AGES_KEY), &record.source_chain).unwrap_or_revert();
+    let max_age = max_age.unwrap_or_default();
+    let allowed: Option<bool> = storage::dictionary_get(
+        get_dictionary(STALE_PROOF_ALLOWLIST_KEY),
+        &proof_key(&record.source_chain, record.nonce, log_index),
+    )
+    .unwrap_or_revert();
+    if max_age == 0 || allowed.unwrap_or_default() {
+        return;
+    }
+    let Some(source_block) = record.source_block else {
+        runtime::revert(casper_types::ApiError::User(ERROR_PROOF_TOO_OLD));
+    };
+
+    let count: u64 = storage::dictionary_get(get_dictionary(CHECKPOINT_COUNTS_KEY), &record.source_chain)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    if count <= max_age {
+        return;
+    }
+    // The oldest checkpoint that may postdate the source block
+    let cutoff: u64 = storage::dictionary_get(
+        get_dictionary(CHECKPOINT_BLOCKS_KEY),
+        &format!("{}_{}", record.source_chain, count - max_age - 1),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_revert();
+    if source_block < cutoff {
+        runtime::revert(casper_types::ApiError::User(ERROR_PROOF_TOO_OLD));
+    }
+}
+
+// Revert unless enough distinct active validators signed the message
+fn require_validator_quorum(message: &[u8], signatures: &[(Vec<u8>, Vec<u8>)]) {
+    let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
+    if count_valid_signatures(message, signatures) < required_sigs {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+}
+
+// Message validators sign to authorize an admin action: "{action}|{bridge_id}|{admin_nonce}"
+// The bridge id stops signatures carrying over to another vault, the nonce stops replays
+fn admin_message(action: &str) -> Vec<u8> {
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    Vec::from(format!("{}|{}|{}", action, bridge_id, admin_nonce).as_bytes())
+}
+
+// The active digest version, or the previous one while its transition window is open
+fn require_accepted_digest_version(version: u8) {
+    let active: u8 = get_key(DIGEST_VERSION_KEY);
+    if version == active {
+        return;
+    }
+
+    let previous: u8 = get_key(PREVIOUS_DIGEST_VERSION_KEY);
+    let deadline: u64 = get_key(PREVIOUS_DIGEST_DEADLINE_KEY);
+    if version != previous || u64::from(runtime::get_blocktime()) >= deadline {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+}
+
+// Distinct active validators with a valid Ed25519 signature over the message
+// Malformed entries, non-validators and repeated keys are ignored rather than reverting
+fn valid_signers(message: &[u8], signatures: &[(Vec<u8>, Vec<u8>)]) -> Vec<AccountHash> {
+    let validators_dict = get_dictionary(VALIDATORS_KEY);
+    signers_among(message, signatures, |validator| is_flagged_in(validators_dict, validator))
+}
+
+// Distinct accounts accepted by is_validator with a valid Ed25519 signature over the message
+fn signers_among(
+    message: &[u8],
+    signatures: &[(Vec<u8>, Vec<u8>)],
+    is_validator: impl Fn(Key) -> bool,
+) -> Vec<AccountHash> {
+    let mut signers: Vec<AccountHash> = Vec::new();
+
+    for (public_key_bytes, signature_bytes) in signatures {
+        let Some(public_key) = ed25519_public_key(public_key_bytes) else {
+            continue;
+        };
+        let Some(signature) = ed25519_signature(signature_bytes) else {
+            continue;
+        };
+
+        let signer = AccountHash::from_public_key(&public_key, runtime::blake2b);
+        if signers.contains(&signer) || !is_validator(Key::Account(signer)) {
+            continue;
+        }
+        if cryptography::verify_signature(message, &signature, &public_key).is_ok() {
+            signers.push(signer);
+        }
+    }
+
+    signers
+}
+
+// Credit each signer of a processed release on the validator scoreboard and in the current
+// reward epoch; scoreboard entries are (proofs signed, blocktime of the latest one)
+fn record_signers(signers: &[AccountHash]) {
+    let stats_dict = get_dictionary(VALIDATOR_STATS_KEY);
+    let epoch_dict = get_dictionary(EPOCH_SIGNATURES_KEY);
+    let now = u64::from(runtime::get_blocktime());
+    let epoch = epoch_at(now);
+    for signer in signers {
+        let item = format!("{:?}", Key::Account(*signer));
+        let (signed, _): (u64, u64) = storage::dictionary_get(stats_dict, &item).unwrap_or_revert().unwrap_or_default();
+        storage::dictionary_put(stats_dict, &item, (signed + 1, now));
+
+        let epoch_item = format!("{}_{}", epoch, item);
+        let epoch_signed: u64 = storage::dictionary_get(epoch_dict, &epoch_item).unwrap_or_revert().unwrap_or_default();
+        storage::dictionary_put(epoch_dict, &epoch_item, epoch_signed + 1);
+    }
+
+    let totals_dict = get_dictionary(EPOCH_SIGNATURE_TOTALS_KEY);
+    let epoch_item = epoch.to_string();
+    let total: u64 = storage::dictionary_get(totals_dict, &epoch_item).unwrap_or_revert().unwrap_or_default();
+    storage::dictionary_put(totals_dict, &epoch_item, total + signers.len() as u64);
+
+    let releases: u64 = get_key(RELEASE_COUNT_KEY);
+    set_key(RELEASE_COUNT_KEY, releases + 1);
+}
+
+// Index of the validator reward epoch containing the current blocktime
+fn current_epoch() -> u64 {
+    epoch_at(u64::from(runtime::get_blocktime()))
+}
+
+fn epoch_at(timestamp: u64) -> u64 {
+    let genesis: u64 = get_key(EPOCH_GENESIS_KEY);
+    (timestamp - genesis) / VALIDATOR_EPOCH_LENGTH
+}
+
+// An epoch's reward pool as (motes accrued, motes paid out or rolled forward)
+fn epoch_reward_pool(epoch: u64) -> (U512, U512) {
+    storage::dictionary_get(get_dictionary(EPOCH_REWARD_POOLS_KEY), &epoch.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+// A validator's pro-rata share of a closed epoch's pool, by signatures contributed
+fn validator_epoch_share(epoch: u64, validator: Key) -> U512 {
+    let signed: u64 = storage::dictionary_get(
+        get_dictionary(EPOCH_SIGNATURES_KEY),
+        &format!("{}_{:?}", epoch, validator),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default();
+    let total: u64 = storage::dictionary_get(get_dictionary(EPOCH_SIGNATURE_TOTALS_KEY), &epoch.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    if signed == 0 || total == 0 {
+        return U512::zero();
+    }
+
+    let (pool, _) = epoch_reward_pool(epoch);
+    pool * U512::from(signed) / U512::from(total)
+}
+
+// The organization (operator, cloud account, region...) a validator runs under; validators
+// never assigned one share the empty organization
+fn validator_organization(validator: Key) -> String {
+    storage::dictionary_get(get_dictionary(VALIDATOR_ORGANIZATIONS_KEY), &format!("{:?}", validator))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+// Revert unless the signers span the configured number of distinct organizations (0 disables)
+fn require_signer_diversity(signers: &[AccountHash]) {
+    let min_organizations: u32 = get_key(MIN_SIGNER_ORGANIZATIONS_KEY);
+    if min_organizations == 0 {
+        return;
+    }
+
+    let mut organizations: Vec<String> = Vec::new();
+    for signer in signers {
+        let organization = validator_organization(Key::Account(*signer));
+        if !organizations.contains(&organization) {
+            organizations.push(organization);
+        }
+    }
+    if (organizations.len() as u32) < min_organizations {
+        runtime::revert(casper_types::ApiError::User(ERROR_SIGNER_DIVERSITY));
+    }
+}
+
+fn count_valid_signatures(message: &[u8], signatures: &[(Vec<u8>, Vec<u8>)]) -> u32 {
+    valid_signers(message, signatures).len() as u32
+}
+
+// Parse a raw 32-byte Ed25519 public key
+fn ed25519_public_key(bytes: &[u8]) -> Option<PublicKey> {
+    let mut tagged_key = vec![casper_types::crypto::ED25519_TAG];
+    tagged_key.extend_from_slice(bytes);
+    match PublicKey::from_bytes(&tagged_key) {
+        Ok((public_key, [])) => Some(public_key),
+        _ => None,
+    }
+}
+
+// Parse a raw 64-byte Ed25519 signature
+fn ed25519_signature(bytes: &[u8]) -> Option<Signature> {
+    let raw_signature = <[u8; 64]>::try_from(bytes).ok()?;
+    Signature::ed25519(raw_signature).ok()
+}
+
+// Two-phase timelock: the first call queues the operation and returns false, a call with the
+// same action and params at least `delay` later returns true; calls in between revert
+// The operation id is the hex blake2b of the action name followed by the params
+fn timelock_ready(action: &str, params: &[u8], delay: u64) -> bool {
+    let mut preimage = Vec::from(action.as_bytes());
+    preimage.extend_from_slice(params);
+    let operation_id = base16(&runtime::blake2b(preimage));
+
+    let queue_dict = get_dictionary(TIMELOCK_QUEUE_KEY);
+    let now = u64::from(runtime::get_blocktime());
+    let eta: u64 = storage::dictionary_get(queue_dict, &operation_id)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+
+    if eta == 0 {
+        storage::dictionary_put(queue_dict, &operation_id, now + delay);
+        record_audit(&format!("queue_{}", action), params);
+        return false;
+    }
+    if now < eta {
+        runtime::revert(casper_types::ApiError::User(ERROR_TIMELOCK_PENDING));
+    }
+
+    storage::dictionary_put(queue_dict, &operation_id, 0u64);
+    true
+}
+
+// Store an AssetsSwept event
+fn record_sweep(record: SweepRecord) {
+    let index: u64 = get_key(SWEEP_COUNT_KEY);
+    runtime::put_key(&format!("assets_swept_{}", index), storage::new_uref(record).into());
+    set_key(SWEEP_COUNT_KEY, index + 1);
+}
+
+// Processed-proof key: "{source_chain}_{nonce}_{log_index}"
+// Nonces are per source chain, and a single source tx can carry several burns, told apart by log index
+fn proof_key(source_chain: &str, nonce: u64, log_index: u32) -> String {
+    format!("{}_{}_{}", source_chain, nonce, log_index)
+}
+
+// Validators are kept in a lookup dictionary and an enumerable list
+fn set_validator(validator: Key, active: bool) {
+    storage::dictionary_put(get_dictionary(VALIDATORS_KEY), &format!("{:?}", validator), active);
+
+    let mut validators: Vec<Key> = get_key(VALIDATOR_LIST_KEY);
+    match (active, validators.iter().position(|v| *v == validator)) {
+        (true, None) => validators.push(validator),
+        (false, Some(index)) => {
+            validators.remove(index);
+        }
+        _ => return,
+    }
+    set_key(VALIDATOR_LIST_KEY, validators);
+}
+
+// Count one validator set change against the current churn window
+fn record_churn() {
+    let limit: u32 = get_key(CHURN_LIMIT_KEY);
+    if limit == 0 {
+        return;
+    }
+
+    let window: u64 = get_key(CHURN_WINDOW_KEY);
+    let now = u64::from(runtime::get_blocktime());
+    let mut window_start: u64 = get_key(CHURN_WINDOW_START_KEY);
+    let mut count: u32 = get_key(CHURN_COUNT_KEY);
+
+    if now >= window_start.saturating_add(window) {
+        window_start = now;
+        count = 0;
+    }
+
+    if count >= limit {
+        runtime::revert(casper_types::ApiError::User(ERROR_CHURN_LIMIT_EXCEEDED));
+    }
+
+    set_key(CHURN_WINDOW_START_KEY, window_start);
+    set_key(CHURN_COUNT_KEY, count + 1);
+}
+
+// Count as much of a payout as fits in the recipient's remaining window allowance against it
+// Returns the part that fits, which can be paid now; the rest must wait for the timelock
+fn take_recipient_allowance(recipient: Key, payout: U512) -> U512 {
+    let limit: U512 = get_key(RECIPIENT_LIMIT_KEY);
+    if limit.is_zero() {
+        return payout;
+    }
+
+    let item = format!("{:?}", recipient);
+    let starts_dict = get_dictionary(RECIPIENT_WINDOW_STARTS_KEY);
+    let released_dict = get_dictionary(RECIPIENT_RELEASED_KEY);
+
+    let window: u64 = get_key(RECIPIENT_WINDOW_KEY);
+    let now = u64::from(runtime::get_blocktime());
+    let mut window_start: u64 = storage::dictionary_get(starts_dict, &item).unwrap_or_revert().unwrap_or_default();
+    let mut released: U512 = storage::dictionary_get(released_dict, &item).unwrap_or_revert().unwrap_or_default();
+
+    if now >= window_start.saturating_add(window) {
+        window_start = now;
+        released = U512::zero();
+    }
+
+    let allowed = payout.min(limit.saturating_sub(released));
+    storage::dictionary_put(starts_dict, &item, window_start);
+    storage::dictionary_put(released_dict, &item, released + allowed);
+    allowed
+}
+
+// Fold a release into the current anomaly window, returning false without recording it if
+// the window would exceed the distinct-recipient bound or leave the average release size bounds
+// The average is only checked once the window holds at least anomaly_min_sample releases
+fn release_within_anomaly_bounds(recipient: Key, amount: U512) -> bool {
+    let window: u64 = get_key(ANOMALY_WINDOW_KEY);
+    let now = u64::from(runtime::get_blocktime());
+    let mut window_start: u64 = get_key(ANOMALY_WINDOW_START_KEY);
+    let mut recipients: u32 = get_key(ANOMALY_RECIPIENT_COUNT_KEY);
+    let mut releases: u32 = get_key(ANOMALY_RELEASE_COUNT_KEY);
+    let mut volume: U512 = get_key(ANOMALY_RELEASE_VOLUME_KEY);
+
+    if now >= window_start.saturating_add(window) {
+        window_start = now;
+        recipients = 0;
+        releases = 0;
+        volume = U512::zero();
+    }
+
+    // Keyed by window start so each window counts recipients afresh
+    let seen_item = format!("{}_{:?}", window_start, recipient);
+    let seen_dict = get_dictionary(ANOMALY_SEEN_RECIPIENTS_KEY);
+    let seen: Option<bool> = storage::dictionary_get(seen_dict, &seen_item).unwrap_or_revert();
+    if seen.is_none() {
+        recipients += 1;
+    }
+    releases += 1;
+    volume += amount;
+
+    let max_recipients: u32 = get_key(ANOMALY_MAX_RECIPIENTS_KEY);
+    if max_recipients > 0 && recipients > max_recipients {
+        return false;
+    }
+
+    let min_sample: u32 = get_key(ANOMALY_MIN_SAMPLE_KEY);
+    if releases >= min_sample {
+        let average = volume / U512::from(releases);
+        let min_average: U512 = get_key(ANOMALY_MIN_AVERAGE_KEY);
+        let max_average: U512 = get_key(ANOMALY_MAX_AVERAGE_KEY);
+        if (!min_average.is_zero() && average < min_average) || (!max_average.is_zero() && average > max_average) {
+            return false;
+        }
+    }
+
+    storage::dictionary_put(seen_dict, &seen_item, true);
+    set_key(ANOMALY_WINDOW_START_KEY, window_start);
+    set_key(ANOMALY_RECIPIENT_COUNT_KEY, recipients);
+    set_key(ANOMALY_RELEASE_COUNT_KEY, releases);
+    set_key(ANOMALY_RELEASE_VOLUME_KEY, volume);
+    true
+}
+
+fn is_flagged(dictionary: &str, address: Key) -> bool {
+    is_flagged_in(get_dictionary(dictionary), address)
+}
+
+// Same as is_flagged with the dictionary already resolved, for lookups in a loop
+fn is_flagged_in(dictionary: URef, address: Key) -> bool {
+    let flag: Option<bool> = storage::dictionary_get(dictionary, &format!("{:?}", address)).unwrap_or_revert();
+    flag.unwrap_or(false)
+}
+
+// Enforce the configured submission mode on the caller
+fn require_submitter() {
+    let caller = Key::Account(runtime::get_caller());
+    let mode: u8 = get_key(SUBMISSION_MODE_KEY);
+    let allowed = match mode {
+        SUBMISSION_MODE_RELAYERS => is_flagged(RELAYERS_KEY, caller),
+        SUBMISSION_MODE_VALIDATORS => is_flagged(VALIDATORS_KEY, caller),
+        _ => true,
+    };
+    if !allowed {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+}
+
+fn previous_vault() -> Option<ContractHash> {
+    runtime::get_key(PREVIOUS_VAULT_KEY)?;
+    let previous: Key = get_key(PREVIOUS_VAULT_KEY);
+    previous.into_hash_addr().map(ContractHash::new)
+}
+
+// Hash of the contract that called the current entry point, None when an account called it
+fn immediate_caller_contract() -> Option<HashAddr> {
+    let caller = runtime::get_immediate_caller().unwrap_or_revert();
+    let field = |index: u8| caller.get_field_by_index(index).cloned();
+    let contract = field(CALLER_CONTRACT_FIELD).and_then(|value| value.into_t::<Option<ContractHash>>().ok()).flatten();
+    let entity = field(CALLER_ENTITY_FIELD).and_then(|value| value.into_t::<Option<EntityAddr>>().ok()).flatten();
+    contract.map(|contract| contract.value()).or(entity.map(|entity| entity.value()))
+}
+
+fn vault_purse() -> URef {
+    runtime::get_key(VAULT_PURSE_KEY)
+        .unwrap_or_revert_with(casper_types::ApiError::MissingKey)
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+// Add to a claimable balance held in a dictionary (rewards, gas refunds or release dust)
+fn credit_relayer(dictionary: &str, relayer: Key, amount: U512) {
+    let balances_dict = get_dictionary(dictionary);
+    let relayer_key = format!("{:?}", relayer);
+    let current: U512 = storage::dictionary_get(balances_dict, &relayer_key)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    storage::dictionary_put(balances_dict, &relayer_key, current + amount);
+
+    let unclaimed: U512 = get_key(UNCLAIMED_TOTAL_KEY);
+    set_key(UNCLAIMED_TOTAL_KEY, unclaimed + amount);
+
+    // BalanceCredited event: (relayer, balance dictionary, amount)
+    let index: u64 = get_key(CREDIT_COUNT_KEY);
+    runtime::put_key(
+        &format!("balance_credited_{}", index),
+        storage::new_uref((relayer, String::from(dictionary), amount)).into(),
+    );
+    set_key(CREDIT_COUNT_KEY, index + 1);
+}
+
+// Pay out and zero the caller's balance held in a dictionary
+fn claim_relayer_balance(dictionary: &str) {
+    let caller = runtime::get_caller();
+    let balances_dict = get_dictionary(dictionary);
+    let relayer_key = format!("{:?}", Key::Account(caller));
+
+    let owed: U512 = storage::dictionary_get(balances_dict, &relayer_key)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    if owed.is_zero() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(balances_dict, &relayer_key, U512::zero());
+    let unclaimed: U512 = get_key(UNCLAIMED_TOTAL_KEY);
+    set_key(UNCLAIMED_TOTAL_KEY, unclaimed - owed);
+
+    system::transfer_from_purse_to_account(vault_purse(), caller, owed, None).unwrap_or_revert();
+}
+
+fn relayer_balance(dictionary: &str, relayer: Key) -> U512 {
+    storage::dictionary_get(get_dictionary(dictionary), &format!("{:?}", relayer))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn require_owner() {
+    let owner: Key = get_key(OWNER_KEY);
+    let caller = runtime::get_caller();
+    if Key::Account(caller) != owner {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+}
+
+// Owner check for configuration changes, which are frozen once the vault is sunset
+fn require_config_owner() {
+    require_owner();
+    let sunset: bool = get_key(SUNSET_KEY);
+    if sunset {
+        runtime::revert(casper_types::ApiError::User(ERROR_SUNSET));
+    }
+}
+
+fn require_not_paused() {
+    let mode: u8 = get_key(OPERATING_MODE_KEY);
+    if mode == MODE_HALTED {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+}
+
+// Switch the operating mode; entering Restricted or Halted records who, when and why
+// Returning to Normal starts a fresh anomaly window so the release that tripped the breaker can go through
+fn enter_operating_mode(mode: u8, by: Key, reason: Option<String>) {
+    set_key(OPERATING_MODE_KEY, mode);
+    if mode == MODE_NORMAL {
+        set_key(ANOMALY_WINDOW_START_KEY, 0u64);
+        return;
+    }
+    set_key(PAUSED_BY_KEY, Some(by));
+    set_key(PAUSED_AT_KEY, u64::from(runtime::get_blocktime()));
+    set_key(PAUSE_REASON_KEY, reason);
+}
+
+// Initialize the contract (called once by the installer in the install deploy)
+#[no_mangle]
+pub extern "C" fn init() {
+    let required_sigs: u32 = runtime::get_named_arg("required_sigs");
+    let min_amount: U512 = runtime::get_named_arg("min_amount");
+    // Initial validator set, defaulting to the owner alone
+    let validators: Vec<Key> = runtime::get_named_arg::<Option<Vec<Key>>>("validators")
+        .unwrap_or_else(|| vec![Key::Account(runtime::get_caller())]);
+    let vault_hash: ContractHash = runtime::get_named_arg("vault_hash");
+
+    initialize(required_sigs, min_amount, &validators, vault_hash);
+
+    let mut params = required_sigs.to_bytes().unwrap_or_revert();
+    params.extend(min_amount.to_bytes().unwrap_or_revert());
+    params.extend(validators.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_INIT, &params);
+}
+
+// Initialize a replacement vault with the governance state exported from a previous one
+// (export_config), attested by a quorum of the previous vault's current validators signing
+// "RESTORE|{previous bridge_id}|{hex blake2b of the snapshot}"; balances start empty
+// The previous vault stays linked, so proofs it processed are still rejected here
+#[no_mangle]
+pub extern "C" fn init_from_snapshot() {
+    let snapshot_bytes: Bytes = runtime::get_named_arg("snapshot");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("attestation_signatures");
+    let previous_vault: Key = runtime::get_named_arg("previous_vault");
+    let vault_hash: ContractHash = runtime::get_named_arg("vault_hash");
+
+    let snapshot: ConfigSnapshot = bytesrepr::deserialize(snapshot_bytes.to_vec()).unwrap_or_revert();
+    if snapshot.max_signatures < snapshot.required_signatures
+        || snapshot.digest_version == 0
+        || snapshot.digest_version > LATEST_DIGEST_VERSION
+    {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    // The attestation is checked against the validator set and threshold the previous vault
+    // holds on chain, not the ones the snapshot claims, before anything is written
+    let previous = previous_vault
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument);
+    let previous_validators: Vec<Key> = runtime::call_contract(previous, ENTRY_POINT_GET_VALIDATORS, runtime_args! {});
+    let previous_config: BridgeConfig = runtime::call_contract(previous, ENTRY_POINT_GET_CONFIG, runtime_args! {});
+    let previous_metadata: BridgeMetadata = runtime::call_contract(previous, ENTRY_POINT_GET_METADATA, runtime_args! {});
+
+    let snapshot_hash = runtime::blake2b(snapshot_bytes.as_slice());
+    let message = format!("RESTORE|{}|{}", previous_metadata.bridge_id, base16(&snapshot_hash));
+    if signatures.len() > previous_config.max_signatures as usize {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    let signers = signers_among(message.as_bytes(), &signatures, |validator| previous_validators.contains(&validator));
+    if (signers.len() as u32) < previous_config.required_signatures {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+
+    initialize(snapshot.required_signatures, snapshot.min_lock_amount, &snapshot.validators, vault_hash);
+    set_key(MAX_SIGNATURES_KEY, snapshot.max_signatures);
+    set_key(PREVIOUS_VAULT_KEY, previous_vault);
+
+    set_key(RELAYER_REWARD_KEY, snapshot.relayer_reward);
+    set_key(VALIDATOR_FEE_KEY, snapshot.validator_fee);
+    set_key(MAX_TVL_KEY, snapshot.max_tvl);
+    set_key(CHURN_LIMIT_KEY, snapshot.churn_limit);
+    set_key(CHURN_WINDOW_KEY, snapshot.churn_window);
+    set_key(SUBMISSION_MODE_KEY, snapshot.submission_mode);
+    set_key(SUPPORTED_CHAINS_KEY, snapshot.supported_chains);
+    set_key(DIGEST_VERSION_KEY, snapshot.digest_version);
+    set_key(PREVIOUS_DIGEST_VERSION_KEY, snapshot.digest_version.saturating_sub(1));
+    set_key(RECIPIENT_LIMIT_KEY, snapshot.recipient_limit);
+    set_key(RECIPIENT_WINDOW_KEY, snapshot.recipient_window);
+    set_key(DUST_THRESHOLD_KEY, snapshot.dust_threshold);
+    set_key(STRICT_MODE_KEY, snapshot.strict_mode);
+    set_key(RESTRICTED_MAX_TRANSFER_KEY, snapshot.restricted_max_transfer);
+    set_key(RESTRICTED_TIMELOCK_KEY, snapshot.restricted_timelock);
+
+    record_audit(ENTRY_POINT_INIT_FROM_SNAPSHOT, &snapshot_hash);
+}
+
+// Storage setup shared by init and init_from_snapshot; reverts if already initialized
+// The bridge id is the hex of the vault's own contract hash, so no two vaults share one
+fn initialize(required_sigs: u32, min_amount: U512, validators: &[Key], vault_hash: ContractHash) {
+    if runtime::get_key(OWNER_KEY).is_some() {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+    let caller = runtime::get_caller();
+
+    if required_sigs == 0 || required_sigs as usize > validators.len() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    // Initialize storage
+    set_key(OWNER_KEY, Key::Account(caller));
+    set_key(BRIDGE_ID_KEY, base16(&vault_hash.value()));
+    set_key(ADMIN_NONCE_KEY, 0u64);
+    // How tokens see the vault as a holder
+    set_key(SELF_KEY, Key::Hash(vault_hash.value()));
+    set_key(SWEEP_COUNT_KEY, 0u64);
+    set_key(CREDIT_COUNT_KEY, 0u64);
+    set_key(RELEASE_COUNT_KEY, 0u64);
+    set_key(VALIDATOR_FEE_KEY, U512::zero());
+    set_key(EPOCH_GENESIS_KEY, u64::from(runtime::get_blocktime()));
+    storage::new_dictionary(EPOCH_REWARD_POOLS_KEY).unwrap_or_revert();
+    storage::new_dictionary(EPOCH_SIGNATURE_TOTALS_KEY).unwrap_or_revert();
+    storage::new_dictionary(EPOCH_SIGNATURES_KEY).unwrap_or_revert();
+    storage::new_dictionary(VALIDATOR_STATS_KEY).unwrap_or_revert();
+    set_key(SUPPORTED_CHAINS_KEY, vec![String::from(DEFAULT_SUPPORTED_CHAIN)]);
+    set_key(DIGEST_VERSION_KEY, LATEST_DIGEST_VERSION);
+    set_key(PREVIOUS_DIGEST_VERSION_KEY, LATEST_DIGEST_VERSION - 1);
+    set_key(PREVIOUS_DIGEST_DEADLINE_KEY, 0u64);
+    set_key(REQUIRED_SIGNATURES_KEY, required_sigs);
+    set_key(MAX_SIGNATURES_KEY, DEFAULT_MAX_SIGNATURES.max(required_sigs));
+    set_key(MIN_LOCK_AMOUNT_KEY, min_amount);
+    set_key(OPERATING_MODE_KEY, MODE_NORMAL);
+    set_key(RESTRICTED_MAX_TRANSFER_KEY, U512::zero());
+    set_key(RESTRICTED_TIMELOCK_KEY, DEFAULT_RESTRICTED_TIMELOCK);
+    set_key(PAUSED_BY_KEY, Option::<Key>::None);
+    set_key(PAUSED_AT_KEY, 0u64);
+    set_key(PAUSE_REASON_KEY, Option::<String>::None);
+    set_key(NONCE_KEY, 0u64);
+    set_key(TOTAL_LOCKED_KEY, U512::zero());
+    set_key(RELAYER_REWARD_KEY, U512::zero());
+    set_key(UNCLAIMED_TOTAL_KEY, U512::zero());
+    set_key(SUBMISSION_MODE_KEY, SUBMISSION_MODE_ANYONE);
+    set_key(MAX_TVL_KEY, U512::zero());
+    set_key(DUST_THRESHOLD_KEY, U512::zero());
+    storage::new_dictionary(DUST_BALANCES_KEY).unwrap_or_revert();
+    set_key(SUNSET_KEY, false);
+    set_key(STRICT_MODE_KEY, false);
+    set_key(HOOKS_KEY, Vec::<(Key, u8)>::new());
+    set_key(GAS_COST_BASE_KEY, U512::zero());
+    set_key(GAS_COST_PER_SIGNATURE_KEY, U512::zero());
+    set_key(GAS_COST_PER_BYTE_KEY, U512::zero());
+    set_key(CHURN_LIMIT_KEY, 0u32);
+    set_key(CHURN_WINDOW_KEY, DEFAULT_CHURN_WINDOW);
+    set_key(CHURN_WINDOW_START_KEY, 0u64);
+    set_key(CHURN_COUNT_KEY, 0u32);
+    set_key(RECIPIENT_LIMIT_KEY, U512::zero());
+    set_key(RECIPIENT_WINDOW_KEY, DEFAULT_RECIPIENT_WINDOW);
+    set_key(QUEUED_RELEASE_TOTAL_KEY, U512::zero());
+    set_key(ANOMALY_WINDOW_KEY, DEFAULT_ANOMALY_WINDOW);
+    set_key(ANOMALY_MAX_RECIPIENTS_KEY, 0u32);
+    set_key(ANOMALY_MIN_AVERAGE_KEY, U512::zero());
+    set_key(ANOMALY_MAX_AVERAGE_KEY, U512::zero());
+    set_key(ANOMALY_MIN_SAMPLE_KEY, 0u32);
+    set_key(ANOMALY_WINDOW_START_KEY, 0u64);
+    set_key(ANOMALY_RECIPIENT_COUNT_KEY, 0u32);
+    set_key(ANOMALY_RELEASE_COUNT_KEY, 0u32);
+    set_key(ANOMALY_RELEASE_VOLUME_KEY, U512::zero());
+    storage::new_dictionary(ANOMALY_SEEN_RECIPIENTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(RECIPIENT_WINDOW_STARTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(RECIPIENT_RELEASED_KEY).unwrap_or_revert();
+    storage::new_dictionary(QUEUED_RELEASES_KEY).unwrap_or_revert();
+    storage::new_dictionary(QUEUED_RELEASE_INDEX_KEY).unwrap_or_revert();
+    set_key(QUEUED_RELEASE_COUNT_KEY, 0u64);
+
+    // Purse holding all locked CSPR
+    let vault_purse = system::create_purse();
+    runtime::put_key(VAULT_PURSE_KEY, vault_purse.into());
+
+    // Initialize validator set
+    storage::new_dictionary(VALIDATORS_KEY).unwrap_or_revert();
+    set_key(VALIDATOR_LIST_KEY, Vec::<Key>::new());
+    for validator in validators {
+        set_validator(*validator, true);
+    }
+
+    // Initialize lock record hashes
+    storage::new_dictionary(LOCK_LEAVES_KEY).unwrap_or_revert();
+
+    // Initialize the empty lock tree
+    let zero_hashes = merkle_zero_hashes();
+    set_key(MERKLE_ROOT_KEY, zero_hashes[MERKLE_TREE_DEPTH]);
+    set_key(MERKLE_ZERO_HASHES_KEY, zero_hashes);
+    set_key(MERKLE_LEAF_COUNT_KEY, 0u64);
+    storage::new_dictionary(MERKLE_NODES_KEY).unwrap_or_revert();
+    storage::new_dictionary(MERKLE_LEAF_INDEX_KEY).unwrap_or_revert();
+
+    // Initialize processed proofs dictionary
+    storage::new_dictionary(PROCESSED_PROOFS_KEY).unwrap_or_revert();
+    storage::new_dictionary(TOKEN_MAP_KEY).unwrap_or_revert();
+    storage::new_dictionary(PENDING_PROOFS_KEY).unwrap_or_revert();
+    storage::new_dictionary(PENDING_SIGNERS_KEY).unwrap_or_revert();
+
+    // Initialize relayer reward and gas refund balances
+    storage::new_dictionary(RELAYER_REWARDS_KEY).unwrap_or_revert();
+    storage::new_dictionary(GAS_REFUNDS_KEY).unwrap_or_revert();
+
+    // Initialize per-chain precision and finality parameters
+    storage::new_dictionary(CHAIN_DECIMALS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_MIN_CONFIRMATIONS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_CHALLENGE_WINDOW_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_ADDRESS_FORMATS_KEY).unwrap_or_revert();
+    storage::new_dictionary(PEER_VAULTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_HEADS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_CHECKPOINTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHECKPOINT_COUNTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHECKPOINT_BLOCKS_KEY).unwrap_or_revert();
+    storage::new_dictionary(MAX_PROOF_AGES_KEY).unwrap_or_revert();
+    storage::new_dictionary(STALE_PROOF_ALLOWLIST_KEY).unwrap_or_revert();
+    storage::new_dictionary(USER_RELEASE_COUNTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(USER_RELEASES_KEY).unwrap_or_revert();
+    set_key(COMPACT_EVENTS_KEY, false);
+    storage::new_dictionary(LOCK_RECORDS_KEY).unwrap_or_revert();
+    storage::new_dictionary(RELEASE_RECORDS_KEY).unwrap_or_revert();
+    storage::new_dictionary(CHAIN_HALT_TIMEOUTS_KEY).unwrap_or_revert();
+
+    // Initialize deposit routes
+    storage::new_dictionary(DEPOSIT_ROUTES_KEY).unwrap_or_revert();
+    storage::new_dictionary(DEPOSIT_ROUTE_IDS_KEY).unwrap_or_revert();
+    set_key(DEPOSIT_ROUTE_COUNT_KEY, 0u64);
+
+    // Initialize timelocked operation queue
+    storage::new_dictionary(TIMELOCK_QUEUE_KEY).unwrap_or_revert();
+
+    // Initialize relayer registry
+    storage::new_dictionary(RELAYERS_KEY).unwrap_or_revert();
+    storage::new_dictionary(GUARDIANS_KEY).unwrap_or_revert();
+    storage::new_dictionary(FEE_EXEMPT_KEY).unwrap_or_revert();
+    storage::new_dictionary(RELAY_COSTS_KEY).unwrap_or_revert();
+    storage::new_dictionary(VALIDATOR_ORGANIZATIONS_KEY).unwrap_or_revert();
+    set_key(MIN_SIGNER_ORGANIZATIONS_KEY, 0u32);
+    set_key(UPGRADE_PROPOSAL_KEY, Option::<UpgradeProposal>::None);
+    set_key(UPGRADE_APPROVED_KEY, false);
+    set_key(SHADOW_DIGEST_VERSION_KEY, 0u8);
+    set_key(SHADOW_AGREE_COUNT_KEY, 0u64);
+    set_key(SHADOW_DISAGREE_COUNT_KEY, 0u64);
+    set_key(SUBMISSION_BOND_KEY, U512::zero());
+    storage::new_dictionary(SUBMISSION_BONDS_KEY).unwrap_or_revert();
+    set_key(BOND_FORFEIT_COUNT_KEY, 0u64);
+    storage::new_dictionary(LOCK_ESCROWS_KEY).unwrap_or_revert();
+    set_key(OPEN_ESCROW_TOTAL_KEY, U512::zero());
+    set_key(GAS_ORACLE_KEY, Option::<Key>::None);
+    set_key(MIN_LOCK_MULTIPLIER_KEY, 0u32);
+    set_key(FEE_EXEMPTION_COUNT_KEY, 0u64);
+    storage::new_dictionary(DISPUTES_KEY).unwrap_or_revert();
+
+    // Initialize audit log
+    storage::new_dictionary(AUDIT_LOG_KEY).unwrap_or_revert();
+    set_key(AUDIT_COUNT_KEY, 0u64);
+}
+
+// The larger of the static minimum and the destination's relay cost scaled by the safety
+// multiplier, once the owner enables the multiplier and the gas oracle has reported a cost
+fn min_lock_amount(chain: &str) -> U512 {
+    let static_min: U512 = get_key(MIN_LOCK_AMOUNT_KEY);
+    let multiplier_bps: u32 = get_key(MIN_LOCK_MULTIPLIER_KEY);
+    if multiplier_bps == 0 {
+        return static_min;
+    }
+    let relay_cost: Option<U512> = storage::dictionary_get(get_dictionary(RELAY_COSTS_KEY), chain).unwrap_or_revert();
+    let dynamic_min = relay_cost.unwrap_or_default() * U512::from(multiplier_bps) / U512::from(BPS_DENOMINATOR);
+    static_min.max(dynamic_min)
+}
+
+// Move motes from the caller's purse into the vault and record the lock
+fn lock_native(destination_chain: String, destination_address: String, route: Vec<Hop>, amount: U512, purse: URef) {
+    require_not_paused();
+    let sunset: bool = get_key(SUNSET_KEY);
+    if sunset {
+        runtime::revert(casper_types::ApiError::User(ERROR_SUNSET));
+    }
+
+    if route.len() > MAX_ROUTE_HOPS || route.iter().any(|hop| hop.chain.is_empty() || hop.address.is_empty()) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let min_amount = min_lock_amount(&destination_chain);
+    if amount < min_amount {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let mode: u8 = get_key(OPERATING_MODE_KEY);
+    let restricted_max: U512 = get_key(RESTRICTED_MAX_TRANSFER_KEY);
+    if mode == MODE_RESTRICTED && amount > restricted_max {
+        runtime::revert(casper_types::ApiError::User(ERROR_RESTRICTED));
+    }
+
+    // Reject amounts whose low-order motes the destination token cannot represent
+    let destination_decimals: Option<u8> =
+        storage::dictionary_get(get_dictionary(CHAIN_DECIMALS_KEY), &destination_chain).unwrap_or_revert();
+    if let Some(decimals) = destination_decimals.filter(|d| *d < CSPR_DECIMALS) {
+        let unit = U512::from(10u64).pow(U512::from(CSPR_DECIMALS - decimals));
+        if !(amount % unit).is_zero() {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        }
+    }
+
+    require_valid_address(&destination_chain, &destination_address);
+    for hop in &route {
+        require_valid_address(&hop.chain, &hop.address);
+    }
+
+    let remote_token = token_mapping(&AssetId::Native, &destination_chain);
+
+    // Strict mode: only bridge where the other side is registered and knows what to mint
+    let strict_mode: bool = get_key(STRICT_MODE_KEY);
+    if strict_mode {
+        let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+        if remote_token.is_none()
+            || !chains.contains(&destination_chain)
+            || route.iter().any(|hop| !chains.contains(&hop.chain))
+        {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        }
+    }
+
+    system::transfer_from_purse_to_purse(purse, vault_purse(), amount, None).unwrap_or_revert();
+
+    let caller = runtime::get_caller();
+
+    // Update total locked, staying within the exposure cap (0 = uncapped)
+    let new_locked: U512 = get_key::<U512>(TOTAL_LOCKED_KEY) + amount;
+    let max_tvl: U512 = get_key(MAX_TVL_KEY);
+    if !max_tvl.is_zero() && new_locked > max_tvl {
+        runtime::revert(casper_types::ApiError::User(ERROR_TVL_CAP_REACHED));
+    }
+    set_key(TOTAL_LOCKED_KEY, new_locked);
+
+    // Increment nonce
+    let current_nonce: u64 = get_key(NONCE_KEY);
+    set_key(NONCE_KEY, current_nonce + 1);
+
+    // TvlCapReached: no further lock of the minimum size fits under the cap
+    if !max_tvl.is_zero() && max_tvl - new_locked < min_amount {
+        runtime::put_key(&format!("tvl_cap_reached_{}", current_nonce), storage::new_uref(new_locked).into());
+    }
+
+    // Emit event (in Casper, we store event data in named keys)
+    let event_name = format!("asset_locked_{}", current_nonce);
+    let record = LockRecord {
+        asset: AssetId::Native,
+        amount,
+        nonce: current_nonce,
+        sender: Key::Account(caller),
+        destination_chain,
+        destination_address,
+        route,
+        remote_token,
+    };
+
+    // Track the lock in its own escrow record until delivery or refund
+    storage::dictionary_put(
+        get_dictionary(LOCK_ESCROWS_KEY),
+        &current_nonce.to_string(),
+        (record.sender, amount, ESCROW_LOCKED),
+    );
+    let open: U512 = get_key(OPEN_ESCROW_TOTAL_KEY);
+    set_key(OPEN_ESCROW_TOTAL_KEY, open + amount);
+
+    // Leaf of the lock tree, so other chains can verify single locks and ranges
+    let leaf = runtime::blake2b(record.to_bytes().unwrap_or_revert());
+    storage::dictionary_put(get_dictionary(LOCK_LEAVES_KEY), &current_nonce.to_string(), leaf);
+    append_merkle_leaf(current_nonce, leaf);
+
+    notify_hooks(HOOK_EVENT_LOCK, current_nonce, amount, &record.to_bytes().unwrap_or_revert());
+    emit_record(&event_name, LOCK_RECORDS_KEY, &current_nonce.to_string(), current_nonce, record);
+}
+
+// "0x" followed by 40 hex digits
+fn is_evm_address(address: &str) -> bool {
+    address.len() == 42 && address.starts_with("0x") && address[2..].bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+// Base58 (Bitcoin alphabet) encoding of a 32-byte public key
+fn is_solana_address(address: &str) -> bool {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    if address.len() < 32 || address.len() > 44 {
+        return false;
+    }
+
+    // Big-endian base256 digits of the decoded value
+    let mut decoded: Vec<u8> = Vec::new();
+    for c in address.bytes() {
+        let mut carry = match ALPHABET.iter().position(|a| *a == c) {
+            Some(digit) => digit as u32,
+            None => return false,
+        };
+        for byte in decoded.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            decoded.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Each leading '1' encodes a leading zero byte
+    let leading_zeros = address.bytes().take_while(|c| *c == b'1').count();
+    leading_zeros + decoded.len() == 32
+}
+
+// BIP-173 bech32 address with the given human-readable part, as used by Cosmos chains
+fn is_bech32_address(address: &str, hrp: &str) -> bool {
+    const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    // Mixed case is invalid
+    let has_lower = address.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = address.bytes().any(|b| b.is_ascii_uppercase());
+    if address.len() > 90 || (has_lower && has_upper) {
+        return false;
+    }
+    let address = address.to_ascii_lowercase();
+    let (prefix, data) = match address.rfind('1') {
+        Some(split) => (&address[..split], &address[split + 1..]),
+        None => return false,
+    };
+    if prefix.is_empty() || prefix != hrp.to_ascii_lowercase() || data.len() < 6 {
+        return false;
+    }
+
+    // Expanded hrp followed by the data values
+    let mut values: Vec<u8> = prefix.bytes().map(|b| b >> 5).collect();
+    values.push(0);
+    values.extend(prefix.bytes().map(|b| b & 0x1f));
+    for c in data.bytes() {
+        match CHARSET.iter().position(|a| *a == c) {
+            Some(value) => values.push(value as u8),
+            None => return false,
+        }
+    }
+
+    let mut checksum: u32 = 1;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ u32::from(value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum == 1
+}
+
+// "account-hash-" followed by 64 hex digits, the recipient form of release messages
+fn is_casper_account(address: &str) -> bool {
+    address
+        .strip_prefix("account-hash-")
+        .is_some_and(|hash| hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+// The vault deployed on another Casper network that a chain name refers to, if registered
+fn peer_vault(chain: &str) -> Option<Key> {
+    storage::dictionary_get::<Option<Key>>(get_dictionary(PEER_VAULTS_KEY), chain)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+// Revert unless the address matches the format configured for its chain
+// Peer vault chains always take Casper account hashes, since their releases pay accounts
+fn require_valid_address(chain: &str, address: &str) {
+    if peer_vault(chain).is_some() {
+        if !is_casper_account(address) {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        }
+        return;
+    }
+
+    let format: Option<(u8, String)> =
+        storage::dictionary_get(get_dictionary(CHAIN_ADDRESS_FORMATS_KEY), chain).unwrap_or_revert();
+    let valid = match format {
+        Some((ADDRESS_FORMAT_EVM, _)) => is_evm_address(address),
+        Some((ADDRESS_FORMAT_SOLANA, _)) => is_solana_address(address),
+        Some((ADDRESS_FORMAT_BECH32, hrp)) => is_bech32_address(address, &hrp),
+        _ => true,
+    };
+    if !valid {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+}
+
+// Store a lock or release event: the full record, or in compact mode (nonce, hex blake2b of the
+// record bytes) with the serialized record kept in the given dictionary under item
+fn emit_record<T: CLTyped + ToBytes>(event_name: &str, records: &str, item: &str, nonce: u64, record: T) {
+    let compact: bool = get_key(COMPACT_EVENTS_KEY);
+    if compact {
+        let bytes = record.to_bytes().unwrap_or_revert();
+        let hash = base16(&runtime::blake2b(&bytes));
+        storage::dictionary_put(get_dictionary(records), item, Bytes::from(bytes));
+        runtime::put_key(event_name, storage::new_uref((nonce, hash)).into());
+    } else {
+        runtime::put_key(event_name, storage::new_uref(record).into());
+    }
+}
+
+// Lock CSPR to bridge to another chain
+#[no_mangle]
+pub extern "C" fn lock_cspr() {
+    let destination_chain: String = runtime::get_named_arg("destination_chain");
+    let destination_address: String = runtime::get_named_arg("destination_address");
+    let amount: U512 = runtime::get_named_arg("amount");
+    // Purse funded by the caller's session code
+    let purse: URef = runtime::get_named_arg("purse");
+    // Optional onward hops as (chain, address) pairs
+    let route: Vec<(String, String)> = runtime::try_get_named_arg("route").unwrap_or_default();
+    let route = route.into_iter().map(|(chain, address)| Hop { chain, address }).collect();
+
+    lock_native(destination_chain, destination_address, route, amount, purse);
+}
+
+// Token map item for an asset on a chain: hex blake2b of the asset id and chain name
+fn token_map_item(asset: &AssetId, chain: &str) -> String {
+    let mut preimage = asset.to_bytes().unwrap_or_revert();
+    preimage.extend(chain.to_bytes().unwrap_or_revert());
+    base16(&runtime::blake2b(preimage))
+}
+
+// Remote token address an asset is minted as on a chain, if mapped
+fn token_mapping(asset: &AssetId, chain: &str) -> Option<String> {
+    let remote_token: Option<String> =
+        storage::dictionary_get(get_dictionary(TOKEN_MAP_KEY), &token_map_item(asset, chain)).unwrap_or_revert();
+    remote_token.filter(|token| !token.is_empty())
+}
+
+// Asset named by the optional token arg: a CEP-18 contract, or native CSPR when absent
+fn token_arg() -> AssetId {
+    match runtime::try_get_named_arg::<Key>("token") {
+        Some(token) => AssetId::Cep18(token),
+        None => AssetId::Native,
+    }
+}
+
+// Read a queued release, reverting unless it is still pending
+fn pending_queued_release(proof_key: &str) -> (Key, U512, u64) {
+    let queued: Option<(Key, U512, u64)> =
+        storage::dictionary_get(get_dictionary(QUEUED_RELEASES_KEY), proof_key).unwrap_or_revert();
+    match queued {
+        Some(entry) if !entry.1.is_zero() => entry,
+        _ => runtime::revert(casper_types::ApiError::MissingKey),
+    }
+}
+
+// Pay out a throttled release once its timelock has elapsed (anyone, while not paused)
+#[no_mangle]
+pub extern "C" fn execute_queued_release() {
+    require_not_paused();
+
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let proof_key = proof_key(&source_chain, nonce, log_index);
+
+    let (recipient, payout, eta) = pending_queued_release(&proof_key);
+    let now = u64::from(runtime::get_blocktime());
+    if now < eta {
+        runtime::revert(casper_types::ApiError::User(ERROR_TIMELOCK_PENDING));
+    }
+    if dispute_deadline(&proof_key).is_some_and(|deadline| now < deadline) {
+        runtime::revert(casper_types::ApiError::User(ERROR_RELEASE_DISPUTED));
+    }
+    // Releases only queue payouts to accounts; anything else must not leave the entry spent and unpaid
+    let Key::Account(account_hash) = recipient else {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    };
+
+    storage::dictionary_put(get_dictionary(QUEUED_RELEASES_KEY), &proof_key, (recipient, U512::zero(), eta));
+    let queued: U512 = get_key(QUEUED_RELEASE_TOTAL_KEY);
+    set_key(QUEUED_RELEASE_TOTAL_KEY, queued - payout);
+
+    system::transfer_from_purse_to_account(vault_purse(), account_hash, payout, None).unwrap_or_revert();
+}
+
+// Drop a queued release judged fraudulent, returning its motes to total locked (owner only)
+#[no_mangle]
+pub extern "C" fn cancel_queued_release() {
+    require_owner();
+
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let proof_key = proof_key(&source_chain, nonce, log_index);
+
+    drop_queued_release(&proof_key);
+
+    let mut params = source_chain.to_bytes().unwrap_or_revert();
+    params.extend(nonce.to_bytes().unwrap_or_revert());
+    params.extend(log_index.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_CANCEL_QUEUED_RELEASE, &params);
+}
+
+// Cancel a pending queued release, returning its motes to total locked
+fn drop_queued_release(proof_key: &str) {
+    let (recipient, payout, eta) = pending_queued_release(proof_key);
+    storage::dictionary_put(get_dictionary(QUEUED_RELEASES_KEY), proof_key, (recipient, U512::zero(), eta));
+
+    let queued: U512 = get_key(QUEUED_RELEASE_TOTAL_KEY);
+    set_key(QUEUED_RELEASE_TOTAL_KEY, queued - payout);
+    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
+    set_key(TOTAL_LOCKED_KEY, current_locked + payout);
+}
+
+// Deadline of an open dispute on a queued release, if any
+fn dispute_deadline(proof_key: &str) -> Option<u64> {
+    let deadline: Option<u64> = storage::dictionary_get(get_dictionary(DISPUTES_KEY), proof_key).unwrap_or_revert();
+    deadline.filter(|deadline| *deadline > 0)
+}
+
+// Freeze one queued release while its timelock is still running (guardians only)
+// The hold lapses after DISPUTE_WINDOW unless the owner resolves it first
+#[no_mangle]
+pub extern "C" fn dispute_release() {
+    if !is_flagged(GUARDIANS_KEY, Key::Account(runtime::get_caller())) {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let proof_key = proof_key(&source_chain, nonce, log_index);
+
+    let (_, _, eta) = pending_queued_release(&proof_key);
+    let now = u64::from(runtime::get_blocktime());
+    if now >= eta || dispute_deadline(&proof_key).is_some() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let deadline = now + DISPUTE_WINDOW;
+    storage::dictionary_put(get_dictionary(DISPUTES_KEY), &proof_key, deadline);
+    // ReleaseDisputed event: (guardian, deadline)
+    runtime::put_key(
+        &format!("release_disputed_{}", proof_key),
+        storage::new_uref((Key::Account(runtime::get_caller()), deadline)).into(),
+    );
+
+    let mut params = source_chain.to_bytes().unwrap_or_revert();
+    params.extend(nonce.to_bytes().unwrap_or_revert());
+    params.extend(log_index.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_DISPUTE_RELEASE, &params);
+}
+
+// Settle a dispute: approve lets the release execute, otherwise it is cancelled (owner only)
+#[no_mangle]
+pub extern "C" fn resolve_dispute() {
+    require_owner();
+
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let approve: bool = runtime::get_named_arg("approve");
+    let proof_key = proof_key(&source_chain, nonce, log_index);
+
+    if dispute_deadline(&proof_key).is_none() {
+        runtime::revert(casper_types::ApiError::MissingKey);
+    }
+    if !approve {
+        drop_queued_release(&proof_key);
+    }
+    storage::dictionary_put(get_dictionary(DISPUTES_KEY), &proof_key, 0u64);
+    // DisputeResolved event: whether the release was approved
+    runtime::put_key(&format!("dispute_resolved_{}", proof_key), storage::new_uref(approve).into());
+
+    let mut params = source_chain.to_bytes().unwrap_or_revert();
+    params.extend(nonce.to_bytes().unwrap_or_revert());
+    params.extend(log_index.to_bytes().unwrap_or_revert());
+    params.extend(approve.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_RESOLVE_DISPUTE, &params);
+}
+
+// Get the deadline of an open dispute on a queued release, if any
+#[no_mangle]
+pub extern "C" fn get_dispute() {
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let deadline = dispute_deadline(&proof_key(&source_chain, nonce, log_index));
+    runtime::ret(CLValue::from_t(deadline).unwrap_or_revert());
+}
+
+// Get a queued release as (recipient, payout, eta); payout is 0 once executed or cancelled
+#[no_mangle]
+pub extern "C" fn get_queued_release() {
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+
+    let queued: Option<(Key, U512, u64)> = storage::dictionary_get(
+        get_dictionary(QUEUED_RELEASES_KEY),
+        &proof_key(&source_chain, nonce, log_index),
+    )
+    .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(queued).unwrap_or_revert());
+}
+
+// Page through the release queue in queue order: scans positions [offset, offset + limit) and
+// returns (queue length, [(proof key, (recipient, payout, eta))]) for releases still pending,
+// optionally only those paying the given recipient
+#[no_mangle]
+pub extern "C" fn get_pending_releases() {
+    let offset: u64 = runtime::get_named_arg("offset");
+    let limit: u32 = runtime::get_named_arg("limit");
+    let recipient: Option<Key> = runtime::try_get_named_arg("recipient");
+    if limit > MAX_PENDING_PAGE {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let count: u64 = get_key(QUEUED_RELEASE_COUNT_KEY);
+    let index_dict = get_dictionary(QUEUED_RELEASE_INDEX_KEY);
+    let queue_dict = get_dictionary(QUEUED_RELEASES_KEY);
+    let mut pending: Vec<(String, (Key, U512, u64))> = Vec::new();
+    for position in offset..count.min(offset.saturating_add(u64::from(limit))) {
+        let proof_key: String = storage::dictionary_get(index_dict, &position.to_string())
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        let queued: (Key, U512, u64) = storage::dictionary_get(queue_dict, &proof_key)
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        if !queued.1.is_zero() && recipient.map_or(true, |recipient| recipient == queued.0) {
+            pending.push((proof_key, queued));
+        }
+    }
+
+    runtime::ret(CLValue::from_t((count, pending)).unwrap_or_revert());
+}
+
+// Register a fixed destination that deposits can be locked to by id alone
+// Registering the same destination again returns the existing id
+#[no_mangle]
+pub extern "C" fn register_deposit_route() {
+    let destination_chain: String = runtime::get_named_arg("destination_chain");
+    let destination_address: String = runtime::get_named_arg("destination_address");
+
+    let ids_dict = get_dictionary(DEPOSIT_ROUTE_IDS_KEY);
+    let route_hash = runtime::blake2b(format!("{}|{}", destination_chain, destination_address));
+    let route_hash_key = base16(&route_hash);
+
+    let existing: Option<u64> = storage::dictionary_get(ids_dict, &route_hash_key).unwrap_or_revert();
+    let route_id = match existing {
+        Some(route_id) => route_id,
+        None => {
+            let route_id: u64 = get_key(DEPOSIT_ROUTE_COUNT_KEY);
+            storage::dictionary_put(
+                get_dictionary(DEPOSIT_ROUTES_KEY),
+                &route_id.to_string(),
+                (destination_chain, destination_address),
+            );
+            storage::dictionary_put(ids_dict, &route_hash_key, route_id);
+            set_key(DEPOSIT_ROUTE_COUNT_KEY, route_id + 1);
+            route_id
+        }
+    };
+
+    runtime::ret(CLValue::from_t(route_id).unwrap_or_revert());
+}
+
+// Lock CSPR to a registered deposit route; the event carries the resolved destination
+#[no_mangle]
+pub extern "C" fn lock_to_route() {
+    let route_id: u64 = runtime::get_named_arg("route_id");
+    let amount: U512 = runtime::get_named_arg("amount");
+    let purse: URef = runtime::get_named_arg("purse");
+
+    let route: Option<(String, String)> =
+        storage::dictionary_get(get_dictionary(DEPOSIT_ROUTES_KEY), &route_id.to_string()).unwrap_or_revert();
+    let (destination_chain, destination_address) =
+        route.unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+
+    lock_native(destination_chain, destination_address, Vec::new(), amount, purse);
+}
+
+// Read the release fields shared by release_cspr and register_proof_header
+// Returns the record with the proof's log index and digest version
+fn release_args() -> (ReleaseRecord, u32, u8) {
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let source_tx_hash: String = runtime::get_named_arg("source_tx_hash");
+    let amount: U512 = runtime::get_named_arg("amount");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    let digest_version: u8 = runtime::get_named_arg("digest_version");
+    // Who burned on the source chain and a free-form memo; only signed from digest v3
+    let source_sender: Option<String> = runtime::try_get_named_arg("source_sender");
+    let memo: Option<String> = runtime::try_get_named_arg("memo");
+    // Source chain block of the burn, checked against checkpoints; required from digest v4
+    let source_block: Option<u64> = runtime::try_get_named_arg("source_block");
+
+    require_accepted_digest_version(digest_version);
+    if digest_version < 3 && (source_sender.is_some() || memo.is_some()) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    if (digest_version >= 4) != source_block.is_some() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    // The sender is delimited by '|' in the signed message, so it must not contain one
+    if source_sender.as_deref().is_some_and(|sender| sender.contains('|'))
+        || memo.as_deref().is_some_and(|memo| memo.len() > MAX_MEMO_LENGTH)
+    {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let record = ReleaseRecord {
+        asset: AssetId::Native,
+        amount,
+        nonce,
+        recipient,
+        source_chain,
+        source_tx_hash,
+        source_sender,
+        memo,
+        source_block,
+    };
+    (record, log_index, digest_version)
+}
+
+// Bound verification cost before doing any other work
+fn require_signature_batch_size(signatures: &[(Vec<u8>, Vec<u8>)]) {
+    let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+    if signatures.len() > max_sigs as usize {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+}
+
+// Whether the proof was processed here or in any vault before it in the migration chain
+fn is_processed(source_chain: &str, nonce: u64, log_index: u32) -> bool {
+    let already_processed: Option<bool> =
+        storage::dictionary_get(get_dictionary(PROCESSED_PROOFS_KEY), &proof_key(source_chain, nonce, log_index))
+            .unwrap_or_revert();
+    if already_processed.is_some() {
+        return true;
+    }
+
+    // Proofs processed before a migration stay spent; the previous vault asks its own predecessor
+    match previous_vault() {
+        Some(previous) => runtime::call_contract(
+            previous,
+            ENTRY_POINT_IS_PROOF_PROCESSED,
+            runtime_args! { "source_chain" => source_chain, "nonce" => nonce, "log_index" => log_index },
+        ),
+        None => false,
+    }
+}
+
+fn require_unprocessed(source_chain: &str, nonce: u64, log_index: u32) {
+    if is_processed(source_chain, nonce, log_index) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+}
+
+// Pay out a release whose signatures met the threshold: breaker check, fees, payout and event
+// signature_count and payload_bytes feed the submitter's gas refund estimate
+fn complete_release(
+    record: ReleaseRecord,
+    log_index: u32,
+    signers: &[AccountHash],
+    signature_count: usize,
+    payload_bytes: usize,
+) {
+    require_fresh_source_chain(&record.source_chain);
+    require_recent_proof(&record, log_index);
+    require_signer_diversity(signers);
+
+    let amount = record.amount;
+    let recipient = record.recipient;
+    let proof_key = proof_key(&record.source_chain, record.nonce, log_index);
+
+    // Trip the circuit breaker instead of paying out a release that makes the window look
+    // anomalous; the proof stays unprocessed so it can be resubmitted after review
+    if !release_within_anomaly_bounds(recipient, amount) {
+        let self_key: Key = get_key(SELF_KEY);
+        enter_operating_mode(MODE_HALTED, self_key, Some(String::from("release anomaly")));
+        runtime::put_key(&format!("breaker_tripped_{}", proof_key), storage::new_uref(amount).into());
+        return;
+    }
+
+    // Mark as processed
+    storage::dictionary_put(get_dictionary(PROCESSED_PROOFS_KEY), &proof_key, true);
+    record_signers(signers);
+
+    // Update total locked
+    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
+    if current_locked < amount {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    set_key(TOTAL_LOCKED_KEY, current_locked - amount);
+
+    // The first successful submitter earns the relayer reward and a gas refund
+    // estimated from the cost table, both out of the released amount
+    // Fee-exempt recipients skip the reward and validator fee but still cover the gas refund
+    let exempt = is_flagged(FEE_EXEMPT_KEY, recipient);
+    let reward: U512 = if exempt { U512::zero() } else { get_key(RELAYER_REWARD_KEY) };
+    let gas_refund = get_key::<U512>(GAS_COST_BASE_KEY)
+        + get_key::<U512>(GAS_COST_PER_SIGNATURE_KEY) * U512::from(signature_count)
+        + get_key::<U512>(GAS_COST_PER_BYTE_KEY) * U512::from(payload_bytes);
+    let validator_fee: U512 = if exempt { U512::zero() } else { get_key(VALIDATOR_FEE_KEY) };
+    let fees = reward + gas_refund + validator_fee;
+    if fees >= amount {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    if !validator_fee.is_zero() {
+        // Accrues to this epoch's pool, shared by the epoch's signers once it closes
+        let epoch = current_epoch().to_string();
+        let pools_dict = get_dictionary(EPOCH_REWARD_POOLS_KEY);
+        let (pool, paid): (U512, U512) = storage::dictionary_get(pools_dict, &epoch).unwrap_or_revert().unwrap_or_default();
+        storage::dictionary_put(pools_dict, &epoch, (pool + validator_fee, paid));
+        let unclaimed: U512 = get_key(UNCLAIMED_TOTAL_KEY);
+        set_key(UNCLAIMED_TOTAL_KEY, unclaimed + validator_fee);
+    }
+    let caller_key = Key::Account(runtime::get_caller());
+    if !reward.is_zero() {
+        credit_relayer(RELAYER_REWARDS_KEY, caller_key, reward);
+    }
+    if !gas_refund.is_zero() {
+        credit_relayer(GAS_REFUNDS_KEY, caller_key, gas_refund);
+    }
+
+    // Transfer CSPR to recipient up to the recipient's remaining allowance for the current
+    // window, queueing any excess behind the timelock
+    let Key::Account(account_hash) = recipient else {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    };
+    let payout = amount - fees;
+    let dust_threshold: U512 = get_key(DUST_THRESHOLD_KEY);
+    if payout < dust_threshold {
+        // Too small to be worth a transfer; the recipient claims it with others later
+        credit_relayer(DUST_BALANCES_KEY, recipient, payout);
+    } else {
+        // While Restricted, payouts above the small-transfer cap wait out the longer timelock
+        let mode: u8 = get_key(OPERATING_MODE_KEY);
+        let restricted_max: U512 = get_key(RESTRICTED_MAX_TRANSFER_KEY);
+        let restricted = mode == MODE_RESTRICTED;
+        let immediate = if restricted && payout > restricted_max {
+            U512::zero()
+        } else {
+            take_recipient_allowance(recipient, payout)
+        };
+        // Nothing is paid now when the allowance is used up or a Restricted payout exceeds the cap;
+        // the whole payout is then queued below
+        if !immediate.is_zero() {
+            if immediate < dust_threshold {
+                credit_relayer(DUST_BALANCES_KEY, recipient, immediate);
+            } else {
+                system::transfer_from_purse_to_account(vault_purse(), account_hash, immediate, None)
+                    .unwrap_or_revert();
+            }
+        }
+
+        let remainder = payout - immediate;
+        if !remainder.is_zero() {
+            let delay = if restricted {
+                get_key::<u64>(RESTRICTED_TIMELOCK_KEY).max(TIMELOCK_DELAY)
+            } else {
+                TIMELOCK_DELAY
+            };
+            let eta = u64::from(runtime::get_blocktime()) + delay;
+            storage::dictionary_put(get_dictionary(QUEUED_RELEASES_KEY), &proof_key, (recipient, remainder, eta));
+            let position: u64 = get_key(QUEUED_RELEASE_COUNT_KEY);
+            storage::dictionary_put(get_dictionary(QUEUED_RELEASE_INDEX_KEY), &position.to_string(), proof_key.clone());
+            set_key(QUEUED_RELEASE_COUNT_KEY, position + 1);
+            let queued: U512 = get_key(QUEUED_RELEASE_TOTAL_KEY);
+            set_key(QUEUED_RELEASE_TOTAL_KEY, queued + remainder);
+            runtime::put_key(&format!("release_queued_{}", proof_key), storage::new_uref((remainder, eta)).into());
+            if !immediate.is_zero() {
+                // ReleasePartiallyPaid event: (paid now, queued)
+                runtime::put_key(
+                    &format!("release_partial_{}", proof_key),
+                    storage::new_uref((immediate, remainder)).into(),
+                );
+            }
+        }
+    }
+
+    // Receipt in the recipient's release history: (proof key, amount, blocktime)
+    let item = format!("{:?}", recipient);
+    let counts_dict = get_dictionary(USER_RELEASE_COUNTS_KEY);
+    let count: u64 = storage::dictionary_get(counts_dict, &item).unwrap_or_revert().unwrap_or_default();
+    storage::dictionary_put(
+        get_dictionary(USER_RELEASES_KEY),
+        &format!("{}_{}", item, count),
+        (proof_key.clone(), amount, u64::from(runtime::get_blocktime())),
+    );
+    storage::dictionary_put(counts_dict, &item, count + 1);
+
+    // Emit event
+    notify_hooks(HOOK_EVENT_RELEASE, record.nonce, amount, &record.to_bytes().unwrap_or_revert());
+    let event_name = format!("asset_released_{}", proof_key);
+    let nonce = record.nonce;
+    emit_record(&event_name, RELEASE_RECORDS_KEY, &proof_key, nonce, record);
+}
+
+// Release CSPR when proof of burn is provided from destination chain
+#[no_mangle]
+pub extern "C" fn release_cspr() {
+    release(false);
+}
+
+// Same as release_cspr, but a proof that was already processed is a no-op instead of a revert,
+// so relayers racing on the same proof don't pay for a failed deploy
+#[no_mangle]
+pub extern "C" fn release_cspr_idempotent() {
+    release(true);
+}
+
+fn release(idempotent: bool) {
+    require_not_paused();
+    require_submitter();
+
+    let (record, log_index, digest_version) = release_args();
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+    if is_processed(&record.source_chain, record.nonce, log_index) {
+        if !idempotent {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        }
+        // DuplicateProofIgnored event: the caller whose submission was skipped
+        runtime::put_key(
+            &format!("duplicate_proof_ignored_{}", proof_key(&record.source_chain, record.nonce, log_index)),
+            storage::new_uref(Key::Account(runtime::get_caller())).into(),
+        );
+        return;
+    }
+
+    let (caller_key, bond) = require_submission_bond();
+
+    // Verify signatures
+    let message = release_message(digest_version, &record, log_index, &get_key::<String>(BRIDGE_ID_KEY));
+    let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
+    let signers = valid_signers(&message, &signatures);
+    if (signers.len() as u32) < required_sigs {
+        if bond.is_zero() {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        }
+        // Returning rather than reverting keeps the forfeiture
+        forfeit_bond(caller_key, bond, &proof_key(&record.source_chain, record.nonce, log_index));
+        return;
+    }
+
+    shadow_verify(&record, log_index);
+
+    let payload_bytes = record.source_chain.serialized_length()
+        + record.source_tx_hash.serialized_length()
+        + signatures.serialized_length();
+    complete_release(record, log_index, &signers, signatures.len(), payload_bytes);
+}
+
+// Submitters must hold the bond before the vault spends gas verifying their signatures
+// Returns the caller and the bond at stake
+fn require_submission_bond() -> (Key, U512) {
+    let bond: U512 = get_key(SUBMISSION_BOND_KEY);
+    let caller_key = Key::Account(runtime::get_caller());
+    if relayer_balance(SUBMISSION_BONDS_KEY, caller_key) < bond {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+    (caller_key, bond)
+}
+
+// Move a submitter's bond into the current validator reward epoch's pool
+// Both balances count toward the unclaimed total, so it is unchanged
+fn forfeit_bond(submitter: Key, bond: U512, proof_key: &str) {
+    let bonds_dict = get_dictionary(SUBMISSION_BONDS_KEY);
+    let item = format!("{:?}", submitter);
+    let held: U512 = storage::dictionary_get(bonds_dict, &item).unwrap_or_revert().unwrap_or_default();
+    storage::dictionary_put(bonds_dict, &item, held - bond);
+
+    let epoch = current_epoch().to_string();
+    let pools_dict = get_dictionary(EPOCH_REWARD_POOLS_KEY);
+    let (pool, paid): (U512, U512) = storage::dictionary_get(pools_dict, &epoch).unwrap_or_revert().unwrap_or_default();
+    storage::dictionary_put(pools_dict, &epoch, (pool + bond, paid));
+
+    // BondForfeited event: (submitter, proof key, amount)
+    let index: u64 = get_key(BOND_FORFEIT_COUNT_KEY);
+    runtime::put_key(
+        &format!("bond_forfeited_{}", index),
+        storage::new_uref((submitter, String::from(proof_key), bond)).into(),
+    );
+    set_key(BOND_FORFEIT_COUNT_KEY, index + 1);
+}
+
+// Shadow mode: check the release again under the shadow digest version, using the optional
+// shadow_signatures arg, and count whether it would also have met the threshold. The active
+// digest has already accepted the release; the shadow result only feeds the counters
+fn shadow_verify(record: &ReleaseRecord, log_index: u32) {
+    let shadow_version: u8 = get_key(SHADOW_DIGEST_VERSION_KEY);
+    if shadow_version == 0 {
+        return;
+    }
+
+    let shadow_signatures: Vec<(Vec<u8>, Vec<u8>)> =
+        runtime::try_get_named_arg("shadow_signatures").unwrap_or_default();
+    require_signature_batch_size(&shadow_signatures);
+    let message = release_message(shadow_version, record, log_index, &get_key::<String>(BRIDGE_ID_KEY));
+    let shadow_signers = valid_signers(&message, &shadow_signatures).len() as u32;
+    let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
+
+    if shadow_signers >= required_sigs {
+        let agreed: u64 = get_key(SHADOW_AGREE_COUNT_KEY);
+        set_key(SHADOW_AGREE_COUNT_KEY, agreed + 1);
+    } else {
+        let disagreed: u64 = get_key(SHADOW_DISAGREE_COUNT_KEY);
+        set_key(SHADOW_DISAGREE_COUNT_KEY, disagreed + 1);
+        // ShadowDivergence event: (shadow version, valid shadow signers)
+        runtime::put_key(
+            &format!("shadow_divergence_{}", proof_key(&record.source_chain, record.nonce, log_index)),
+            storage::new_uref((shadow_version, shadow_signers)).into(),
+        );
+    }
+}
+
+// Stage 1 of a release too large for one deploy: store the proof fields and return the proof
+// id, the hex blake2b of the release message. Keying by the message rather than the nonce means
+// a bogus header cannot block the real one; registering the same header again returns its id
+#[no_mangle]
+pub extern "C" fn register_proof_header() {
+    require_not_paused();
+    require_submitter();
+
+    let (record, log_index, digest_version) = release_args();
+    require_unprocessed(&record.source_chain, record.nonce, log_index);
+
+    let message = release_message(digest_version, &record, log_index, &get_key::<String>(BRIDGE_ID_KEY));
+    let proof_id = base16(&runtime::blake2b(message));
+    let headers_dict = get_dictionary(PENDING_PROOFS_KEY);
+    let existing: Option<(ReleaseRecord, u32, u8)> =
+        storage::dictionary_get(headers_dict, &proof_id).unwrap_or_revert();
+    if existing.is_none() {
+        storage::dictionary_put(headers_dict, &proof_id, (record, log_index, digest_version));
+        storage::dictionary_put(get_dictionary(PENDING_SIGNERS_KEY), &proof_id, Vec::<AccountHash>::new());
+    }
+
+    runtime::ret(CLValue::from_t(proof_id).unwrap_or_revert());
+}
+
+fn pending_proof(proof_id: &str) -> (ReleaseRecord, u32, u8) {
+    let header: Option<(ReleaseRecord, u32, u8)> =
+        storage::dictionary_get(get_dictionary(PENDING_PROOFS_KEY), proof_id).unwrap_or_revert();
+    header.unwrap_or_revert_with(casper_types::ApiError::MissingKey)
+}
+
+fn pending_signers(proof_id: &str) -> Vec<AccountHash> {
+    storage::dictionary_get(get_dictionary(PENDING_SIGNERS_KEY), proof_id)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+// Stage 2, repeatable: verify a batch of signatures over a registered proof and store the signers
+// Bonded like release_cspr: a batch without a single valid validator signature forfeits the bond
+#[no_mangle]
+pub extern "C" fn add_signatures() {
+    require_not_paused();
+    require_submitter();
+
+    let proof_id: String = runtime::get_named_arg("proof_id");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+    let (caller_key, bond) = require_submission_bond();
+
+    let (record, log_index, digest_version) = pending_proof(&proof_id);
+    let message = release_message(digest_version, &record, log_index, &get_key::<String>(BRIDGE_ID_KEY));
+
+    let batch_signers = valid_signers(&message, &signatures);
+    if batch_signers.is_empty() && !bond.is_zero() {
+        // Returning rather than reverting keeps the forfeiture
+        forfeit_bond(caller_key, bond, &proof_key(&record.source_chain, record.nonce, log_index));
+        return;
+    }
+
+    let mut signers = pending_signers(&proof_id);
+    for signer in batch_signers {
+        if !signers.contains(&signer) {
+            signers.push(signer);
+        }
+    }
+    storage::dictionary_put(get_dictionary(PENDING_SIGNERS_KEY), &proof_id, signers);
+}
+
+// Stage 3: release once the stored signers, still active validators, meet the threshold
+#[no_mangle]
+pub extern "C" fn finalize_release() {
+    require_not_paused();
+    require_submitter();
+
+    let proof_id: String = runtime::get_named_arg("proof_id");
+    let (record, log_index, digest_version) = pending_proof(&proof_id);
+    require_accepted_digest_version(digest_version);
+    require_unprocessed(&record.source_chain, record.nonce, log_index);
+
+    // Validators removed since they signed no longer count
+    let validators_dict = get_dictionary(VALIDATORS_KEY);
+    let signers: Vec<AccountHash> = pending_signers(&proof_id)
+        .into_iter()
+        .filter(|signer| is_flagged_in(validators_dict, Key::Account(*signer)))
+        .collect();
+    let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
+    if (signers.len() as u32) < required_sigs {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let payload_bytes = record.source_chain.serialized_length() + record.source_tx_hash.serialized_length();
+    let signature_count = signers.len();
+    complete_release(record, log_index, &signers, signature_count, payload_bytes);
+}
+
+// Get the signers stored so far for a staged proof
+#[no_mangle]
+pub extern "C" fn get_pending_signers() {
+    let proof_id: String = runtime::get_named_arg("proof_id");
+    let signers: Vec<Key> = pending_signers(&proof_id).into_iter().map(Key::Account).collect();
+    runtime::ret(CLValue::from_t(signers).unwrap_or_revert());
+}
+
+// Withdraw accumulated relayer rewards to the caller
+#[no_mangle]
+pub extern "C" fn claim_relayer_reward() {
+    claim_relayer_balance(RELAYER_REWARDS_KEY);
+}
+
+// Withdraw accumulated gas refunds to the caller
+#[no_mangle]
+pub extern "C" fn claim_gas_refund() {
+    claim_relayer_balance(GAS_REFUNDS_KEY);
+}
+
+// Withdraw the caller's share of a closed, unexpired validator reward epoch
+#[no_mangle]
+pub extern "C" fn claim_validator_reward() {
+    let epoch: u64 = runtime::get_named_arg("epoch");
+    let current = current_epoch();
+    if epoch >= current || current - epoch > VALIDATOR_REWARD_EXPIRY_EPOCHS {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let caller = runtime::get_caller();
+    let validator = Key::Account(caller);
+    let share = validator_epoch_share(epoch, validator);
+    if share.is_zero() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    // Zeroing the signature count makes the share claimable once
+    storage::dictionary_put(
+        get_dictionary(EPOCH_SIGNATURES_KEY),
+        &format!("{}_{:?}", epoch, validator),
+        0u64,
+    );
+    let (pool, paid) = epoch_reward_pool(epoch);
+    storage::dictionary_put(get_dictionary(EPOCH_REWARD_POOLS_KEY), &epoch.to_string(), (pool, paid + share));
+    let unclaimed: U512 = get_key(UNCLAIMED_TOTAL_KEY);
+    set_key(UNCLAIMED_TOTAL_KEY, unclaimed - share);
+
+    system::transfer_from_purse_to_account(vault_purse(), caller, share, None).unwrap_or_revert();
+}
+
+// Move what an expired epoch's validators left unclaimed into the current epoch's pool (anyone)
+#[no_mangle]
+pub extern "C" fn roll_expired_validator_rewards() {
+    let epoch: u64 = runtime::get_named_arg("epoch");
+    let current = current_epoch();
+    if epoch >= current || current - epoch <= VALIDATOR_REWARD_EXPIRY_EPOCHS {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let (pool, paid) = epoch_reward_pool(epoch);
+    let remainder = pool - paid;
+    if remainder.is_zero() {
+        return;
+    }
+
+    let pools_dict = get_dictionary(EPOCH_REWARD_POOLS_KEY);
+    storage::dictionary_put(pools_dict, &epoch.to_string(), (pool, pool));
+    let (current_pool, current_paid) = epoch_reward_pool(current);
+    storage::dictionary_put(pools_dict, &current.to_string(), (current_pool + remainder, current_paid));
+}
+
+// Post a submission bond from a purse funded by the caller's session code
+#[no_mangle]
+pub extern "C" fn deposit_submission_bond() {
+    let amount: U512 = runtime::get_named_arg("amount");
+    let purse: URef = runtime::get_named_arg("purse");
+    if amount.is_zero() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    system::transfer_from_purse_to_purse(purse, vault_purse(), amount, None).unwrap_or_revert();
+    credit_relayer(SUBMISSION_BONDS_KEY, Key::Account(runtime::get_caller()), amount);
+}
+
+// Withdraw the caller's whole submission bond
+#[no_mangle]
+pub extern "C" fn claim_submission_bond() {
+    claim_relayer_balance(SUBMISSION_BONDS_KEY);
+}
+
+// Require release_cspr submitters to hold this bond, forfeited when their signatures fall
+// short of the threshold (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_submission_bond() {
+    require_config_owner();
+
+    let bond: U512 = runtime::get_named_arg("bond");
+    set_key(SUBMISSION_BOND_KEY, bond);
+
+    record_audit(ENTRY_POINT_SET_SUBMISSION_BOND, &bond.to_bytes().unwrap_or_revert());
+}
+
+// Get an address's posted submission bond
+#[no_mangle]
+pub extern "C" fn get_submission_bond() {
+    let address: Key = runtime::get_named_arg("address");
+    runtime::ret(CLValue::from_t(relayer_balance(SUBMISSION_BONDS_KEY, address)).unwrap_or_revert());
+}
+
+// Withdraw release payouts that were below the dust threshold to the caller
+#[no_mangle]
+pub extern "C" fn claim_dust() {
+    claim_relayer_balance(DUST_BALANCES_KEY);
+}
+
+// Add a validator (owner only)
+#[no_mangle]
+pub extern "C" fn add_validator() {
+    require_config_owner();
+    apply_admin_op(AdminOp::AddValidator(runtime::get_named_arg("validator")));
+}
+
+// Remove a validator (owner only)
+#[no_mangle]
+pub extern "C" fn remove_validator() {
+    require_config_owner();
+    apply_admin_op(AdminOp::RemoveValidator(runtime::get_named_arg("validator")));
+}
+
+// Replace the calling validator's signing key with a new one, keeping its place in the set
+// The new key proves possession by signing "ROTATE|{bridge_id}|{old account hash}"
+// Not counted against the churn limit, since the set size is unchanged
+#[no_mangle]
+pub extern "C" fn rotate_validator_key() {
+    let old_public_key: Vec<u8> = runtime::get_named_arg("old_public_key");
+    let new_public_key: Vec<u8> = runtime::get_named_arg("new_public_key");
+    let proof_of_possession: Vec<u8> = runtime::get_named_arg("proof_of_possession");
+
+    let (Some(old_key), Some(new_key), Some(proof)) = (
+        ed25519_public_key(&old_public_key),
+        ed25519_public_key(&new_public_key),
+        ed25519_signature(&proof_of_possession),
+    ) else {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    };
+
+    let old_validator = Key::Account(AccountHash::from_public_key(&old_key, runtime::blake2b));
+    let new_validator = Key::Account(AccountHash::from_public_key(&new_key, runtime::blake2b));
+    if Key::Account(runtime::get_caller()) != old_validator || !is_flagged(VALIDATORS_KEY, old_validator) {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+    if is_flagged(VALIDATORS_KEY, new_validator) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let message = format!("ROTATE|{}|{}", bridge_id, old_validator.to_formatted_string());
+    if cryptography::verify_signature(message.as_bytes(), &proof, &new_key).is_err() {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+
+    let validators_dict = get_dictionary(VALIDATORS_KEY);
+    storage::dictionary_put(validators_dict, &format!("{:?}", old_validator), false);
+    storage::dictionary_put(validators_dict, &format!("{:?}", new_validator), true);
+
+    let mut validators: Vec<Key> = get_key(VALIDATOR_LIST_KEY);
+    if let Some(slot) = validators.iter_mut().find(|v| **v == old_validator) {
+        *slot = new_validator;
+    }
+    set_key(VALIDATOR_LIST_KEY, validators);
+
+    // Same operator, same organization
+    storage::dictionary_put(
+        get_dictionary(VALIDATOR_ORGANIZATIONS_KEY),
+        &format!("{:?}", new_validator),
+        validator_organization(old_validator),
+    );
+
+    let mut params = old_validator.to_bytes().unwrap_or_revert();
+    params.extend(new_validator.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_ROTATE_VALIDATOR_KEY, &params);
+}
+
+// Digest of a validator set and its threshold, as signed for rotate_validator_set: hex blake2b-256
+// of the members' raw Ed25519 public keys in ascending order, then required_signatures as u32 big-endian
+fn validator_set_digest(public_keys: &[Vec<u8>], required_signatures: u32) -> String {
+    let mut preimage = Vec::new();
+    for public_key in public_keys {
+        preimage.extend_from_slice(public_key);
+    }
+    preimage.extend_from_slice(&required_signatures.to_be_bytes());
+    base16(&runtime::blake2b(preimage))
+}
+
+// Replace the whole validator set and threshold on a quorum of the outgoing set, for key ceremonies
+// run offline; validators sign admin_message("ROTATE_SET") + "|{validator_set_digest}"
+// Keys must be strictly ascending, the threshold a majority of the new set within max_signatures,
+// and every new key proves possession by signing "JOIN|{bridge_id}|{its account hash}"
+// Counted as one change against the churn limit
+#[no_mangle]
+pub extern "C" fn rotate_validator_set() {
+    let new_validators: Vec<Vec<u8>> = runtime::get_named_arg("new_validators");
+    let required_signatures: u32 = runtime::get_named_arg("required_signatures");
+    let proofs_of_possession: Vec<Vec<u8>> = runtime::get_named_arg("proofs_of_possession");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+
+    let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+    let size = new_validators.len() as u32;
+    if required_signatures == 0
+        || required_signatures > max_sigs
+        || required_signatures * 2 <= size
+        || proofs_of_possession.len() != new_validators.len()
+        || new_validators.windows(2).any(|pair| pair[0] >= pair[1])
+    {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let digest = validator_set_digest(&new_validators, required_signatures);
+    let mut message = admin_message("ROTATE_SET");
+    message.extend_from_slice(format!("|{}", digest).as_bytes());
+    require_validator_quorum(&message, &signatures);
+
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let mut incoming: Vec<Key> = Vec::new();
+    for (public_key_bytes, proof_bytes) in new_validators.iter().zip(&proofs_of_possession) {
+        let (Some(public_key), Some(proof)) = (ed25519_public_key(public_key_bytes), ed25519_signature(proof_bytes))
+        else {
+            runtime::revert(casper_types::ApiError::InvalidArgument);
+        };
+        let validator = Key::Account(AccountHash::from_public_key(&public_key, runtime::blake2b));
+        let possession = format!("JOIN|{}|{}", bridge_id, validator.to_formatted_string());
+        if cryptography::verify_signature(possession.as_bytes(), &proof, &public_key).is_err() {
+            runtime::revert(casper_types::ApiError::PermissionDenied);
+        }
+        incoming.push(validator);
+    }
+
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    set_key(ADMIN_NONCE_KEY, admin_nonce + 1);
+    record_churn();
+
+    let outgoing: Vec<Key> = get_key(VALIDATOR_LIST_KEY);
+    let validators_dict = get_dictionary(VALIDATORS_KEY);
+    for validator in &outgoing {
+        storage::dictionary_put(validators_dict, &format!("{:?}", validator), false);
+    }
+    for validator in &incoming {
+        storage::dictionary_put(validators_dict, &format!("{:?}", validator), true);
+    }
+    set_key(VALIDATOR_LIST_KEY, incoming);
+    set_key(REQUIRED_SIGNATURES_KEY, required_signatures);
+
+    let mut params = admin_nonce.to_bytes().unwrap_or_revert();
+    params.extend(digest.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_ROTATE_VALIDATOR_SET, &params);
+}
+
+// Record the organization a validator runs under, for the signer diversity policy (owner only)
+#[no_mangle]
+pub extern "C" fn set_validator_organization() {
+    require_config_owner();
+
+    let validator: Key = runtime::get_named_arg("validator");
+    let organization: String = runtime::get_named_arg("organization");
+
+    let mut params = validator.to_bytes().unwrap_or_revert();
+    params.extend(organization.to_bytes().unwrap_or_revert());
+
+    storage::dictionary_put(get_dictionary(VALIDATOR_ORGANIZATIONS_KEY), &format!("{:?}", validator), organization);
+
+    record_audit(ENTRY_POINT_SET_VALIDATOR_ORGANIZATION, &params);
+}
+
+// Require release signers to span at least this many distinct organizations (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_min_signer_organizations() {
+    require_config_owner();
+
+    let count: u32 = runtime::get_named_arg("count");
+    set_key(MIN_SIGNER_ORGANIZATIONS_KEY, count);
+
+    record_audit(ENTRY_POINT_SET_MIN_SIGNER_ORGANIZATIONS, &count.to_bytes().unwrap_or_revert());
+}
+
+// Get the organization recorded for a validator (empty if none)
+#[no_mangle]
+pub extern "C" fn get_validator_organization() {
+    let validator: Key = runtime::get_named_arg("validator");
+    runtime::ret(CLValue::from_t(validator_organization(validator)).unwrap_or_revert());
+}
+
+// Set required signatures (owner only)
+#[no_mangle]
+pub extern "C" fn set_required_signatures() {
+    require_config_owner();
+    apply_admin_op(AdminOp::SetRequiredSignatures(runtime::get_named_arg("count")));
+}
+
+// Set maximum signatures per proof (owner only)
+#[no_mangle]
+pub extern "C" fn set_max_signatures() {
+    require_config_owner();
+    apply_admin_op(AdminOp::SetMaxSignatures(runtime::get_named_arg("count")));
+}
+
+// Set reward paid to the first successful release submitter (owner only)
+#[no_mangle]
+pub extern "C" fn set_relayer_reward() {
+    require_config_owner();
+    apply_admin_op(AdminOp::SetRelayerReward(runtime::get_named_arg("reward")));
+}
+
+// Run a digest version in shadow mode next to the active one, resetting its counters (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_shadow_digest_version() {
+    require_config_owner();
+
+    let version: u8 = runtime::get_named_arg("version");
+    if version > LATEST_DIGEST_VERSION {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(SHADOW_DIGEST_VERSION_KEY, version);
+    set_key(SHADOW_AGREE_COUNT_KEY, 0u64);
+    set_key(SHADOW_DISAGREE_COUNT_KEY, 0u64);
+
+    record_audit(ENTRY_POINT_SET_SHADOW_DIGEST_VERSION, &version.to_bytes().unwrap_or_revert());
+}
+
+// Get the shadow digest version and its (agree, disagree) counts since it was set
+#[no_mangle]
+pub extern "C" fn get_shadow_stats() {
+    let version: u8 = get_key(SHADOW_DIGEST_VERSION_KEY);
+    let agreed: u64 = get_key(SHADOW_AGREE_COUNT_KEY);
+    let disagreed: u64 = get_key(SHADOW_DISAGREE_COUNT_KEY);
+    runtime::ret(CLValue::from_t((version, agreed, disagreed)).unwrap_or_revert());
+}
+
+// Switch the active digest version, still accepting the old one for transition_window ms (owner only)
+#[no_mangle]
+pub extern "C" fn set_digest_version() {
+    require_config_owner();
+
+    let version: u8 = runtime::get_named_arg("version");
+    let transition_window: u64 = runtime::get_named_arg("transition_window");
+
+    let active: u8 = get_key(DIGEST_VERSION_KEY);
+    if version == 0 || version > LATEST_DIGEST_VERSION || version == active {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(DIGEST_VERSION_KEY, version);
+    set_key(PREVIOUS_DIGEST_VERSION_KEY, active);
+    set_key(
+        PREVIOUS_DIGEST_DEADLINE_KEY,
+        u64::from(runtime::get_blocktime()).saturating_add(transition_window),
+    );
+
+    let mut params = version.to_bytes().unwrap_or_revert();
+    params.extend(transition_window.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_DIGEST_VERSION, &params);
+}
+
+// Move CEP-18 tokens sent straight to the vault to `to` (owner only, timelocked)
+// The vault never holds CEP-18 balances on behalf of users, so its whole balance is stray
+#[no_mangle]
+pub extern "C" fn sweep_stray_tokens() {
+    require_owner();
+
+    let token: Key = runtime::get_named_arg("token");
+    let to: Key = runtime::get_named_arg("to");
+
+    let mut params = token.to_bytes().unwrap_or_revert();
+    params.extend(to.to_bytes().unwrap_or_revert());
+    if !timelock_ready(ENTRY_POINT_SWEEP_STRAY_TOKENS, &params, TIMELOCK_DELAY) {
+        return;
+    }
+
+    let token_hash = token
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument);
+    let self_key: Key = get_key(SELF_KEY);
+    let balance: U256 = runtime::call_contract(token_hash, CEP18_BALANCE_OF, runtime_args! { "address" => self_key });
+    if balance.is_zero() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    runtime::call_contract::<()>(
+        token_hash,
+        CEP18_TRANSFER,
+        runtime_args! { "recipient" => to, "amount" => balance },
+    );
+
+    let mut amount_bytes = [0u8; 32];
+    balance.to_little_endian(&mut amount_bytes);
+    record_sweep(SweepRecord {
+        asset: AssetId::Cep18(token),
+        amount: U512::from_little_endian(&amount_bytes),
+        to,
+    });
+    record_audit(ENTRY_POINT_SWEEP_STRAY_TOKENS, &params);
+}
+
+// Move motes above what the vault owes to `to` (owner only, timelocked)
+// Only purse_balance - total_locked - unclaimed relayer balances - queued releases can ever leave this way
+#[no_mangle]
+pub extern "C" fn sweep_excess_cspr() {
+    require_owner();
+
+    let to: Key = runtime::get_named_arg("to");
+    let Key::Account(to_account) = to else {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    };
+
+    let params = to.to_bytes().unwrap_or_revert();
+    if !timelock_ready(ENTRY_POINT_SWEEP_EXCESS_CSPR, &params, TIMELOCK_DELAY) {
+        return;
+    }
+
+    let balance = system::get_purse_balance(vault_purse()).unwrap_or_revert();
+    let owed = get_key::<U512>(TOTAL_LOCKED_KEY)
+        + get_key::<U512>(UNCLAIMED_TOTAL_KEY)
+        + get_key::<U512>(QUEUED_RELEASE_TOTAL_KEY);
+    if balance <= owed {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    let excess = balance - owed;
+
+    system::transfer_from_purse_to_account(vault_purse(), to_account, excess, None).unwrap_or_revert();
+
+    record_sweep(SweepRecord {
+        asset: AssetId::Native,
+        amount: excess,
+        to,
+    });
+    record_audit(ENTRY_POINT_SWEEP_EXCESS_CSPR, &params);
+}
+
+// Drop a queued timelocked operation by id (owner only)
+#[no_mangle]
+pub extern "C" fn cancel_operation() {
+    require_owner();
+
+    let operation_id: String = runtime::get_named_arg("operation_id");
+    let queue_dict = get_dictionary(TIMELOCK_QUEUE_KEY);
+    let eta: u64 = storage::dictionary_get(queue_dict, &operation_id)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    if eta == 0 {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    storage::dictionary_put(queue_dict, &operation_id, 0u64);
+
+    record_audit(ENTRY_POINT_CANCEL_OPERATION, operation_id.as_bytes());
+}
+
+// Add a chain to the supported chain registry (owner only)
+#[no_mangle]
+pub extern "C" fn add_supported_chain() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let mut chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if chain.is_empty() || chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    chains.push(chain.clone());
+    set_key(SUPPORTED_CHAINS_KEY, chains);
+
+    record_audit(ENTRY_POINT_ADD_SUPPORTED_CHAIN, &chain.to_bytes().unwrap_or_revert());
+}
+
+// Remove a chain from the supported chain registry (owner only)
+#[no_mangle]
+pub extern "C" fn remove_supported_chain() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let mut chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    let index = chains
+        .iter()
+        .position(|c| *c == chain)
+        .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument);
+    chains.remove(index);
+    set_key(SUPPORTED_CHAINS_KEY, chains);
+
+    record_audit(ENTRY_POINT_REMOVE_SUPPORTED_CHAIN, &chain.to_bytes().unwrap_or_revert());
+}
+
+// Set the decimals of bridged CSPR on a supported chain (owner only)
+// Locks to chains with fewer than 9 decimals must be whole multiples of the smallest representable unit
+#[no_mangle]
+pub extern "C" fn set_chain_decimals() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let decimals: u8 = runtime::get_named_arg("decimals");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(CHAIN_DECIMALS_KEY), &chain, decimals);
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(decimals.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_CHAIN_DECIMALS, &params);
+}
+
+// Set the destination address format locks to a supported chain must match (owner only)
+// hrp is the bech32 human-readable part (e.g. "cosmos") and must be empty for other formats
+#[no_mangle]
+pub extern "C" fn set_chain_address_format() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let format: u8 = runtime::get_named_arg("format");
+    let hrp: String = runtime::get_named_arg("hrp");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) || format > ADDRESS_FORMAT_BECH32 || (format == ADDRESS_FORMAT_BECH32) == hrp.is_empty() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(format.to_bytes().unwrap_or_revert());
+    params.extend(hrp.to_bytes().unwrap_or_revert());
+
+    storage::dictionary_put(get_dictionary(CHAIN_ADDRESS_FORMATS_KEY), &chain, (format, hrp));
+
+    record_audit(ENTRY_POINT_SET_CHAIN_ADDRESS_FORMAT, &params);
+}
+
+// Set the finality parameters relayers and signers must apply to a supported chain (owner only)
+#[no_mangle]
+pub extern "C" fn set_chain_finality() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let min_confirmations: u64 = runtime::get_named_arg("min_confirmations");
+    let challenge_window: u64 = runtime::get_named_arg("challenge_window");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(CHAIN_MIN_CONFIRMATIONS_KEY), &chain, min_confirmations);
+    storage::dictionary_put(get_dictionary(CHAIN_CHALLENGE_WINDOW_KEY), &chain, challenge_window);
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(min_confirmations.to_bytes().unwrap_or_revert());
+    params.extend(challenge_window.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_CHAIN_FINALITY, &params);
+}
+
+// Map an asset to the token contract that represents it on a supported chain (owner only)
+// The asset is the optional token arg (native CSPR when absent); an empty remote_token clears the mapping
+#[no_mangle]
+pub extern "C" fn set_token_mapping() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let remote_token: String = runtime::get_named_arg("remote_token");
+    let asset = token_arg();
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(TOKEN_MAP_KEY), &token_map_item(&asset, &chain), remote_token.clone());
+
+    let mut params = asset.to_bytes().unwrap_or_revert();
+    params.extend(chain.to_bytes().unwrap_or_revert());
+    params.extend(remote_token.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_TOKEN_MAPPING, &params);
+}
+
+// Get the remote token an asset maps to on a chain (optional token arg, native CSPR when absent)
+#[no_mangle]
+pub extern "C" fn get_token_mapping() {
+    let chain: String = runtime::get_named_arg("chain");
+    let remote_token = token_mapping(&token_arg(), &chain);
+    runtime::ret(CLValue::from_t(remote_token).unwrap_or_revert());
+}
+
+// Register another Casper-network deployment of this vault as the peer behind a supported chain
+// (config owner only); omitting the optional vault arg clears it
+// Peers share the release digest scheme, so each side's validators sign the other's locks as releases
+#[no_mangle]
+pub extern "C" fn set_peer_vault() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let vault: Option<Key> = runtime::try_get_named_arg("vault");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) || vault.is_some_and(|vault| vault.into_hash_addr().is_none()) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(PEER_VAULTS_KEY), &chain, vault);
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(vault.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_PEER_VAULT, &params);
+}
+
+// Get the peer vault registered behind a chain, if any
+#[no_mangle]
+pub extern "C" fn get_peer_vault() {
+    let chain: String = runtime::get_named_arg("chain");
+    runtime::ret(CLValue::from_t(peer_vault(&chain)).unwrap_or_revert());
+}
+
+// Refuse releases from a supported chain once its head goes unattested for timeout ms (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_chain_halt_timeout() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let timeout: u64 = runtime::get_named_arg("timeout");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(CHAIN_HALT_TIMEOUTS_KEY), &chain, timeout);
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(timeout.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_CHAIN_HALT_TIMEOUT, &params);
+}
+
+// Subscribe a contract to lock (0) or release (1) events; it must expose
+// on_bridge_event(event_kind: u8, nonce: u64, amount: U512, record: Bytes) (owner only)
+#[no_mangle]
+pub extern "C" fn register_hook() {
+    require_config_owner();
+
+    let address: Key = runtime::get_named_arg("address");
+    let event_kind: u8 = runtime::get_named_arg("event_kind");
+    let mut hooks: Vec<(Key, u8)> = get_key(HOOKS_KEY);
+    if address.into_hash_addr().is_none()
+        || event_kind > HOOK_EVENT_RELEASE
+        || hooks.len() >= MAX_HOOKS
+        || hooks.contains(&(address, event_kind))
+    {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    hooks.push((address, event_kind));
+    set_key(HOOKS_KEY, hooks);
+
+    let mut params = address.to_bytes().unwrap_or_revert();
+    params.extend(event_kind.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_REGISTER_HOOK, &params);
+}
+
+// Unsubscribe a contract from an event (owner only, also after sunset so a broken listener can be dropped)
+#[no_mangle]
+pub extern "C" fn unregister_hook() {
+    require_owner();
+
+    let address: Key = runtime::get_named_arg("address");
+    let event_kind: u8 = runtime::get_named_arg("event_kind");
+    let mut hooks: Vec<(Key, u8)> = get_key(HOOKS_KEY);
+    let index = hooks
+        .iter()
+        .position(|hook| *hook == (address, event_kind))
+        .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument);
+    hooks.remove(index);
+    set_key(HOOKS_KEY, hooks);
+
+    let mut params = address.to_bytes().unwrap_or_revert();
+    params.extend(event_kind.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_UNREGISTER_HOOK, &params);
+}
+
+// Choose who may call release_cspr: 0 anyone, 1 registered relayers, 2 validators (owner only)
+#[no_mangle]
+pub extern "C" fn set_submission_mode() {
+    require_config_owner();
+
+    let mode: u8 = runtime::get_named_arg("mode");
+    if mode > SUBMISSION_MODE_VALIDATORS {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(SUBMISSION_MODE_KEY, mode);
+
+    record_audit(ENTRY_POINT_SET_SUBMISSION_MODE, &mode.to_bytes().unwrap_or_revert());
+}
+
+// Register a relayer (owner only)
+#[no_mangle]
+pub extern "C" fn add_relayer() {
+    require_config_owner();
+
+    let relayer: Key = runtime::get_named_arg("relayer");
+    storage::dictionary_put(get_dictionary(RELAYERS_KEY), &format!("{:?}", relayer), true);
+
+    record_audit(ENTRY_POINT_ADD_RELAYER, &relayer.to_bytes().unwrap_or_revert());
+}
+
+// Deregister a relayer (owner only)
+#[no_mangle]
+pub extern "C" fn remove_relayer() {
+    require_config_owner();
+
+    let relayer: Key = runtime::get_named_arg("relayer");
+    storage::dictionary_put(get_dictionary(RELAYERS_KEY), &format!("{:?}", relayer), false);
+
+    record_audit(ENTRY_POINT_REMOVE_RELAYER, &relayer.to_bytes().unwrap_or_revert());
+}
+
+// Register a guardian who may dispute queued releases (owner only)
+#[no_mangle]
+pub extern "C" fn add_guardian() {
+    require_config_owner();
+
+    let guardian: Key = runtime::get_named_arg("guardian");
+    storage::dictionary_put(get_dictionary(GUARDIANS_KEY), &format!("{:?}", guardian), true);
+
+    record_audit(ENTRY_POINT_ADD_GUARDIAN, &guardian.to_bytes().unwrap_or_revert());
+}
+
+// Deregister a guardian (owner only)
+#[no_mangle]
+pub extern "C" fn remove_guardian() {
+    require_config_owner();
+
+    let guardian: Key = runtime::get_named_arg("guardian");
+    storage::dictionary_put(get_dictionary(GUARDIANS_KEY), &format!("{:?}", guardian), false);
+
+    record_audit(ENTRY_POINT_REMOVE_GUARDIAN, &guardian.to_bytes().unwrap_or_revert());
+}
+
+// Exempt an account from the relayer reward and validator fee on releases it receives,
+// e.g. the treasury or insurance fund (owner only)
+#[no_mangle]
+pub extern "C" fn set_fee_exempt() {
+    require_config_owner();
+
+    let account: Key = runtime::get_named_arg("account");
+    let exempt: bool = runtime::get_named_arg("exempt");
+    storage::dictionary_put(get_dictionary(FEE_EXEMPT_KEY), &format!("{:?}", account), exempt);
+
+    // FeeExemptionChanged event
+    let index: u64 = get_key(FEE_EXEMPTION_COUNT_KEY);
+    runtime::put_key(&format!("fee_exemption_{}", index), storage::new_uref((account, exempt)).into());
+    set_key(FEE_EXEMPTION_COUNT_KEY, index + 1);
+
+    let mut params = account.to_bytes().unwrap_or_revert();
+    params.extend(exempt.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_FEE_EXEMPT, &params);
+}
+
+// Check whether an account's releases are fee-exempt
+#[no_mangle]
+pub extern "C" fn is_fee_exempt() {
+    let account: Key = runtime::get_named_arg("account");
+    runtime::ret(CLValue::from_t(is_flagged(FEE_EXEMPT_KEY, account)).unwrap_or_revert());
+}
+
+// Set the cost table used to estimate relayer gas refunds, in motes (owner only)
+#[no_mangle]
+pub extern "C" fn set_gas_cost_table() {
+    require_config_owner();
+
+    let base: U512 = runtime::get_named_arg("base");
+    let per_signature: U512 = runtime::get_named_arg("per_signature");
+    let per_byte: U512 = runtime::get_named_arg("per_byte");
+
+    set_key(GAS_COST_BASE_KEY, base);
+    set_key(GAS_COST_PER_SIGNATURE_KEY, per_signature);
+    set_key(GAS_COST_PER_BYTE_KEY, per_byte);
+
+    let mut params = base.to_bytes().unwrap_or_revert();
+    params.extend(per_signature.to_bytes().unwrap_or_revert());
+    params.extend(per_byte.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_GAS_COST_TABLE, &params);
+}
+
+// Limit how much each recipient can be paid per window; the excess waits in the timelocked
+// release queue (owner only, a limit of 0 disables)
+#[no_mangle]
+pub extern "C" fn set_recipient_limit() {
+    require_config_owner();
+
+    let limit: U512 = runtime::get_named_arg("limit");
+    let window: u64 = runtime::get_named_arg("window");
+    if window == 0 {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(RECIPIENT_LIMIT_KEY, limit);
+    set_key(RECIPIENT_WINDOW_KEY, window);
+
+    let mut params = limit.to_bytes().unwrap_or_revert();
+    params.extend(window.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_RECIPIENT_LIMIT, &params);
+}
+
+// Configure the release anomaly breaker (owner only): at most max_recipients distinct recipients
+// per window, and an average release size within [min_average, max_average] once min_sample
+// releases are in; any bound of 0 is not checked
+#[no_mangle]
+pub extern "C" fn set_anomaly_bounds() {
+    require_config_owner();
+
+    let window: u64 = runtime::get_named_arg("window");
+    let max_recipients: u32 = runtime::get_named_arg("max_recipients");
+    let min_average: U512 = runtime::get_named_arg("min_average");
+    let max_average: U512 = runtime::get_named_arg("max_average");
+    let min_sample: u32 = runtime::get_named_arg("min_sample");
+    if window == 0 || (!max_average.is_zero() && min_average > max_average) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(ANOMALY_WINDOW_KEY, window);
+    set_key(ANOMALY_MAX_RECIPIENTS_KEY, max_recipients);
+    set_key(ANOMALY_MIN_AVERAGE_KEY, min_average);
+    set_key(ANOMALY_MAX_AVERAGE_KEY, max_average);
+    set_key(ANOMALY_MIN_SAMPLE_KEY, min_sample);
+
+    let mut params = window.to_bytes().unwrap_or_revert();
+    params.extend(max_recipients.to_bytes().unwrap_or_revert());
+    params.extend(min_average.to_bytes().unwrap_or_revert());
+    params.extend(max_average.to_bytes().unwrap_or_revert());
+    params.extend(min_sample.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_ANOMALY_BOUNDS, &params);
+}
+
+// Fee taken from each release into the validator reward pool (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_validator_fee() {
+    require_config_owner();
+    apply_admin_op(AdminOp::SetValidatorFee(runtime::get_named_arg("fee")));
+}
+
+// Apply several owner operations in one deploy, in order and all-or-nothing, so validator-set
+// reconfigurations never sit in an intermediate state between deploys (owner only)
+#[no_mangle]
+pub extern "C" fn admin_multicall() {
+    require_config_owner();
+
+    let ops: Vec<AdminOp> = runtime::get_named_arg("ops");
+    if ops.is_empty() || ops.len() > MAX_ADMIN_OPS {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let params = ops.to_bytes().unwrap_or_revert();
+    for op in ops {
+        apply_admin_op(op);
+    }
+
+    record_audit(ENTRY_POINT_ADMIN_MULTICALL, &params);
+}
+
+// Validate and apply one owner operation, auditing it under its standalone entry point's name
+// Callers check ownership first
+fn apply_admin_op(op: AdminOp) {
+    match op {
+        AdminOp::AddValidator(validator) => {
+            record_churn();
+            set_validator(validator, true);
+            record_audit(ENTRY_POINT_ADD_VALIDATOR, &validator.to_bytes().unwrap_or_revert());
+        }
+        AdminOp::RemoveValidator(validator) => {
+            record_churn();
+            set_validator(validator, false);
+            record_audit(ENTRY_POINT_REMOVE_VALIDATOR, &validator.to_bytes().unwrap_or_revert());
+        }
+        AdminOp::SetRequiredSignatures(count) => {
+            let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+            if count == 0 || count > max_sigs {
+                runtime::revert(casper_types::ApiError::InvalidArgument);
+            }
+            set_key(REQUIRED_SIGNATURES_KEY, count);
+            record_audit(ENTRY_POINT_SET_REQUIRED_SIGNATURES, &count.to_bytes().unwrap_or_revert());
+        }
+        AdminOp::SetMaxSignatures(count) => {
+            let required_sigs: u32 = get_key(REQUIRED_SIGNATURES_KEY);
+            if count < required_sigs {
+                runtime::revert(casper_types::ApiError::InvalidArgument);
+            }
+            set_key(MAX_SIGNATURES_KEY, count);
+            record_audit(ENTRY_POINT_SET_MAX_SIGNATURES, &count.to_bytes().unwrap_or_revert());
+        }
+        AdminOp::SetRelayerReward(reward) => {
+            set_key(RELAYER_REWARD_KEY, reward);
+            record_audit(ENTRY_POINT_SET_RELAYER_REWARD, &reward.to_bytes().unwrap_or_revert());
+        }
+        AdminOp::SetValidatorFee(fee) => {
+            set_key(VALIDATOR_FEE_KEY, fee);
+            record_audit(ENTRY_POINT_SET_VALIDATOR_FEE, &fee.to_bytes().unwrap_or_revert());
+        }
+    }
+}
+
+// Release payouts below this many motes are credited to the recipient's claimable dust
+// balance instead of transferred (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_dust_threshold() {
+    require_config_owner();
+
+    let threshold: U512 = runtime::get_named_arg("threshold");
+    set_key(DUST_THRESHOLD_KEY, threshold);
+
+    record_audit(ENTRY_POINT_SET_DUST_THRESHOLD, &threshold.to_bytes().unwrap_or_revert());
+}
+
+// Emit lock and release events as compact (nonce, record hash) references instead of full
+// records, keeping the records in lock_records / release_records (owner only)
+#[no_mangle]
+pub extern "C" fn set_compact_events() {
+    require_config_owner();
+
+    let compact: bool = runtime::get_named_arg("compact");
+    set_key(COMPACT_EVENTS_KEY, compact);
+
+    record_audit(ENTRY_POINT_SET_COMPACT_EVENTS, &compact.to_bytes().unwrap_or_revert());
+}
+
+// Reject locks unless the asset is mapped on the destination chain and every chain on the
+// route is supported (owner only)
+#[no_mangle]
+pub extern "C" fn set_strict_mode() {
+    require_config_owner();
+
+    let enabled: bool = runtime::get_named_arg("enabled");
+    set_key(STRICT_MODE_KEY, enabled);
+
+    record_audit(ENTRY_POINT_SET_STRICT_MODE, &enabled.to_bytes().unwrap_or_revert());
+}
+
+// Cap total locked motes at the exposure bonds and insurance can back (owner only, 0 disables)
+// Lowering it below the current total only blocks new locks; releases are unaffected
+#[no_mangle]
+pub extern "C" fn set_max_tvl() {
+    require_config_owner();
+
+    let max_tvl: U512 = runtime::get_named_arg("max_tvl");
+    set_key(MAX_TVL_KEY, max_tvl);
+
+    record_audit(ENTRY_POINT_SET_MAX_TVL, &max_tvl.to_bytes().unwrap_or_revert());
+}
+
+// Set the account that reports destination relay costs, or clear it by omitting oracle (owner only)
+#[no_mangle]
+pub extern "C" fn set_gas_oracle() {
+    require_config_owner();
+
+    let oracle: Option<Key> = runtime::try_get_named_arg("oracle");
+    set_key(GAS_ORACLE_KEY, oracle);
+
+    record_audit(ENTRY_POINT_SET_GAS_ORACLE, &oracle.to_bytes().unwrap_or_revert());
+}
+
+// Scale the minimum lock to relay cost * multiplier_bps / 10000, never below the static
+// minimum (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_min_lock_multiplier() {
+    require_config_owner();
+
+    let multiplier_bps: u32 = runtime::get_named_arg("multiplier_bps");
+    set_key(MIN_LOCK_MULTIPLIER_KEY, multiplier_bps);
+
+    record_audit(ENTRY_POINT_SET_MIN_LOCK_MULTIPLIER, &multiplier_bps.to_bytes().unwrap_or_revert());
+}
+
+// Report the current cost, in motes, of relaying a transfer to a supported chain (gas oracle only)
+#[no_mangle]
+pub extern "C" fn update_relay_cost() {
+    let oracle: Option<Key> = get_key(GAS_ORACLE_KEY);
+    if oracle != Some(Key::Account(runtime::get_caller())) {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+
+    let chain: String = runtime::get_named_arg("chain");
+    let cost: U512 = runtime::get_named_arg("cost");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    storage::dictionary_put(get_dictionary(RELAY_COSTS_KEY), &chain, cost);
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(cost.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_UPDATE_RELAY_COST, &params);
+}
+
+// Get the minimum lock currently enforced for a destination chain
+#[no_mangle]
+pub extern "C" fn get_min_lock_amount() {
+    let chain: String = runtime::get_named_arg("chain");
+    runtime::ret(CLValue::from_t(min_lock_amount(&chain)).unwrap_or_revert());
+}
+
+// Cap validator additions and removals per time window (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_churn_limit() {
+    require_config_owner();
+
+    let limit: u32 = runtime::get_named_arg("limit");
+    let window: u64 = runtime::get_named_arg("window");
+    if window == 0 {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    set_key(CHURN_LIMIT_KEY, limit);
+    set_key(CHURN_WINDOW_KEY, window);
+
+    let mut params = limit.to_bytes().unwrap_or_revert();
+    params.extend(window.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_CHURN_LIMIT, &params);
+}
+
+// Pause contract (owner only), recording who paused, when, and why
+#[no_mangle]
+pub extern "C" fn pause() {
+    require_owner();
+
+    let reason: Option<String> = runtime::try_get_named_arg::<Option<String>>("reason").flatten();
+
+    enter_operating_mode(MODE_HALTED, Key::Account(runtime::get_caller()), reason.clone());
+
+    record_audit(ENTRY_POINT_PAUSE, &reason.to_bytes().unwrap_or_revert());
+}
+
+// Pause on a validator quorum, for when the owner key is unavailable or compromised
+// Validators sign admin_message("PAUSE"); anyone may submit the signatures
+#[no_mangle]
+pub extern "C" fn pause_with_signatures() {
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+
+    let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+    if signatures.len() > max_sigs as usize {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    require_validator_quorum(&admin_message("PAUSE"), &signatures);
+
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    set_key(ADMIN_NONCE_KEY, admin_nonce + 1);
+
+    enter_operating_mode(
+        MODE_HALTED,
+        Key::Account(runtime::get_caller()),
+        Some(String::from("validator quorum")),
+    );
+
+    record_audit(ENTRY_POINT_PAUSE_WITH_SIGNATURES, &admin_nonce.to_bytes().unwrap_or_revert());
+}
+
+// Heartbeat: a validator quorum attests the latest finalized block of a source chain, signing
+// "HEAD|{bridge_id}|{chain}|{block_number}"; block numbers must increase, so it cannot be replayed
+#[no_mangle]
+pub extern "C" fn attest_chain_head() {
+    let chain: String = runtime::get_named_arg("chain");
+    let block_number: u64 = runtime::get_named_arg("block_number");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let message = format!("HEAD|{}|{}|{}", bridge_id, chain, block_number);
+    require_validator_quorum(message.as_bytes(), &signatures);
+
+    record_chain_head(&chain, block_number);
+}
+
+// A lock's escrow record as (sender, amount, state); locks made before escrow tracking have none
+fn lock_escrow(nonce: u64) -> Option<(Key, U512, u8)> {
+    storage::dictionary_get(get_dictionary(LOCK_ESCROWS_KEY), &nonce.to_string()).unwrap_or_revert()
+}
+
+// Move an open escrow to a final state under validator quorum over "{action}|{bridge_id}|{nonce}",
+// returning its nonce, sender and amount; only open escrows move, so signatures cannot be replayed
+fn settle_escrow(action: &str, state: u8) -> (u64, Key, U512) {
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+
+    let (sender, amount, current) = lock_escrow(nonce).unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+    if current != ESCROW_LOCKED {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let message = format!("{}|{}|{}", action, bridge_id, nonce);
+    require_validator_quorum(message.as_bytes(), &signatures);
+
+    storage::dictionary_put(get_dictionary(LOCK_ESCROWS_KEY), &nonce.to_string(), (sender, amount, state));
+    let open: U512 = get_key(OPEN_ESCROW_TOTAL_KEY);
+    set_key(OPEN_ESCROW_TOTAL_KEY, open - amount);
+    (nonce, sender, amount)
+}
+
+// Locked -> Delivered: a validator quorum confirms the destination chain minted the lock,
+// signing "DELIVERED|{bridge_id}|{nonce}"; the funds stay pooled behind the wrapped supply
+#[no_mangle]
+pub extern "C" fn confirm_delivery() {
+    settle_escrow("DELIVERED", ESCROW_DELIVERED);
+}
+
+// Locked -> Refunded: a validator quorum confirms the lock will never be delivered, signing
+// "REFUND|{bridge_id}|{nonce}", and the vault returns the amount to the sender
+#[no_mangle]
+pub extern "C" fn refund_lock() {
+    require_not_paused();
+
+    let (nonce, sender, amount) = settle_escrow("REFUND", ESCROW_REFUNDED);
+    let Key::Account(sender_account) = sender else {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    };
+
+    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
+    set_key(TOTAL_LOCKED_KEY, current_locked - amount);
+    system::transfer_from_purse_to_account(vault_purse(), sender_account, amount, None).unwrap_or_revert();
+
+    // LockRefunded event: the amount returned to the sender
+    runtime::put_key(&format!("lock_refunded_{}", nonce), storage::new_uref(amount).into());
+}
+
+// Reconcile escrow records: returns ([(nonce, amount)] of locks in [from_nonce, to_nonce) still
+// open, total of all open escrows, total_locked). An open total above total_locked means
+// undelivered locks are no longer fully backed
+#[no_mangle]
+pub extern "C" fn audit_open_transfers() {
+    let from_nonce: u64 = runtime::get_named_arg("from_nonce");
+    let to_nonce: u64 = runtime::get_named_arg("to_nonce");
+    if to_nonce < from_nonce || to_nonce - from_nonce > MAX_ESCROW_AUDIT_RANGE {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let mut open: Vec<(u64, U512)> = Vec::new();
+    for nonce in from_nonce..to_nonce {
+        if let Some((_, amount, ESCROW_LOCKED)) = lock_escrow(nonce) {
+            open.push((nonce, amount));
+        }
+    }
+
+    let open_total: U512 = get_key(OPEN_ESCROW_TOTAL_KEY);
+    let total_locked: U512 = get_key(TOTAL_LOCKED_KEY);
+    runtime::ret(CLValue::from_t((open, open_total, total_locked)).unwrap_or_revert());
+}
+
+// Get a lock's escrow record as (sender, amount, state), if tracked
+#[no_mangle]
+pub extern "C" fn get_lock_escrow() {
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    runtime::ret(CLValue::from_t(lock_escrow(nonce)).unwrap_or_revert());
+}
+
+// A validator quorum attests a finalized source chain block and its hash, signing
+// "CHECKPOINT|{bridge_id}|{chain}|{block_number}|{block_hash}"; also counts as a heartbeat
+#[no_mangle]
+pub extern "C" fn submit_checkpoint() {
+    let chain: String = runtime::get_named_arg("chain");
+    let block_number: u64 = runtime::get_named_arg("block_number");
+    let block_hash: String = runtime::get_named_arg("block_hash");
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+    if block_hash.is_empty() || block_hash.contains('|') {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let bridge_id: String = get_key(BRIDGE_ID_KEY);
+    let message = format!("CHECKPOINT|{}|{}|{}|{}", bridge_id, chain, block_number, block_hash);
+    require_validator_quorum(message.as_bytes(), &signatures);
+
+    record_chain_head(&chain, block_number);
+    storage::dictionary_put(
+        get_dictionary(CHAIN_CHECKPOINTS_KEY),
+        &format!("{}_{}", chain, block_number),
+        block_hash.clone(),
+    );
+
+    // Checkpoints in submission order, which is block order, for the proof age limit
+    let counts_dict = get_dictionary(CHECKPOINT_COUNTS_KEY);
+    let count: u64 = storage::dictionary_get(counts_dict, &chain).unwrap_or_revert().unwrap_or_default();
+    storage::dictionary_put(get_dictionary(CHECKPOINT_BLOCKS_KEY), &format!("{}_{}", chain, count), block_number);
+    storage::dictionary_put(counts_dict, &chain, count + 1);
+
+    // CheckpointSubmitted event: (chain, block number, block hash)
+    runtime::put_key(
+        &format!("checkpoint_{}_{}", chain, block_number),
+        storage::new_uref((chain, block_number, block_hash)).into(),
+    );
+}
+
+// Permanently retire the vault (owner, behind SUNSET_DELAY, plus a validator quorum over
+// admin_message("SUNSET") on the executing call): locks stop and configuration freezes,
+// while releases, queued releases and relayer claims keep working so no funds are stranded
+#[no_mangle]
+pub extern "C" fn sunset() {
+    require_config_owner();
+
+    if !timelock_ready(ENTRY_POINT_SUNSET, &[], SUNSET_DELAY) {
+        return;
+    }
+
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+    if signatures.len() > max_sigs as usize {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    require_validator_quorum(&admin_message("SUNSET"), &signatures);
+
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    set_key(ADMIN_NONCE_KEY, admin_nonce + 1);
+    set_key(SUNSET_KEY, true);
+
+    record_audit(ENTRY_POINT_SUNSET, &admin_nonce.to_bytes().unwrap_or_revert());
+}
+
+// Unpause contract (owner only)
+#[no_mangle]
+pub extern "C" fn unpause() {
+    require_owner();
+    if runtime::get_key(MIGRATED_TO_KEY).is_some() {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+    enter_operating_mode(MODE_NORMAL, Key::Account(runtime::get_caller()), None);
+    record_audit(ENTRY_POINT_UNPAUSE, &[]);
+}
+
+// Set the operating mode: 0 Normal, 1 Restricted, 2 Halted
+// The owner may move in any direction; guardians may only make it stricter
+#[no_mangle]
+pub extern "C" fn set_operating_mode() {
+    let mode: u8 = runtime::get_named_arg("mode");
+    let reason: Option<String> = runtime::try_get_named_arg::<Option<String>>("reason").flatten();
+    if mode > MODE_HALTED {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let caller = Key::Account(runtime::get_caller());
+    let owner: Key = get_key(OWNER_KEY);
+    let current: u8 = get_key(OPERATING_MODE_KEY);
+    if caller != owner && !(mode > current && is_flagged(GUARDIANS_KEY, caller)) {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+    // A migrated vault stays halted
+    if mode != MODE_HALTED && runtime::get_key(MIGRATED_TO_KEY).is_some() {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    }
+
+    enter_operating_mode(mode, caller, reason.clone());
+
+    let mut params = mode.to_bytes().unwrap_or_revert();
+    params.extend(reason.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_OPERATING_MODE, &params);
+}
+
+// Limits applied while Restricted: locks above max_transfer revert, and release payouts above it
+// are queued for timelock ms (owner only)
+#[no_mangle]
+pub extern "C" fn set_restricted_limits() {
+    require_config_owner();
+
+    let max_transfer: U512 = runtime::get_named_arg("max_transfer");
+    let timelock: u64 = runtime::get_named_arg("timelock");
+    set_key(RESTRICTED_MAX_TRANSFER_KEY, max_transfer);
+    set_key(RESTRICTED_TIMELOCK_KEY, timelock);
+
+    let mut params = max_transfer.to_bytes().unwrap_or_revert();
+    params.extend(timelock.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_SET_RESTRICTED_LIMITS, &params);
+}
+
+// Hand locked funds and state to a replacement vault (owner only, while paused)
+// Only the approved successor contract may call this, from its import_from_previous under the
+// same owner account; the funds go to the purse the successor itself reports
+#[no_mangle]
+pub extern "C" fn export_for_migration() {
+    require_owner();
+
+    let dry_run: bool = runtime::get_named_arg("dry_run");
+
+    let mode: u8 = get_key(OPERATING_MODE_KEY);
+    if mode != MODE_HALTED || runtime::get_key(MIGRATED_TO_KEY).is_some() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    // Queued releases cannot execute once the vault is halted for good, and only total locked moves
+    if !get_key::<U512>(QUEUED_RELEASE_TOTAL_KEY).is_zero() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    // Only the approved successor, once its activation time has passed
+    let proposal: Option<UpgradeProposal> = get_key(UPGRADE_PROPOSAL_KEY);
+    let approved: bool = get_key(UPGRADE_APPROVED_KEY);
+    let caller = immediate_caller_contract();
+    let Some(proposal) = proposal.filter(|proposal| approved && proposal.successor.into_hash_addr() == caller) else {
+        runtime::revert(casper_types::ApiError::PermissionDenied);
+    };
+    if u64::from(runtime::get_blocktime()) < proposal.activation_time {
+        runtime::revert(casper_types::ApiError::User(ERROR_TIMELOCK_PENDING));
+    }
+
+    let snapshot = MigrationSnapshot {
+        total_locked: get_key(TOTAL_LOCKED_KEY),
+        nonce: get_key(NONCE_KEY),
+        required_signatures: get_key(REQUIRED_SIGNATURES_KEY),
+        validators: get_key(VALIDATOR_LIST_KEY),
+    };
+
+    if !dry_run {
+        let successor = ContractHash::new(caller.unwrap_or_revert());
+        let target_purse: URef = runtime::call_contract(successor, ENTRY_POINT_GET_MIGRATION_PURSE, runtime_args! {});
+        // Unclaimed relayer rewards stay behind and remain claimable here
+        system::transfer_from_purse_to_purse(vault_purse(), target_purse, snapshot.total_locked, None)
+            .unwrap_or_revert();
+        set_key(TOTAL_LOCKED_KEY, U512::zero());
+        // Remember the purse that received the funds; the vault stays paused for good
+        set_key(MIGRATED_TO_KEY, target_purse.remove_access_rights());
+
+        record_audit(ENTRY_POINT_EXPORT_FOR_MIGRATION, &snapshot.to_bytes().unwrap_or_revert());
+    }
+
+    runtime::ret(CLValue::from_t(snapshot).unwrap_or_revert());
+}
+
+// Deposit-only handle on the vault purse, where a previous vault sends its funds in export_for_migration
+#[no_mangle]
+pub extern "C" fn get_migration_purse() {
+    runtime::ret(CLValue::from_t(vault_purse().into_add()).unwrap_or_revert());
+}
+
+// Announce the replacement vault a migration will hand funds to (owner only)
+// Activation must be at least the timelock delay away; a new proposal replaces the old one and
+// needs approving again
+#[no_mangle]
+pub extern "C" fn propose_upgrade() {
+    require_config_owner();
+
+    let proposal = UpgradeProposal {
+        successor: runtime::get_named_arg("successor"),
+        wasm_hash: runtime::get_named_arg("wasm_hash"),
+        changelog_hash: runtime::get_named_arg("changelog_hash"),
+        activation_time: runtime::get_named_arg("activation_time"),
+    };
+    let now = u64::from(runtime::get_blocktime());
+    if proposal.successor.into_hash_addr().is_none() || proposal.activation_time < now + TIMELOCK_DELAY {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let params = proposal.to_bytes().unwrap_or_revert();
+    set_key(UPGRADE_PROPOSAL_KEY, Some(proposal));
+    set_key(UPGRADE_APPROVED_KEY, false);
+
+    record_audit(ENTRY_POINT_PROPOSE_UPGRADE, &params);
+}
+
+// Validator sign-off on the current upgrade proposal: a quorum signs
+// "UPGRADE|{bridge_id}|{admin_nonce}|{hex blake2b of the serialized proposal}"
+#[no_mangle]
+pub extern "C" fn approve_upgrade() {
+    let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+    require_signature_batch_size(&signatures);
+
+    let proposal: Option<UpgradeProposal> = get_key(UPGRADE_PROPOSAL_KEY);
+    let proposal = proposal.unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+    let proposal_hash = runtime::blake2b(proposal.to_bytes().unwrap_or_revert());
+
+    let mut message = admin_message("UPGRADE");
+    message.extend(format!("|{}", base16(&proposal_hash)).as_bytes());
+    require_validator_quorum(&message, &signatures);
+
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    set_key(ADMIN_NONCE_KEY, admin_nonce + 1);
+    set_key(UPGRADE_APPROVED_KEY, true);
+
+    record_audit(ENTRY_POINT_APPROVE_UPGRADE, &proposal_hash);
+}
+
+// Withdraw the upgrade proposal (owner only)
+#[no_mangle]
+pub extern "C" fn cancel_upgrade() {
+    require_owner();
+
+    set_key(UPGRADE_PROPOSAL_KEY, Option::<UpgradeProposal>::None);
+    set_key(UPGRADE_APPROVED_KEY, false);
+
+    record_audit(ENTRY_POINT_CANCEL_UPGRADE, &[]);
+}
+
+// Get the upgrade proposal, if any, and whether validators have approved it
+#[no_mangle]
+pub extern "C" fn get_upgrade_proposal() {
+    let proposal: Option<UpgradeProposal> = get_key(UPGRADE_PROPOSAL_KEY);
+    let approved: bool = get_key(UPGRADE_APPROVED_KEY);
+    runtime::ret(CLValue::from_t((proposal, approved)).unwrap_or_revert());
+}
+
+// Pull funds and state from the previous vault (owner only, once), under a quorum of this
+// vault's validators signing "MIGRATE|{bridge_id}|{admin_nonce}|{hex previous contract hash}"
+// With dry_run set, both sides check their preconditions and only the preview is stored; no signatures needed
+#[no_mangle]
+pub extern "C" fn import_from_previous() {
+    require_config_owner();
+
+    let previous_vault: Key = runtime::get_named_arg("previous_vault");
+    let dry_run: bool = runtime::get_named_arg("dry_run");
+
+    if runtime::get_key(PREVIOUS_VAULT_KEY).is_some() {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+    let previous = previous_vault
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument);
+
+    if !dry_run {
+        let signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("signatures");
+        require_signature_batch_size(&signatures);
+        let mut message = admin_message("MIGRATE");
+        message.extend(format!("|{}", base16(&previous.value())).as_bytes());
+        require_validator_quorum(&message, &signatures);
+
+        let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+        set_key(ADMIN_NONCE_KEY, admin_nonce + 1);
+    }
+
+    let snapshot: MigrationSnapshot = runtime::call_contract(
+        previous,
+        ENTRY_POINT_EXPORT_FOR_MIGRATION,
+        runtime_args! {
+            "dry_run" => dry_run,
+        },
+    );
+
+    if dry_run {
+        set_key(MIGRATION_PREVIEW_KEY, snapshot);
+        return;
+    }
+
+    let current_locked: U512 = get_key(TOTAL_LOCKED_KEY);
+    set_key(TOTAL_LOCKED_KEY, current_locked + snapshot.total_locked);
+
+    // Keep lock nonces unique across vault versions
+    let current_nonce: u64 = get_key(NONCE_KEY);
+    set_key(NONCE_KEY, current_nonce.max(snapshot.nonce));
+
+    for validator in &snapshot.validators {
+        set_validator(*validator, true);
+    }
+    let max_sigs: u32 = get_key(MAX_SIGNATURES_KEY);
+    set_key(REQUIRED_SIGNATURES_KEY, snapshot.required_signatures.min(max_sigs));
+
+    set_key(PREVIOUS_VAULT_KEY, previous_vault);
+    record_audit(ENTRY_POINT_IMPORT_FROM_PREVIOUS, &previous_vault.to_bytes().unwrap_or_revert());
+}
+
+// Check if address is validator
+#[no_mangle]
+pub extern "C" fn is_validator() {
+    let address: Key = runtime::get_named_arg("address");
+
+    let validators_dict = runtime::get_key(VALIDATORS_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let address_key = format!("{:?}", address);
+    let is_val: Option<bool> = storage::dictionary_get(validators_dict, &address_key)
+        .unwrap_or_revert();
+
+    let result = is_val.unwrap_or(false);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+// Check if address is a registered relayer
+#[no_mangle]
+pub extern "C" fn is_relayer() {
+    let address: Key = runtime::get_named_arg("address");
+    runtime::ret(CLValue::from_t(is_flagged(RELAYERS_KEY, address)).unwrap_or_revert());
+}
+
+// Get (min_confirmations, challenge_window) for a chain, or None if not configured
+#[no_mangle]
+pub extern "C" fn get_chain_finality() {
+    let chain: String = runtime::get_named_arg("chain");
+
+    let min_confirmations: Option<u64> =
+        storage::dictionary_get(get_dictionary(CHAIN_MIN_CONFIRMATIONS_KEY), &chain).unwrap_or_revert();
+    let challenge_window: Option<u64> =
+        storage::dictionary_get(get_dictionary(CHAIN_CHALLENGE_WINDOW_KEY), &chain).unwrap_or_revert();
+    let finality = min_confirmations.zip(challenge_window);
+
+    runtime::ret(CLValue::from_t(finality).unwrap_or_revert());
+}
+
+// Get a chain's destination address format as (format, hrp), if configured
+#[no_mangle]
+pub extern "C" fn get_chain_address_format() {
+    let chain: String = runtime::get_named_arg("chain");
+    let format: Option<(u8, String)> =
+        storage::dictionary_get(get_dictionary(CHAIN_ADDRESS_FORMATS_KEY), &chain).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(format).unwrap_or_revert());
+}
+
+// Get a chain's latest attested head as (block number, blocktime attested at), if any
+#[no_mangle]
+pub extern "C" fn get_chain_head() {
+    let chain: String = runtime::get_named_arg("chain");
+    runtime::ret(CLValue::from_t(chain_head(&chain)).unwrap_or_revert());
+}
+
+// Get the attested hash of a source chain block, if a checkpoint was submitted for it
+#[no_mangle]
+pub extern "C" fn get_checkpoint() {
+    let chain: String = runtime::get_named_arg("chain");
+    let block_number: u64 = runtime::get_named_arg("block_number");
+    let block_hash: Option<String> =
+        storage::dictionary_get(get_dictionary(CHAIN_CHECKPOINTS_KEY), &format!("{}_{}", chain, block_number))
+            .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(block_hash).unwrap_or_revert());
+}
+
+// Get the subscribed listeners as (contract, event kind) pairs
+#[no_mangle]
+pub extern "C" fn get_hooks() {
+    let hooks: Vec<(Key, u8)> = get_key(HOOKS_KEY);
+    runtime::ret(CLValue::from_t(hooks).unwrap_or_revert());
+}
+
+// Describe this contract so clients can refuse incompatible versions
+#[no_mangle]
+pub extern "C" fn get_metadata() {
+    let metadata = BridgeMetadata {
+        name: String::from(CONTRACT_NAME),
+        semver: String::from(CONTRACT_SEMVER),
+        bridge_id: get_key(BRIDGE_ID_KEY),
+        supported_chains: get_key(SUPPORTED_CHAINS_KEY),
+        // Only native CSPR locks are implemented so far
+        supported_tokens: vec![AssetId::Native],
+        digest_scheme_version: get_key(DIGEST_VERSION_KEY),
+    };
+    runtime::ret(CLValue::from_t(metadata).unwrap_or_revert());
+}
+
+// Get current settings and pause diagnostics
+// paused_by, paused_at and pause_reason describe the most recent pause and survive unpause
+#[no_mangle]
+pub extern "C" fn get_config() {
+    let config = BridgeConfig {
+        owner: get_key(OWNER_KEY),
+        required_signatures: get_key(REQUIRED_SIGNATURES_KEY),
+        max_signatures: get_key(MAX_SIGNATURES_KEY),
+        min_lock_amount: get_key(MIN_LOCK_AMOUNT_KEY),
+        relayer_reward: get_key(RELAYER_REWARD_KEY),
+        max_tvl: get_key(MAX_TVL_KEY),
+        strict_mode: get_key(STRICT_MODE_KEY),
+        churn_limit: get_key(CHURN_LIMIT_KEY),
+        churn_window: get_key(CHURN_WINDOW_KEY),
+        submission_mode: get_key(SUBMISSION_MODE_KEY),
+        operating_mode: get_key(OPERATING_MODE_KEY),
+        restricted_max_transfer: get_key(RESTRICTED_MAX_TRANSFER_KEY),
+        restricted_timelock: get_key(RESTRICTED_TIMELOCK_KEY),
+        sunset: get_key(SUNSET_KEY),
+        paused_by: get_key(PAUSED_BY_KEY),
+        paused_at: get_key(PAUSED_AT_KEY),
+        pause_reason: get_key(PAUSE_REASON_KEY),
+    };
+    runtime::ret(CLValue::from_t(config).unwrap_or_revert());
+}
+
+// Export the governance state as canonical bytes for init_from_snapshot on a replacement vault
+#[no_mangle]
+pub extern "C" fn export_config() {
+    let snapshot = ConfigSnapshot {
+        required_signatures: get_key(REQUIRED_SIGNATURES_KEY),
+        max_signatures: get_key(MAX_SIGNATURES_KEY),
+        min_lock_amount: get_key(MIN_LOCK_AMOUNT_KEY),
+        relayer_reward: get_key(RELAYER_REWARD_KEY),
+        validator_fee: get_key(VALIDATOR_FEE_KEY),
+        max_tvl: get_key(MAX_TVL_KEY),
+        churn_limit: get_key(CHURN_LIMIT_KEY),
+        churn_window: get_key(CHURN_WINDOW_KEY),
+        submission_mode: get_key(SUBMISSION_MODE_KEY),
+        supported_chains: get_key(SUPPORTED_CHAINS_KEY),
+        digest_version: get_key(DIGEST_VERSION_KEY),
+        recipient_limit: get_key(RECIPIENT_LIMIT_KEY),
+        recipient_window: get_key(RECIPIENT_WINDOW_KEY),
+        dust_threshold: get_key(DUST_THRESHOLD_KEY),
+        strict_mode: get_key(STRICT_MODE_KEY),
+        restricted_max_transfer: get_key(RESTRICTED_MAX_TRANSFER_KEY),
+        restricted_timelock: get_key(RESTRICTED_TIMELOCK_KEY),
+        validators: get_key(VALIDATOR_LIST_KEY),
+    };
+    let bytes = Bytes::from(snapshot.to_bytes().unwrap_or_revert());
+    runtime::ret(CLValue::from_t(bytes).unwrap_or_revert());
+}
+
+// Get the nonce the next validator-signed admin action must sign over
+#[no_mangle]
+pub extern "C" fn get_admin_nonce() {
+    let admin_nonce: u64 = get_key(ADMIN_NONCE_KEY);
+    runtime::ret(CLValue::from_t(admin_nonce).unwrap_or_revert());
+}
+
+// Get a Merkle root over the lock records with nonces in [start_nonce, end_nonce), hashed like the lock tree:
+// the range's leaf nodes padded with empty slots to the next power of two. A range that is exactly one
+// aligned subtree returns that tree node, so it can be checked against get_merkle_root with one path
+#[no_mangle]
+pub extern "C" fn get_lock_commitment() {
+    let start_nonce: u64 = runtime::get_named_arg("start_nonce");
+    let end_nonce: u64 = runtime::get_named_arg("end_nonce");
+
+    let nonce: u64 = get_key(NONCE_KEY);
+    if start_nonce >= end_nonce || end_nonce > nonce || end_nonce - start_nonce > MAX_COMMITMENT_RANGE {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    // Locks occupy consecutive leaves; a range spanning a migration has no single subtree
+    let index_dict = get_dictionary(MERKLE_LEAF_INDEX_KEY);
+    let leaf_index = |n: u64| -> u64 {
+        storage::dictionary_get(index_dict, &n.to_string())
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(casper_types::ApiError::MissingKey)
+    };
+    let start = leaf_index(start_nonce);
+    let count = end_nonce - start_nonce;
+    if leaf_index(end_nonce - 1) != start + count - 1 {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let nodes_dict = get_dictionary(MERKLE_NODES_KEY);
+    let node = |level: usize, index: u64| -> [u8; 32] {
+        storage::dictionary_get(nodes_dict, &format!("{}_{}", level, index))
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(casper_types::ApiError::MissingKey)
+    };
+    let depth = count.next_power_of_two().trailing_zeros() as usize;
+    if count.is_power_of_two() && start % count == 0 {
+        runtime::ret(CLValue::from_t(node(depth, start >> depth)).unwrap_or_revert());
+    }
+
+    let zero_hashes: Vec<[u8; 32]> = get_key(MERKLE_ZERO_HASHES_KEY);
+    let leaves: Vec<[u8; 32]> = (start..start + count).map(|index| node(0, index)).collect();
+    runtime::ret(CLValue::from_t(commitment_root(leaves, &zero_hashes)).unwrap_or_revert());
+}
+
+// Get the root of the lock tree
+#[no_mangle]
+pub extern "C" fn get_merkle_root() {
+    let root: [u8; 32] = get_key(MERKLE_ROOT_KEY);
+    runtime::ret(CLValue::from_t(root).unwrap_or_revert());
+}
+
+// Get (leaf_index, siblings from the leaf level up) proving a lock against the current root
+#[no_mangle]
+pub extern "C" fn get_merkle_path() {
+    let nonce: u64 = runtime::get_named_arg("nonce");
+
+    let leaf_index: u64 = storage::dictionary_get(get_dictionary(MERKLE_LEAF_INDEX_KEY), &nonce.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+
+    let nodes_dict = get_dictionary(MERKLE_NODES_KEY);
+    let zero_hashes: Vec<[u8; 32]> = get_key(MERKLE_ZERO_HASHES_KEY);
+
+    let mut index = leaf_index;
+    let mut siblings: Vec<[u8; 32]> = Vec::with_capacity(MERKLE_TREE_DEPTH);
+    for (level, zero) in zero_hashes.iter().take(MERKLE_TREE_DEPTH).enumerate() {
+        let sibling: Option<[u8; 32]> =
+            storage::dictionary_get(nodes_dict, &format!("{}_{}", level, index ^ 1)).unwrap_or_revert();
+        siblings.push(sibling.unwrap_or(*zero));
+        index /= 2;
+    }
+
+    runtime::ret(CLValue::from_t((leaf_index, siblings)).unwrap_or_revert());
+}
+
+// Get active validator set
+#[no_mangle]
+pub extern "C" fn get_validators() {
+    let validators: Vec<Key> = get_key(VALIDATOR_LIST_KEY);
+    runtime::ret(CLValue::from_t(validators).unwrap_or_revert());
+}
+
+// Check if a release proof was already processed, here or by an earlier vault version
+#[no_mangle]
+pub extern "C" fn is_proof_processed() {
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+
+    runtime::ret(CLValue::from_t(is_processed(&source_chain, nonce, log_index)).unwrap_or_revert());
+}
+
+// Get total locked amount
+#[no_mangle]
+pub extern "C" fn get_total_locked() {
+    let total: U512 = get_key(TOTAL_LOCKED_KEY);
+    runtime::ret(CLValue::from_t(total).unwrap_or_revert());
+}
+
+// Get current nonce
+#[no_mangle]
+pub extern "C" fn get_nonce() {
+    let nonce: u64 = get_key(NONCE_KEY);
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
+// Get unclaimed rewards of a relayer
+#[no_mangle]
+pub extern "C" fn get_relayer_rewards() {
+    let relayer: Key = runtime::get_named_arg("relayer");
+    let owed = relayer_balance(RELAYER_REWARDS_KEY, relayer);
+    runtime::ret(CLValue::from_t(owed).unwrap_or_revert());
+}
+
+// Get unclaimed gas refunds of a relayer
+#[no_mangle]
+pub extern "C" fn get_gas_refund() {
+    let relayer: Key = runtime::get_named_arg("relayer");
+    let owed = relayer_balance(GAS_REFUNDS_KEY, relayer);
+    runtime::ret(CLValue::from_t(owed).unwrap_or_revert());
+}
+
+// Get a validator's signing record as (proofs signed, releases processed in total, last signed at)
+#[no_mangle]
+pub extern "C" fn get_validator_stats() {
+    let address: Key = runtime::get_named_arg("address");
+    let (signed, last_signed_at): (u64, u64) =
+        storage::dictionary_get(get_dictionary(VALIDATOR_STATS_KEY), &format!("{:?}", address))
+            .unwrap_or_revert()
+            .unwrap_or_default();
+    let releases: u64 = get_key(RELEASE_COUNT_KEY);
+    runtime::ret(CLValue::from_t((signed, releases, last_signed_at)).unwrap_or_revert());
+}
+
+// Get a validator's claimable share of an epoch's rewards and the current epoch index
+#[no_mangle]
+pub extern "C" fn get_validator_reward() {
+    let address: Key = runtime::get_named_arg("address");
+    let epoch: u64 = runtime::get_named_arg("epoch");
+    let share = validator_epoch_share(epoch, address);
+    runtime::ret(CLValue::from_t((share, current_epoch())).unwrap_or_revert());
+}
+
+// Get everything an address can withdraw: relayer rewards, gas refunds and release dust
+#[no_mangle]
+pub extern "C" fn get_claimable() {
+    let address: Key = runtime::get_named_arg("address");
+    let claimable = relayer_balance(RELAYER_REWARDS_KEY, address)
+        + relayer_balance(GAS_REFUNDS_KEY, address)
+        + relayer_balance(DUST_BALANCES_KEY, address);
+    runtime::ret(CLValue::from_t(claimable).unwrap_or_revert());
+}
+
+// Get the total credited to relayers, recipients' dust and validator pools, not yet claimed
+#[no_mangle]
+pub extern "C" fn get_unclaimed_total() {
+    let unclaimed: U512 = get_key(UNCLAIMED_TOTAL_KEY);
+    runtime::ret(CLValue::from_t(unclaimed).unwrap_or_revert());
+}
+
+// Get an audit log entry by index
+#[no_mangle]
+pub extern "C" fn get_audit_entry() {
+    let index: u64 = runtime::get_named_arg("index");
+
+    let entry: AuditEntry = storage::dictionary_get(get_dictionary(AUDIT_LOG_KEY), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+
+    runtime::ret(CLValue::from_t(entry).unwrap_or_revert());
+}
+
+// Get how many releases have paid the given user
+#[no_mangle]
+pub extern "C" fn get_user_release_count() {
+    let user: Key = runtime::get_named_arg("user");
+    let count: Option<u64> =
+        storage::dictionary_get(get_dictionary(USER_RELEASE_COUNTS_KEY), &format!("{:?}", user)).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(count.unwrap_or_default()).unwrap_or_revert());
+}
+
+// Get a user's release receipt by index, oldest first, as (proof key, amount, blocktime);
+// the proof key names the asset_released_{proof_key} event
+#[no_mangle]
+pub extern "C" fn get_user_release() {
+    let user: Key = runtime::get_named_arg("user");
+    let index: u64 = runtime::get_named_arg("index");
+
+    let receipt: (String, U512, u64) =
+        storage::dictionary_get(get_dictionary(USER_RELEASES_KEY), &format!("{:?}_{}", user, index))
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(casper_types::ApiError::MissingKey);
+
+    runtime::ret(CLValue::from_t(receipt).unwrap_or_revert());
+}
+
+// Get number of audit log entries
+#[no_mangle]
+pub extern "C" fn get_audit_count() {
+    let count: u64 = get_key(AUDIT_COUNT_KEY);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+// Set how many attested checkpoints may postdate a release's source block before the proof is
+// rejected (owner only, 0 disables)
+#[no_mangle]
+pub extern "C" fn set_max_proof_age() {
+    require_config_owner();
+
+    let chain: String = runtime::get_named_arg("chain");
+    let checkpoints: u64 = runtime::get_named_arg("checkpoints");
+    let chains: Vec<String> = get_key(SUPPORTED_CHAINS_KEY);
+    if !chains.contains(&chain) {
+        runtime::revert(casper_types::ApiError::InvalidArgument);
+    }
+
+    let mut params = chain.to_bytes().unwrap_or_revert();
+    params.extend(checkpoints.to_bytes().unwrap_or_revert());
+
+    storage::dictionary_put(get_dictionary(MAX_PROOF_AGES_KEY), &chain, checkpoints);
+
+    record_audit(ENTRY_POINT_SET_MAX_PROOF_AGE, &params);
+}
+
+// Exempt one proof from the max proof age, e.g. a burn stuck behind a relayer outage (owner only)
+#[no_mangle]
+pub extern "C" fn allow_stale_proof() {
+    require_config_owner();
+
+    let source_chain: String = runtime::get_named_arg("source_chain");
+    let nonce: u64 = runtime::get_named_arg("nonce");
+    let log_index: u32 = runtime::get_named_arg("log_index");
+    storage::dictionary_put(
+        get_dictionary(STALE_PROOF_ALLOWLIST_KEY),
+        &proof_key(&source_chain, nonce, log_index),
+        true,
+    );
+
+    let mut params = source_chain.to_bytes().unwrap_or_revert();
+    params.extend(nonce.to_bytes().unwrap_or_revert());
+    params.extend(log_index.to_bytes().unwrap_or_revert());
+    record_audit(ENTRY_POINT_ALLOW_STALE_PROOF, &params);
+}
+
+// Get a chain's max proof age in checkpoints (0 if unlimited)
+#[no_mangle]
+pub extern "C" fn get_max_proof_age() {
+    let chain: String = runtime::get_named_arg("chain");
+    let checkpoints: Option<u64> =
+        storage::dictionary_get(get_dictionary(MAX_PROOF_AGES_KEY), &chain).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(checkpoints.unwrap_or_default()).unwrap_or_revert());
+}
+
+// Contract installer (the critical "call" entry point)
+#[no_mangle]
+pub extern "C" fn call() {
+    // Get installation parameters
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    // Disaster recovery: bootstrap from a previous vault's export_config instead of fresh init args
+    let snapshot: Option<Bytes> = runtime::try_get_named_arg("snapshot");
+
+    // Define entry points
+    let mut entry_points = EntryPoints::new();
+
+    // init
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_INIT,
+        vec![
+            Parameter::new("required_sigs", CLType::U32),
+            Parameter::new("min_amount", CLType::U512),
+            Parameter::new("validators", CLType::Option(Box::new(CLType::List(Box::new(CLType::Key))))),
+            Parameter::new("vault_hash", CLType::ByteArray(32)),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // lock_cspr
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LOCK_CSPR,
+        vec![
+            Parameter::new("destination_chain", CLType::String),
+            Parameter::new("destination_address", CLType::String),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // release_cspr
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_RELEASE_CSPR,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("source_tx_hash", CLType::String),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+            Parameter::new("signatures", CLType::Any),
+            Parameter::new("digest_version", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // claim_relayer_reward
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_RELAYER_REWARD,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // add_validator
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_VALIDATOR,
+        vec![Parameter::new("validator", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // remove_validator
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_VALIDATOR,
+        vec![Parameter::new("validator", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_required_signatures
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_REQUIRED_SIGNATURES,
+        vec![Parameter::new("count", CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_max_signatures
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_MAX_SIGNATURES,
+        vec![Parameter::new("count", CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_relayer_reward
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_RELAYER_REWARD,
+        vec![Parameter::new("reward", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // pause
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PAUSE,
+        vec![Parameter::new("reason", CLType::Option(Box::new(CLType::String)))],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // unpause
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UNPAUSE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // export_for_migration
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_EXPORT_FOR_MIGRATION,
+        vec![Parameter::new("dry_run", CLType::Bool)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_migration_purse
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_MIGRATION_PURSE,
+        vec![],
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // import_from_previous
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_IMPORT_FROM_PREVIOUS,
+        vec![
+            Parameter::new("previous_vault", CLType::Key),
+            Parameter::new("dry_run", CLType::Bool),
+            Parameter::new("signatures", CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // is_validator
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_IS_VALIDATOR,
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_validators
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_VALIDATORS,
+        vec![],
+        CLType::List(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // is_proof_processed
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_IS_PROOF_PROCESSED,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_total_locked
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOTAL_LOCKED,
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_nonce
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_NONCE,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_audit_entry
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_AUDIT_ENTRY,
+        vec![Parameter::new("index", CLType::U64)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_audit_count
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_AUDIT_COUNT,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_relayer_rewards
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_RELAYER_REWARDS,
+        vec![Parameter::new("relayer", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_churn_limit
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_CHURN_LIMIT,
+        vec![
+            Parameter::new("limit", CLType::U32),
+            Parameter::new("window", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_config
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CONFIG,
+        vec![],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_lock_commitment
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_LOCK_COMMITMENT,
+        vec![
+            Parameter::new("start_nonce", CLType::U64),
+            Parameter::new("end_nonce", CLType::U64),
+        ],
+        CLType::ByteArray(32),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_merkle_root
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_MERKLE_ROOT,
+        vec![],
+        CLType::ByteArray(32),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_merkle_path
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_MERKLE_PATH,
+        vec![Parameter::new("nonce", CLType::U64)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_gas_cost_table
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_GAS_COST_TABLE,
+        vec![
+            Parameter::new("base", CLType::U512),
+            Parameter::new("per_signature", CLType::U512),
+            Parameter::new("per_byte", CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // claim_gas_refund
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_GAS_REFUND,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_gas_refund
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_GAS_REFUND,
+        vec![Parameter::new("relayer", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_submission_mode
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_SUBMISSION_MODE,
+        vec![Parameter::new("mode", CLType::U8)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // add_relayer
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_RELAYER,
+        vec![Parameter::new("relayer", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // remove_relayer
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_RELAYER,
+        vec![Parameter::new("relayer", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // is_relayer
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_IS_RELAYER,
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // register_deposit_route
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REGISTER_DEPOSIT_ROUTE,
+        vec![
+            Parameter::new("destination_chain", CLType::String),
+            Parameter::new("destination_address", CLType::String),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // lock_to_route
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_LOCK_TO_ROUTE,
+        vec![
+            Parameter::new("route_id", CLType::U64),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // add_supported_chain
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_SUPPORTED_CHAIN,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // remove_supported_chain
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_SUPPORTED_CHAIN,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_metadata
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_METADATA,
+        vec![],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_digest_version
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_DIGEST_VERSION,
+        vec![
+            Parameter::new("version", CLType::U8),
+            Parameter::new("transition_window", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // sweep_stray_tokens
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SWEEP_STRAY_TOKENS,
+        vec![
+            Parameter::new("token", CLType::Key),
+            Parameter::new("to", CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // cancel_operation
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CANCEL_OPERATION,
+        vec![Parameter::new("operation_id", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // sweep_excess_cspr
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SWEEP_EXCESS_CSPR,
+        vec![Parameter::new("to", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_chain_decimals
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_CHAIN_DECIMALS,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("decimals", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_chain_finality
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_CHAIN_FINALITY,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("min_confirmations", CLType::U64),
+            Parameter::new("challenge_window", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_chain_finality
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CHAIN_FINALITY,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_max_tvl
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_MAX_TVL,
+        vec![Parameter::new("max_tvl", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // pause_with_signatures
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PAUSE_WITH_SIGNATURES,
+        vec![Parameter::new("signatures", CLType::Any)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_admin_nonce
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_ADMIN_NONCE,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_recipient_limit
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_RECIPIENT_LIMIT,
+        vec![
+            Parameter::new("limit", CLType::U512),
+            Parameter::new("window", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // execute_queued_release
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_EXECUTE_QUEUED_RELEASE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // cancel_queued_release
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CANCEL_QUEUED_RELEASE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_queued_release
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_QUEUED_RELEASE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_anomaly_bounds
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_ANOMALY_BOUNDS,
+        vec![
+            Parameter::new("window", CLType::U64),
+            Parameter::new("max_recipients", CLType::U32),
+            Parameter::new("min_average", CLType::U512),
+            Parameter::new("max_average", CLType::U512),
+            Parameter::new("min_sample", CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // rotate_validator_key
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ROTATE_VALIDATOR_KEY,
+        vec![
+            Parameter::new("old_public_key", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("new_public_key", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("proof_of_possession", CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // rotate_validator_set
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ROTATE_VALIDATOR_SET,
+        vec![
+            Parameter::new("new_validators", CLType::List(Box::new(CLType::List(Box::new(CLType::U8))))),
+            Parameter::new("required_signatures", CLType::U32),
+            Parameter::new("proofs_of_possession", CLType::List(Box::new(CLType::List(Box::new(CLType::U8))))),
+            Parameter::new("signatures", CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // sunset
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SUNSET,
+        vec![Parameter::new("signatures", CLType::Any)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_claimable
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CLAIMABLE,
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_unclaimed_total
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_UNCLAIMED_TOTAL,
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_dust_threshold
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_DUST_THRESHOLD,
+        vec![Parameter::new("threshold", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // claim_dust
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_DUST,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_validator_stats
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_VALIDATOR_STATS,
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_validator_fee
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_VALIDATOR_FEE,
+        vec![Parameter::new("fee", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // claim_validator_reward
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CLAIM_VALIDATOR_REWARD,
+        vec![Parameter::new("epoch", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // roll_expired_validator_rewards
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ROLL_EXPIRED_VALIDATOR_REWARDS,
+        vec![Parameter::new("epoch", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_validator_reward
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_VALIDATOR_REWARD,
+        vec![
+            Parameter::new("address", CLType::Key),
+            Parameter::new("epoch", CLType::U64),
+        ],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // register_proof_header
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REGISTER_PROOF_HEADER,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("source_tx_hash", CLType::String),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+            Parameter::new("digest_version", CLType::U8),
+        ],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // add_signatures
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_SIGNATURES,
+        vec![
+            Parameter::new("proof_id", CLType::String),
+            Parameter::new("signatures", CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // finalize_release
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_FINALIZE_RELEASE,
+        vec![Parameter::new("proof_id", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_pending_signers
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_PENDING_SIGNERS,
+        vec![Parameter::new("proof_id", CLType::String)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // release_cspr_idempotent
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_RELEASE_CSPR_IDEMPOTENT,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("source_tx_hash", CLType::String),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("recipient", CLType::Key),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+            Parameter::new("signatures", CLType::Any),
+            Parameter::new("digest_version", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // admin_multicall
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADMIN_MULTICALL,
+        vec![Parameter::new("ops", CLType::Any)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_token_mapping
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_TOKEN_MAPPING,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("remote_token", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_token_mapping
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_TOKEN_MAPPING,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_strict_mode
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_STRICT_MODE,
+        vec![Parameter::new("enabled", CLType::Bool)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // attest_chain_head
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ATTEST_CHAIN_HEAD,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("block_number", CLType::U64),
+            Parameter::new("signatures", CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_chain_halt_timeout
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_CHAIN_HALT_TIMEOUT,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("timeout", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_chain_head
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CHAIN_HEAD,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // submit_checkpoint
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SUBMIT_CHECKPOINT,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("block_number", CLType::U64),
+            Parameter::new("block_hash", CLType::String),
+            Parameter::new("signatures", CLType::Any),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_checkpoint
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CHECKPOINT,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("block_number", CLType::U64),
+        ],
+        CLType::Option(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // register_hook
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REGISTER_HOOK,
+        vec![
+            Parameter::new("address", CLType::Key),
+            Parameter::new("event_kind", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // unregister_hook
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UNREGISTER_HOOK,
+        vec![
+            Parameter::new("address", CLType::Key),
+            Parameter::new("event_kind", CLType::U8),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_hooks
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_HOOKS,
+        vec![],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // add_guardian
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_ADD_GUARDIAN,
+        vec![Parameter::new("guardian", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // remove_guardian
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_REMOVE_GUARDIAN,
+        vec![Parameter::new("guardian", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // dispute_release
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_DISPUTE_RELEASE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // resolve_dispute
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_RESOLVE_DISPUTE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+            Parameter::new("approve", CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_dispute
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_DISPUTE,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_operating_mode
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_OPERATING_MODE,
+        vec![
+            Parameter::new("mode", CLType::U8),
+            Parameter::new("reason", CLType::Option(Box::new(CLType::String))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_restricted_limits
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_RESTRICTED_LIMITS,
+        vec![
+            Parameter::new("max_transfer", CLType::U512),
+            Parameter::new("timelock", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // init_from_snapshot
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_INIT_FROM_SNAPSHOT,
+        vec![
+            Parameter::new("snapshot", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("attestation_signatures", CLType::Any),
+            Parameter::new("previous_vault", CLType::Key),
+            Parameter::new("vault_hash", CLType::ByteArray(32)),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // export_config
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_EXPORT_CONFIG,
+        vec![],
+        CLType::List(Box::new(CLType::U8)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_chain_address_format
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_CHAIN_ADDRESS_FORMAT,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("format", CLType::U8),
+            Parameter::new("hrp", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_chain_address_format
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_CHAIN_ADDRESS_FORMAT,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_peer_vault
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_PEER_VAULT,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_peer_vault
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_PEER_VAULT,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let validator: Key = runtime::get_named_arg("validator");
+    // set_fee_exempt
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_FEE_EXEMPT,
+        vec![
+            Parameter::new("account", CLType::Key),
+            Parameter::new("exempt", CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let validators_dict = runtime::get_key(VALIDATORS_KEY)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
+    // is_fee_exempt
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_IS_FEE_EXEMPT,
+        vec![Parameter::new("account", CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let validator_key = format!("{:?}", validator);
-    storage::dictionary_put(validators_dict, &validator_key, false);
-}
+    // set_gas_oracle
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_GAS_ORACLE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Set required signatures (owner only)
-#[no_mangle]
-pub extern "C" fn set_required_signatures() {
-    require_owner();
+    // set_min_lock_multiplier
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_MIN_LOCK_MULTIPLIER,
+        vec![Parameter::new("multiplier_bps", CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let count: u32 = runtime::get_named_arg("count");
-    if count == 0 {
-        runtime::revert(casper_types::ApiError::InvalidArgument);
-    }
+    // update_relay_cost
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_UPDATE_RELAY_COST,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("cost", CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    set_key(REQUIRED_SIGNATURES_KEY, count);
-}
+    // get_min_lock_amount
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_MIN_LOCK_AMOUNT,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Pause contract (owner only)
-#[no_mangle]
-pub extern "C" fn pause() {
-    require_owner();
-    set_key(PAUSED_KEY, true);
-}
+    // get_pending_releases
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_PENDING_RELEASES,
+        vec![
+            Parameter::new("offset", CLType::U64),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Unpause contract (owner only)
-#[no_mangle]
-pub extern "C" fn unpause() {
-    require_owner();
-    set_key(PAUSED_KEY, false);
-}
+    // set_validator_organization
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_VALIDATOR_ORGANIZATION,
+        vec![
+            Parameter::new("validator", CLType::Key),
+            Parameter::new("organization", CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Check if address is validator
-#[no_mangle]
-pub extern "C" fn is_validator() {
-    let address: Key = runtime::get_named_arg("address");
+    // set_min_signer_organizations
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_MIN_SIGNER_ORGANIZATIONS,
+        vec![Parameter::new("count", CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let validators_dict = runtime::get_key(VALIDATORS_KEY)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
+    // get_validator_organization
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_VALIDATOR_ORGANIZATION,
+        vec![Parameter::new("validator", CLType::Key)],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let address_key = format!("{:?}", address);
-    let is_val: Option<bool> = storage::dictionary_get(validators_dict, &address_key)
-        .unwrap_or_revert();
+    // propose_upgrade
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PROPOSE_UPGRADE,
+        vec![
+            Parameter::new("successor", CLType::Key),
+            Parameter::new("wasm_hash", CLType::String),
+            Parameter::new("changelog_hash", CLType::String),
+            Parameter::new("activation_time", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    let result = is_val.unwrap_or(false);
-    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
-}
+    // approve_upgrade
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_APPROVE_UPGRADE,
+        vec![Parameter::new("signatures", CLType::Any)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Get total locked amount
-#[no_mangle]
-pub extern "C" fn get_total_locked() {
-    let total: U512 = get_key(TOTAL_LOCKED_KEY);
-    runtime::ret(CLValue::from_t(total).unwrap_or_revert());
-}
+    // cancel_upgrade
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CANCEL_UPGRADE,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Get current nonce
-#[no_mangle]
-pub extern "C" fn get_nonce() {
-    let nonce: u64 = get_key(NONCE_KEY);
-    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
-}
+    // get_upgrade_proposal
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_UPGRADE_PROPOSAL,
+        vec![],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-// Contract installer (the critical "call" entry point)
-#[no_mangle]
-pub extern "C" fn call() {
-    // Get installation parameters
-    let contract_name: String = runtime::get_named_arg("contract_name");
+    // set_shadow_digest_version
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_SHADOW_DIGEST_VERSION,
+        vec![Parameter::new("version", CLType::U8)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    // Define entry points
-    let mut entry_points = EntryPoints::new();
+    // get_shadow_stats
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_SHADOW_STATS,
+        vec![],
+        CLType::Tuple3([Box::new(CLType::U8), Box::new(CLType::U64), Box::new(CLType::U64)]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
 
-    // init
+    // deposit_submission_bond
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_INIT,
+        ENTRY_POINT_DEPOSIT_SUBMISSION_BOND,
         vec![
-            Parameter::new("required_sigs", CLType::U32),
-            Parameter::new("min_amount", CLType::U512),
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::URef),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -306,13 +6315,42 @@ pub extern "C" fn call() {
         EntryPointPayment::Caller,
     ));
 
-    // lock_cspr
+    // claim_submission_bond
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_LOCK_CSPR,
+        ENTRY_POINT_CLAIM_SUBMISSION_BOND,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // set_submission_bond
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_SET_SUBMISSION_BOND,
+        vec![Parameter::new("bond", CLType::U512)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // get_submission_bond
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_GET_SUBMISSION_BOND,
+        vec![Parameter::new("address", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    // confirm_delivery
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_CONFIRM_DELIVERY,
         vec![
-            Parameter::new("destination_chain", CLType::String),
-            Parameter::new("destination_address", CLType::String),
-            Parameter::new("amount", CLType::U512),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("signatures", CLType::Any),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -320,14 +6358,10 @@ pub extern "C" fn call() {
         EntryPointPayment::Caller,
     ));
 
-    // release_cspr
+    // refund_lock
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_RELEASE_CSPR,
+        ENTRY_POINT_REFUND_LOCK,
         vec![
-            Parameter::new("source_chain", CLType::String),
-            Parameter::new("source_tx_hash", CLType::String),
-            Parameter::new("amount", CLType::U512),
-            Parameter::new("recipient", CLType::Key),
             Parameter::new("nonce", CLType::U64),
             Parameter::new("signatures", CLType::Any),
         ],
@@ -337,81 +6371,94 @@ pub extern "C" fn call() {
         EntryPointPayment::Caller,
     ));
 
-    // add_validator
+    // audit_open_transfers
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_ADD_VALIDATOR,
-        vec![Parameter::new("validator", CLType::Key)],
-        CLType::Unit,
+        ENTRY_POINT_AUDIT_OPEN_TRANSFERS,
+        vec![
+            Parameter::new("from_nonce", CLType::U64),
+            Parameter::new("to_nonce", CLType::U64),
+        ],
+        CLType::Any,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // remove_validator
+    // get_lock_escrow
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_REMOVE_VALIDATOR,
-        vec![Parameter::new("validator", CLType::Key)],
-        CLType::Unit,
+        ENTRY_POINT_GET_LOCK_ESCROW,
+        vec![Parameter::new("nonce", CLType::U64)],
+        CLType::Any,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // set_required_signatures
+    // set_max_proof_age
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_SET_REQUIRED_SIGNATURES,
-        vec![Parameter::new("count", CLType::U32)],
+        ENTRY_POINT_SET_MAX_PROOF_AGE,
+        vec![
+            Parameter::new("chain", CLType::String),
+            Parameter::new("checkpoints", CLType::U64),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // pause
+    // allow_stale_proof
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_PAUSE,
-        vec![],
+        ENTRY_POINT_ALLOW_STALE_PROOF,
+        vec![
+            Parameter::new("source_chain", CLType::String),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("log_index", CLType::U32),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // unpause
+    // get_max_proof_age
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_UNPAUSE,
-        vec![],
-        CLType::Unit,
+        ENTRY_POINT_GET_MAX_PROOF_AGE,
+        vec![Parameter::new("chain", CLType::String)],
+        CLType::U64,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // is_validator
+    // get_user_release_count
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_IS_VALIDATOR,
-        vec![Parameter::new("address", CLType::Key)],
-        CLType::Bool,
+        ENTRY_POINT_GET_USER_RELEASE_COUNT,
+        vec![Parameter::new("user", CLType::Key)],
+        CLType::U64,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // get_total_locked
+    // get_user_release
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_GET_TOTAL_LOCKED,
-        vec![],
-        CLType::U512,
+        ENTRY_POINT_GET_USER_RELEASE,
+        vec![
+            Parameter::new("user", CLType::Key),
+            Parameter::new("index", CLType::U64),
+        ],
+        CLType::Tuple3([Box::new(CLType::String), Box::new(CLType::U512), Box::new(CLType::U64)]),
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
     ));
 
-    // get_nonce
+    // set_compact_events
     entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_GET_NONCE,
-        vec![],
-        CLType::U64,
+        ENTRY_POINT_SET_COMPACT_EVENTS,
+        vec![Parameter::new("compact", CLType::Bool)],
+        CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
@@ -431,5 +6478,40 @@ pub extern "C" fn call() {
 
     // Store contract hash under the contract name
     runtime::put_key(&contract_name, contract_hash.into());
+
+    // Initialize in the same deploy so nobody else can call init first
+    match snapshot {
+        Some(snapshot) => {
+            let attestation_signatures: Vec<(Vec<u8>, Vec<u8>)> = runtime::get_named_arg("attestation_signatures");
+            let previous_vault: Key = runtime::get_named_arg("previous_vault");
+            runtime::call_contract::<()>(
+                contract_hash,
+                ENTRY_POINT_INIT_FROM_SNAPSHOT,
+                runtime_args! {
+                    "snapshot" => snapshot,
+                    "attestation_signatures" => attestation_signatures,
+                    "previous_vault" => previous_vault,
+                    "vault_hash" => contract_hash,
+                },
+            );
+        }
+        None => {
+            let required_sigs: u32 = runtime::get_named_arg("required_sigs");
+            let min_amount: U512 = runtime::get_named_arg("min_amount");
+            let validators: Option<Vec<Key>> = runtime::try_get_named_arg("validators");
+            runtime::call_contract::<()>(
+                contract_hash,
+                ENTRY_POINT_INIT,
+                runtime_args! {
+                    "required_sigs" => required_sigs,
+                    "min_amount" => min_amount,
+                    "validators" => validators,
+                    "vault_hash" => contract_hash,
+                },
+            );
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,260 @@
+// Host unit tests for the vault's pure helpers
+// Run with `cargo test`; casper-contract is built with std off wasm, and the host functions it
+// links against are stubbed below, so only code that never reaches the runtime can be exercised
+
+use super::*;
+
+use serde_json::Value;
+
+macro_rules! host_stubs {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[no_mangle]
+            extern "C" fn $name() {
+                unreachable!(concat!(stringify!($name), " called outside the Casper runtime"));
+            }
+        )*
+    };
+}
+
+host_stubs!(
+    casper_add, casper_add_associated_key, casper_add_contract_version,
+    casper_add_contract_version_with_message_topics, casper_add_package_version_with_message_topics,
+    casper_blake2b, casper_call_contract, casper_call_package_version, casper_call_versioned_contract,
+    casper_create_contract_package_at_hash, casper_create_contract_user_group, casper_create_purse,
+    casper_dictionary_get, casper_dictionary_put, casper_dictionary_read, casper_disable_contract_version,
+    casper_emit_message, casper_enable_contract_version, casper_get_balance, casper_get_block_info,
+    casper_get_blocktime, casper_get_caller, casper_get_key, casper_get_main_purse, casper_get_named_arg,
+    casper_get_named_arg_size, casper_get_phase, casper_get_system_contract, casper_has_key,
+    casper_is_valid_uref, casper_load_authorization_keys, casper_load_call_stack,
+    casper_load_caller_information, casper_load_named_keys, casper_manage_message_topic,
+    casper_new_dictionary, casper_new_uref, casper_provision_contract_user_group_uref, casper_put_key,
+    casper_random_bytes, casper_read_host_buffer, casper_read_value, casper_recover_secp256k1,
+    casper_remove_associated_key, casper_remove_contract_user_group, casper_remove_contract_user_group_urefs,
+    casper_remove_key, casper_ret, casper_revert, casper_set_action_threshold,
+    casper_transfer_from_purse_to_account, casper_transfer_from_purse_to_purse, casper_transfer_to_account,
+    casper_update_associated_key, casper_verify_signature, casper_write
+);
+
+// blake2b-256 like the host, so the merkle and commitment helpers can run here
+#[no_mangle]
+extern "C" fn casper_generic_hash(in_ptr: *const u8, in_size: usize, _algorithm: u8, out_ptr: *mut u8, out_size: usize) -> i32 {
+    let input = unsafe { core::slice::from_raw_parts(in_ptr, in_size) };
+    let output = unsafe { core::slice::from_raw_parts_mut(out_ptr, out_size) };
+    output.copy_from_slice(&casper_types::Digest::hash(input).value()[..out_size]);
+    0
+}
+
+// Vectors the relayer signs against, so the two sides cannot drift apart
+const RELEASE_VECTORS: &str = include_str!("../../../relayer/vectors/release-vectors.json");
+
+// Bridge id the v5 vectors are signed for
+const VECTOR_BRIDGE_ID: &str = "0a1e7bf8e45328bdc7aa2c9a1fafeb7ec91b9f556807f829ef409cbc6f6432ab";
+
+fn optional_string(vector: &Value, field: &str) -> Option<String> {
+    vector[field].as_str().map(String::from)
+}
+
+fn vector_release(vector: &Value) -> ReleaseRecord {
+    ReleaseRecord {
+        asset: AssetId::Native,
+        amount: U512::from_dec_str(vector["amount"].as_str().unwrap()).unwrap(),
+        nonce: vector["nonce"].as_str().unwrap().parse().unwrap(),
+        recipient: Key::from_formatted_str(vector["recipient"].as_str().unwrap()).unwrap(),
+        source_chain: optional_string(vector, "source_chain").unwrap(),
+        source_tx_hash: optional_string(vector, "source_tx_hash").unwrap(),
+        source_sender: optional_string(vector, "source_sender"),
+        memo: optional_string(vector, "memo"),
+        source_block: vector["source_block"].as_u64(),
+    }
+}
+
+#[test]
+fn release_message_matches_relayer_vectors() {
+    let vectors: Value = serde_json::from_str(RELEASE_VECTORS).unwrap();
+    for vector in vectors["vectors"].as_array().unwrap() {
+        let version = vector["digest_version"].as_u64().unwrap() as u8;
+        let log_index = vector["log_index"].as_u64().unwrap() as u32;
+        let bridge_id = vector["bridge_id"].as_str().unwrap_or_default();
+        let message = release_message(version, &vector_release(vector), log_index, bridge_id);
+        assert_eq!(base16(&message), vector["message_hex"].as_str().unwrap(), "{}", vector["name"]);
+    }
+}
+
+#[test]
+fn release_message_versions_extend_each_other() {
+    let vectors: Value = serde_json::from_str(RELEASE_VECTORS).unwrap();
+    let release = vector_release(&vectors["vectors"][2]);
+    for version in 2..=LATEST_DIGEST_VERSION {
+        let previous = release_message(version - 1, &release, 3, VECTOR_BRIDGE_ID);
+        let message = release_message(version, &release, 3, VECTOR_BRIDGE_ID);
+        assert_eq!(message[0], version);
+        let body = if version == 2 { &previous[..] } else { &previous[1..] };
+        assert!(message[1..].starts_with(body), "v{} does not extend v{}", version, version - 1);
+    }
+}
+
+#[test]
+fn release_message_covers_log_index() {
+    let vectors: Value = serde_json::from_str(RELEASE_VECTORS).unwrap();
+    let release = vector_release(&vectors["vectors"][0]);
+    assert_ne!(
+        release_message(LATEST_DIGEST_VERSION, &release, 0, VECTOR_BRIDGE_ID),
+        release_message(LATEST_DIGEST_VERSION, &release, 1, VECTOR_BRIDGE_ID)
+    );
+}
+
+#[test]
+fn release_message_covers_bridge_id() {
+    let vectors: Value = serde_json::from_str(RELEASE_VECTORS).unwrap();
+    let release = vector_release(&vectors["vectors"][0]);
+    let other_vault = base16(&[0x11; 32]);
+    assert_eq!(release_message(4, &release, 0, VECTOR_BRIDGE_ID), release_message(4, &release, 0, &other_vault));
+    assert_ne!(release_message(5, &release, 0, VECTOR_BRIDGE_ID), release_message(5, &release, 0, &other_vault));
+}
+
+// Same digest as validatorSetDigest in relayer/src/signature-utils.ts
+#[test]
+fn validator_set_digest_covers_keys_and_threshold() {
+    let keys = vec![vec![1u8; 32], vec![2u8; 32], vec![3u8; 32]];
+    assert_eq!(
+        validator_set_digest(&keys, 2),
+        "8fa80a74cdf3089fc24ed1a5f1c0bc311462ad45bdeff86f1299d8f157cfbed9"
+    );
+    assert_ne!(validator_set_digest(&keys, 2), validator_set_digest(&keys, 3));
+    assert_ne!(validator_set_digest(&keys, 2), validator_set_digest(&keys[..2], 2));
+}
+
+#[test]
+fn base16_is_lowercase_hex() {
+    assert_eq!(base16(&[]), "");
+    assert_eq!(base16(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+}
+
+#[test]
+fn evm_addresses() {
+    assert!(is_evm_address("0x89205A3A3b2A69De6Dbf7f01ED13B2108B2c43e7"));
+    assert!(is_evm_address("0x89205a3a3b2a69de6dbf7f01ed13b2108b2c43e7"));
+    assert!(!is_evm_address("89205a3a3b2a69de6dbf7f01ed13b2108b2c43e7"));
+    assert!(!is_evm_address("0x89205a3a3b2a69de6dbf7f01ed13b2108b2c43e"));
+    assert!(!is_evm_address("0x89205a3a3b2a69de6dbf7f01ed13b2108b2c43e7a"));
+    assert!(!is_evm_address("0x89205a3a3b2a69de6dbf7f01ed13b2108b2c43eg"));
+}
+
+#[test]
+fn solana_addresses() {
+    // System program, wrapped SOL and the token program: 32 bytes with 32, 1 and 0 leading zeros
+    assert!(is_solana_address("11111111111111111111111111111111"));
+    assert!(is_solana_address("So11111111111111111111111111111111111111112"));
+    assert!(is_solana_address("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"));
+    // 31 and 33 bytes
+    assert!(!is_solana_address("1111111111111111111111111111111"));
+    assert!(!is_solana_address("111111111111111111111111111111111"));
+    // '0', 'O', 'I' and 'l' are not base58 digits
+    assert!(!is_solana_address("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5D0"));
+    assert!(!is_solana_address("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5Dl"));
+}
+
+#[test]
+fn bech32_addresses() {
+    // BIP-173 test vectors
+    assert!(is_bech32_address("A12UEL5L", "a"));
+    assert!(is_bech32_address("a12uel5l", "A"));
+    assert!(is_bech32_address("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", "abcdef"));
+    assert!(is_bech32_address("split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w", "split"));
+    assert!(!is_bech32_address("A12UEL5L", "b"));
+    assert!(!is_bech32_address("A12uEL5L", "a"));
+    assert!(!is_bech32_address("a12uel5m", "a"));
+    assert!(!is_bech32_address("a1b2uel5l", "a"));
+    assert!(!is_bech32_address("pzry9x0s0muk", "pzry9x0s0muk"));
+    assert!(!is_bech32_address("1pzry9x0s0muk", ""));
+}
+
+#[test]
+fn casper_accounts() {
+    assert!(is_casper_account("account-hash-5c24fc4213a208fc5a89e0ec152af482076ff424855a9ae258a67fd6e403aa5f"));
+    assert!(!is_casper_account("5c24fc4213a208fc5a89e0ec152af482076ff424855a9ae258a67fd6e403aa5f"));
+    assert!(!is_casper_account("account-hash-5c24fc4213a208fc5a89e0ec152af482076ff424855a9ae258a67fd6e403aa5"));
+    assert!(!is_casper_account("account-hash-5c24fc4213a208fc5a89e0ec152af482076ff424855a9ae258a67fd6e403aa5z"));
+    assert!(!is_casper_account("hash-5c24fc4213a208fc5a89e0ec152af482076ff424855a9ae258a67fd6e403aa5f"));
+}
+
+// blake2b-256 of the prefixed preimages, computed independently of the contract
+const LEAF_OF_ZERO: &str = "d8908c165dee785924e7421a0fd0418a19d5daeec395fd505a92a0fd3117e428";
+const PAIR_OF_ZEROS: &str = "086dabbfde6914778334b717e94921e353b7cc3f103cd2d19c5a825f30c067cc";
+const LEAF_OF_COUNTING_BYTES: &str = "0ddaaec3ffac93977c83c3d7440e9e65663850d4861be2f48532548d0a463336";
+
+fn counting_bytes() -> [u8; 32] {
+    core::array::from_fn(|i| i as u8)
+}
+
+#[test]
+fn merkle_hashes_are_prefixed_blake2b() {
+    assert_eq!(base16(&hash_leaf(&[0; 32])), LEAF_OF_ZERO);
+    assert_eq!(base16(&hash_leaf(&counting_bytes())), LEAF_OF_COUNTING_BYTES);
+    assert_eq!(base16(&hash_pair(&[0; 32], &[0; 32])), PAIR_OF_ZEROS);
+}
+
+#[test]
+fn merkle_pairs_are_ordered() {
+    let leaf = hash_leaf(&counting_bytes());
+    assert_ne!(hash_pair(&leaf, &[0; 32]), hash_pair(&[0; 32], &leaf));
+}
+
+#[test]
+fn merkle_zero_hashes_cover_every_level() {
+    let zero_hashes = merkle_zero_hashes();
+    assert_eq!(zero_hashes.len(), MERKLE_TREE_DEPTH + 1);
+    assert_eq!(zero_hashes[0], [0; 32]);
+    assert_eq!(base16(&zero_hashes[1]), PAIR_OF_ZEROS);
+    for level in 0..MERKLE_TREE_DEPTH {
+        assert_eq!(zero_hashes[level + 1], hash_pair(&zero_hashes[level], &zero_hashes[level]));
+    }
+    // An empty slot never hashes like a lock, not even a lock of all zeros
+    assert!(!zero_hashes.contains(&hash_leaf(&[0; 32])));
+}
+
+fn lock_nodes(count: usize) -> Vec<[u8; 32]> {
+    (0..count).map(|i| hash_leaf(&[i as u8; 32])).collect()
+}
+
+// Root of the tree over the nodes with every missing slot spelled out as an empty leaf
+fn padded_root(nodes: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = nodes.to_vec();
+    level.resize(nodes.len().next_power_of_two(), [0; 32]);
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+    }
+    level[0]
+}
+
+#[test]
+fn commitment_of_one_lock_is_its_leaf_node() {
+    let nodes = lock_nodes(1);
+    assert_eq!(commitment_root(nodes.clone(), &merkle_zero_hashes()), nodes[0]);
+}
+
+#[test]
+fn commitment_pads_ranges_with_empty_slots() {
+    let zero_hashes = merkle_zero_hashes();
+    for count in 1..=17 {
+        let nodes = lock_nodes(count);
+        assert_eq!(commitment_root(nodes.clone(), &zero_hashes), padded_root(&nodes), "{} locks", count);
+    }
+}
+
+#[test]
+fn commitment_of_three_locks() {
+    let nodes = lock_nodes(3);
+    let expected = hash_pair(&hash_pair(&nodes[0], &nodes[1]), &hash_pair(&nodes[2], &[0; 32]));
+    assert_eq!(commitment_root(nodes, &merkle_zero_hashes()), expected);
+}
+
+#[test]
+fn commitment_depends_on_lock_order() {
+    let zero_hashes = merkle_zero_hashes();
+    let nodes = lock_nodes(4);
+    let mut swapped = nodes.clone();
+    swapped.swap(1, 2);
+    assert_ne!(commitment_root(nodes, &zero_hashes), commitment_root(swapped, &zero_hashes));
+}
@@ -10,10 +10,10 @@ import { ethers } from 'ethers';
 import { CONTRACTS } from '../config/contracts';
 import {
   CLPublicKey,
-  DeployUtil,
-  RuntimeArgs,
-  CLValueBuilder
+  DeployUtil
 } from 'casper-js-sdk';
+import { buildLockDeploy, loadLockSessionWasm } from '../utils/lockDeploy';
+import { csprToMotes } from '../../../relayer/src/units';
 
 export function BridgeForm() {
   const {
@@ -110,8 +110,8 @@ export function BridgeForm() {
     }
 
     try {
-      // Convert amount to motes (1 CSPR = 1,000,000,000 motes)
-      const amountInMotes = BigInt(Math.floor(parseFloat(amount) * 1_000_000_000));
+      // Convert amount to motes (1 CSPR = 1,000,000,000 motes), rejecting sub-mote precision
+      const amountInMotes = csprToMotes(amount);
 
       // Get Casper Wallet provider
       const provider = window.CasperWalletProvider?.() || window.csprclick;
@@ -123,46 +123,17 @@ export function BridgeForm() {
       const publicKeyHex = await provider.getActivePublicKey();
       const publicKey = CLPublicKey.fromHex(publicKeyHex);
 
-      // Contract details - remove 'hash-' prefix and convert to byte array
-      const contractHashString = CONTRACTS.casper.vaultContract.replace('hash-', '');
-
-      // Convert hex string to Uint8Array (browser-compatible)
-      const hexToBytes = (hex: string): Uint8Array => {
-        const bytes = new Uint8Array(hex.length / 2);
-        for (let i = 0; i < hex.length; i += 2) {
-          bytes[i / 2] = parseInt(hex.substr(i, 2), 16);
-        }
-        return bytes;
-      };
-
-      const contractHashBytes = hexToBytes(contractHashString);
-
-      // Prepare runtime arguments for lock_cspr
-      const runtimeArgs = RuntimeArgs.fromMap({
-        destination_chain: CLValueBuilder.string('ethereum'),
-        destination_address: CLValueBuilder.string(ethereumAddress),
-        amount: CLValueBuilder.u512(amountInMotes.toString())
+      // lock_cspr takes motes from a purse, so the lock runs as session code
+      const sessionWasm = await loadLockSessionWasm();
+      const deploy = buildLockDeploy(sessionWasm, {
+        publicKey,
+        networkName: CONTRACTS.casper.networkName,
+        vaultContractHash: CONTRACTS.casper.vaultContract,
+        destinationChain: 'ethereum',
+        destinationAddress: ethereumAddress,
+        amountInMotes,
       });
 
-      // Create payment using the standardPayment helper
-      const payment = DeployUtil.standardPayment(5_000_000_000);
-
-      // Create deploy - pass bytes directly, not wrapped in CLByteArray
-      const deploy = DeployUtil.makeDeploy(
-        new DeployUtil.DeployParams(
-          publicKey,
-          CONTRACTS.casper.networkName,
-          1, // Gas price
-          1800000 // TTL (30 minutes)
-        ),
-        DeployUtil.ExecutableDeployItem.newStoredContractByHash(
-          contractHashBytes,
-          'lock_cspr',
-          runtimeArgs
-        ),
-        payment
-      );
-
       console.log('Deploy created, requesting signature from wallet...');
       setStatus('signing');
 
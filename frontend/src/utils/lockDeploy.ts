@@ -0,0 +1,111 @@
+/**
+ * Lock Deploy Builder
+ *
+ * lock_cspr pulls motes from a purse passed by the caller, so accounts must run
+ * session code that creates a purse, funds it from their main purse, and calls
+ * the vault with it. This helper builds that session deploy.
+ */
+
+import { CLPublicKey, CLValueBuilder, DeployUtil, RuntimeArgs } from 'casper-js-sdk';
+import { motesToCspr } from '../../../relayer/src/units';
+
+// Session wasm built from contracts/casper/session/lock_cspr_session
+export const LOCK_SESSION_WASM_URL = '/lock_cspr_session.wasm';
+
+export interface LockDeployParams {
+  publicKey: CLPublicKey;
+  networkName: string;
+  vaultContractHash: string; // "hash-" prefixed or bare hex
+  destinationChain: string;
+  destinationAddress: string;
+  amountInMotes: bigint;
+  route?: Array<{ chain: string; address: string }>; // onward hops after destinationChain
+  paymentInMotes?: number;
+  ttlMs?: number;
+}
+
+const hexToBytes = (hex: string): Uint8Array => {
+  const bytes = new Uint8Array(hex.length / 2);
+  for (let i = 0; i < hex.length; i += 2) {
+    bytes[i / 2] = parseInt(hex.substr(i, 2), 16);
+  }
+  return bytes;
+};
+
+/**
+ * Fetch the lock session wasm served alongside the frontend
+ */
+export async function loadLockSessionWasm(url: string = LOCK_SESSION_WASM_URL): Promise<Uint8Array> {
+  const response = await fetch(url);
+  if (!response.ok) {
+    throw new Error(`Failed to load lock session wasm from ${url}: ${response.status}`);
+  }
+  return new Uint8Array(await response.arrayBuffer());
+}
+
+/**
+ * Build an unsigned deploy that locks CSPR through the session wasm
+ */
+export function buildLockDeploy(sessionWasm: Uint8Array, params: LockDeployParams): DeployUtil.Deploy {
+  const contractHashBytes = hexToBytes(params.vaultContractHash.replace('hash-', ''));
+
+  // Argument names must match lock_cspr_session
+  const sessionArgs = RuntimeArgs.fromMap({
+    vault_contract_hash: CLValueBuilder.byteArray(contractHashBytes),
+    destination_chain: CLValueBuilder.string(params.destinationChain),
+    destination_address: CLValueBuilder.string(params.destinationAddress),
+    amount: CLValueBuilder.u512(params.amountInMotes.toString()),
+  });
+  if (params.route && params.route.length > 0) {
+    sessionArgs.insert(
+      'route',
+      CLValueBuilder.list(
+        params.route.map((hop) =>
+          CLValueBuilder.tuple2([CLValueBuilder.string(hop.chain), CLValueBuilder.string(hop.address)])
+        )
+      )
+    );
+  }
+
+  return DeployUtil.makeDeploy(
+    new DeployUtil.DeployParams(
+      params.publicKey,
+      params.networkName,
+      1, // Gas price
+      params.ttlMs ?? 1800000 // TTL (30 minutes)
+    ),
+    DeployUtil.ExecutableDeployItem.newModuleBytes(sessionWasm, sessionArgs),
+    DeployUtil.standardPayment(params.paymentInMotes ?? 5_000_000_000)
+  );
+}
+
+export interface LockPreview {
+  lines: string[]; // "Label: value" rows in display order
+  deployHash: string; // hex hash the wallet signature covers
+}
+
+const formatMotes = (motes: bigint): string => `${motesToCspr(motes)} CSPR`;
+
+/**
+ * Summarize a lock deploy for display before the wallet signs it
+ * Pass the same params used for buildLockDeploy
+ */
+export function describeLockDeploy(deploy: DeployUtil.Deploy, params: LockDeployParams): LockPreview {
+  const payment = BigInt(params.paymentInMotes ?? 5_000_000_000);
+  const expiresAt = new Date(deploy.header.timestamp + deploy.header.ttl);
+
+  const lines = [
+    `Action: Lock CSPR on Casper`,
+    `Amount: ${formatMotes(params.amountInMotes)}`,
+    `Destination: ${params.destinationChain}`,
+    `Recipient: ${params.destinationAddress}`,
+  ];
+  for (const hop of params.route ?? []) {
+    lines.push(`Then: ${hop.chain} ${hop.address}`);
+  }
+  lines.push(`Max network fee: ${formatMotes(payment)}`);
+  lines.push(`Expires: ${expiresAt.toISOString()}`);
+
+  const deployHash = Array.from(deploy.hash, (b) => b.toString(16).padStart(2, '0')).join('');
+  return { lines, deployHash };
+}
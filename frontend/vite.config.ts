@@ -7,6 +7,10 @@ export default defineConfig({
   server: {
     port: 3000,
     open: true,
+    fs: {
+      // Amount parsing is shared with the relayer (relayer/src/units.ts)
+      allow: ['..'],
+    },
     proxy: {
       '/api': {
         target: 'http://localhost:3001',
@@ -0,0 +1,13 @@
+// Compile TypeScript sources for jest with the project's own compiler and tsconfig
+const ts = require('typescript');
+const { compilerOptions } = require('./tsconfig.json');
+
+module.exports = {
+  process(source, fileName) {
+    const { outputText } = ts.transpileModule(source, {
+      fileName,
+      compilerOptions: { ...compilerOptions, module: 'commonjs', declaration: false, declarationMap: false, sourceMap: false },
+    });
+    return { code: outputText };
+  },
+};
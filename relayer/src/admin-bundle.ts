@@ -0,0 +1,267 @@
+/**
+ * Offline Admin Signing
+ *
+ * Collects validator signatures for a quorum-signed vault admin action
+ * without any signer going online. A coordinator proposes a bundle, each
+ * validator signs it on an air-gapped machine, and the coordinator submits
+ * the combined signatures:
+ *
+ *   npm run admin -- propose --op pause --bridge-id <id> --admin-nonce <n> --out pause.json
+ *   npm run admin -- sign --bundle pause.json --key <private-key-file>
+ *   npm run admin -- submit --bundle pause.json
+ *
+ * Ops: pause (pause_with_signatures), sunset (sunset; the submitting account
+ * must be the config owner), upgrade (approve_upgrade, needs
+ * --proposal-hash) and migrate (import_from_previous on the new vault, needs
+ * --previous-vault; the submitter must be the config owner). The admin nonce is get_admin_nonce; any other admin
+ * action executing first bumps it and invalidates the bundle. Only submit
+ * needs the relayer config and network access. Validator set rotations
+ * (rotate_validator_set) use the same bundles, planned and signed with
+ * `npm run rotate-key -- plan|sign|submit`.
+ */
+
+import * as fs from 'fs';
+import * as ed25519 from '@noble/ed25519';
+import { sha512 } from '@noble/hashes/sha512';
+import { CasperClient, CLValueBuilder, DeployUtil, RuntimeArgs } from 'casper-js-sdk';
+import { loadConfig } from './config';
+import {
+  CasperSigner,
+  createAdminMessage,
+  createMigrationMessage,
+  createUpgradeMessage,
+  createValidatorSetMessage,
+  validatorSetDigest,
+} from './signature-utils';
+import { submitDeploy, waitForDeploy } from './deploy-tracker';
+
+ed25519.etc.sha512Sync = (...m) => sha512(ed25519.etc.concatBytes(...m));
+
+const ADMIN_OPS: Record<string, { entryPoint: string; action: string }> = {
+  pause: { entryPoint: 'pause_with_signatures', action: 'PAUSE' },
+  sunset: { entryPoint: 'sunset', action: 'SUNSET' },
+  upgrade: { entryPoint: 'approve_upgrade', action: 'UPGRADE' },
+  migrate: { entryPoint: 'import_from_previous', action: 'MIGRATE' },
+  'rotate-set': { entryPoint: 'rotate_validator_set', action: 'ROTATE_SET' },
+};
+
+export interface AdminBundle {
+  op: string;
+  entry_point: string;
+  bridge_id: string;
+  admin_nonce: string;
+  proposal_hash?: string; // upgrade only
+  previous_vault?: string; // migrate only, contract hash hex
+  validators?: string[]; // rotate-set only, new set's public keys as ascending hex
+  required_signatures?: number; // rotate-set only, new set's threshold
+  proofs_of_possession?: Array<{ public_key: string; signature: string }>; // rotate-set only, one per new key
+  message_hex: string; // exact bytes every signer signs
+  signatures: Array<{ public_key: string; signature: string }>; // hex
+}
+
+/**
+ * Build an unsigned bundle for an admin op
+ */
+export function proposeBundle(
+  op: string,
+  bridgeId: string,
+  adminNonce: string,
+  proposalHash?: string,
+  previousVault?: string,
+  rotation?: { validators: string[]; requiredSignatures: number }
+): AdminBundle {
+  const spec = ADMIN_OPS[op];
+  if (!spec) {
+    throw new Error(`Unknown op "${op}", expected one of ${Object.keys(ADMIN_OPS).join(', ')}`);
+  }
+  if ((op === 'upgrade') !== (proposalHash !== undefined)) {
+    throw new Error('--proposal-hash is required for upgrade and only for upgrade');
+  }
+  if ((op === 'migrate') !== (previousVault !== undefined)) {
+    throw new Error('--previous-vault is required for migrate and only for migrate');
+  }
+  if ((op === 'rotate-set') !== (rotation !== undefined)) {
+    throw new Error('rotate-set bundles are planned with `npm run rotate-key -- plan`');
+  }
+
+  const previous = previousVault?.replace(/^(hash-|0x)/, '').toLowerCase();
+  const validators = rotation?.validators.map((key) => key.toLowerCase()).sort();
+  const setDigest = rotation && validatorSetDigest(validators!, rotation.requiredSignatures);
+  const message =
+    op === 'upgrade'
+      ? createUpgradeMessage(bridgeId, adminNonce, proposalHash!)
+      : op === 'migrate'
+        ? createMigrationMessage(bridgeId, adminNonce, previous!)
+        : op === 'rotate-set'
+          ? createValidatorSetMessage(bridgeId, adminNonce, setDigest!)
+          : createAdminMessage(spec.action, bridgeId, adminNonce);
+
+  return {
+    op,
+    entry_point: spec.entryPoint,
+    bridge_id: bridgeId,
+    admin_nonce: adminNonce,
+    proposal_hash: proposalHash,
+    previous_vault: previous,
+    validators,
+    required_signatures: rotation?.requiredSignatures,
+    proofs_of_possession: rotation ? [] : undefined,
+    message_hex: Buffer.from(message).toString('hex'),
+    signatures: [],
+  };
+}
+
+/**
+ * Add the signer's signature over the bundle message, replacing any earlier
+ * one from the same key
+ */
+export async function signBundle(bundle: AdminBundle, signer: CasperSigner): Promise<AdminBundle> {
+  const signature = await signer.signMessage(Uint8Array.from(Buffer.from(bundle.message_hex, 'hex')));
+  const publicKey = signer.getPublicKeyHex();
+  return {
+    ...bundle,
+    signatures: [
+      ...bundle.signatures.filter((entry) => entry.public_key !== publicKey),
+      { public_key: publicKey, signature: Buffer.from(signature).toString('hex') },
+    ],
+  };
+}
+
+/**
+ * Public keys whose signatures do not verify against the bundle message
+ */
+export function invalidSignatures(bundle: AdminBundle): string[] {
+  const message = Buffer.from(bundle.message_hex, 'hex');
+  return bundle.signatures
+    .filter((entry) => {
+      try {
+        return !ed25519.verify(entry.signature, message, entry.public_key);
+      } catch {
+        return true;
+      }
+    })
+    .map((entry) => entry.public_key);
+}
+
+function parseArgs(argv: string[]): { command: string; flags: Record<string, string> } {
+  const [command, ...rest] = argv;
+  const flags: Record<string, string> = {};
+  for (let i = 0; i < rest.length; i++) {
+    if (rest[i].startsWith('--')) {
+      flags[rest[i].slice(2)] = rest[++i];
+    }
+  }
+  return { command, flags };
+}
+
+function requireFlags(flags: Record<string, string>, names: string[], usage: string): void {
+  if (names.some((name) => !flags[name])) {
+    throw new Error(`Usage: admin ${usage}`);
+  }
+}
+
+/**
+ * Submit a fully signed bundle from the configured account and wait for it to execute
+ */
+export async function submitBundle(bundle: AdminBundle): Promise<void> {
+  const invalid = invalidSignatures(bundle);
+  if (invalid.length > 0) {
+    throw new Error(`Signatures do not verify for ${invalid.join(', ')}`);
+  }
+
+  const config = loadConfig();
+  const submitter = new CasperSigner(config.casper.privateKeyHex);
+  const u8List = (hex: string) =>
+    CLValueBuilder.list(Array.from(Buffer.from(hex, 'hex')).map((b) => CLValueBuilder.u8(b)));
+
+  const args = RuntimeArgs.fromMap({
+    // Vec<(Vec<u8>, Vec<u8>)> of (Ed25519 public key, signature)
+    signatures: CLValueBuilder.list(
+      bundle.signatures.map((entry) => CLValueBuilder.tuple2([u8List(entry.public_key), u8List(entry.signature)]))
+    ),
+  });
+  if (bundle.op === 'migrate') {
+    // Submitted to the new vault, which is the configured vault once it is live
+    args.insert(
+      'previous_vault',
+      CLValueBuilder.key(CLValueBuilder.byteArray(Uint8Array.from(Buffer.from(bundle.previous_vault!, 'hex'))))
+    );
+    args.insert('dry_run', CLValueBuilder.bool(false));
+  }
+  if (bundle.op === 'rotate-set') {
+    // Proofs are passed in the same order as the keys they belong to
+    const proofs = bundle.validators!.map((key) => {
+      const proof = bundle.proofs_of_possession!.find((entry) => entry.public_key === key);
+      if (!proof) {
+        throw new Error(`No proof of possession for ${key}`);
+      }
+      return u8List(proof.signature);
+    });
+    args.insert('new_validators', CLValueBuilder.list(bundle.validators!.map(u8List)));
+    args.insert('required_signatures', CLValueBuilder.u32(bundle.required_signatures!));
+    args.insert('proofs_of_possession', CLValueBuilder.list(proofs));
+  }
+
+  const session = DeployUtil.ExecutableDeployItem.newStoredContractByHash(
+    Uint8Array.from(Buffer.from(config.casper.vaultContract.replace('hash-', ''), 'hex')),
+    bundle.entry_point,
+    args
+  );
+
+  const deploy = DeployUtil.makeDeploy(
+    new DeployUtil.DeployParams(submitter.getPublicKeyCL(), config.casper.networkName, 1, 1800000),
+    session,
+    DeployUtil.standardPayment(3_000_000_000)
+  );
+
+  const client = new CasperClient(config.casper.rpcUrl);
+  const deployHash = await submitDeploy(client, DeployUtil.signDeploy(deploy, submitter.getKeyPair()));
+  console.log(`${bundle.entry_point} deploy: ${deployHash}`);
+
+  const outcome = await waitForDeploy(client, deployHash);
+  if (!outcome.success) {
+    throw outcome.error!;
+  }
+  console.log(`${bundle.op} executed with ${bundle.signatures.length} signatures`);
+}
+
+async function main() {
+  const { command, flags } = parseArgs(process.argv.slice(2));
+  const readBundle = (): AdminBundle => JSON.parse(fs.readFileSync(flags.bundle, 'utf8'));
+
+  if (command === 'propose') {
+    requireFlags(
+      flags,
+      ['op', 'bridge-id', 'admin-nonce', 'out'],
+      'propose --op <pause|sunset|upgrade|migrate> --bridge-id <id> --admin-nonce <n> [--proposal-hash <hex>] ' +
+        '[--previous-vault <contract-hash>] --out <file>'
+    );
+    const bundle = proposeBundle(
+      flags.op,
+      flags['bridge-id'],
+      flags['admin-nonce'],
+      flags['proposal-hash'],
+      flags['previous-vault']
+    );
+    fs.writeFileSync(flags.out, JSON.stringify(bundle, null, 2), { flag: 'wx' });
+    console.log(`Bundle written to ${flags.out}; message ${bundle.message_hex}`);
+  } else if (command === 'sign') {
+    requireFlags(flags, ['bundle', 'key'], 'sign --bundle <file> --key <private-key-file>');
+    const signer = new CasperSigner(fs.readFileSync(flags.key, 'utf8').trim());
+    const bundle = await signBundle(readBundle(), signer);
+    fs.writeFileSync(flags.bundle, JSON.stringify(bundle, null, 2));
+    console.log(`Signed as ${signer.getPublicKeyHex()} (${bundle.signatures.length} signatures)`);
+  } else if (command === 'submit') {
+    requireFlags(flags, ['bundle'], 'submit --bundle <file>');
+    await submitBundle(readBundle());
+  } else {
+    throw new Error('Usage: admin <propose|sign|submit> ...');
+  }
+}
+
+if (require.main === module) {
+  main().catch((error) => {
+    console.error('Admin bundle failed:', error.message);
+    process.exit(1);
+  });
+}
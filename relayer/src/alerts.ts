@@ -0,0 +1,288 @@
+/**
+ * Alerts
+ *
+ * Evaluates alert rules every checkIntervalMs and pushes incidents to a
+ * generic webhook, Slack and PagerDuty, so bridge incidents are noticed in
+ * minutes instead of days:
+ *
+ *   transfer-stuck     a transfer has not moved (or stayed failed) for stuckAfterMinutes
+ *   solvency           wCSPR supply on Ethereum exceeds the vault's total_locked
+ *   heartbeat-missing  no validator-attested Ethereum head for heartbeatMaxAgeMs
+ *   paused             the vault left Normal operating mode
+ *   signing-refused    a release request did not match its burn receipt (raised at once)
+ *   approval-pending   a release above the approval threshold waits for an operator (raised at once)
+ *
+ * Each alert has a key and fires once until its condition clears, when a
+ * resolution is sent; PagerDuty receives both under the key as dedup_key.
+ * A rule whose chain reads fail is logged and skipped until the next check,
+ * so RPC outages do not resolve open alerts.
+ */
+
+import { ethers } from 'ethers';
+import { CasperClient } from 'casper-js-sdk';
+import { Logger } from './logger';
+import { TransferStore } from './transfer-store';
+import { CSPR_DECIMALS, WCSPR_DECIMALS, convertDecimals, motesToCspr } from './units';
+
+const logger = Logger.getInstance();
+
+const PAGERDUTY_EVENTS_URL = 'https://events.pagerduty.com/v2/enqueue';
+
+// A slow destination must not hold up the other ones
+const DELIVERY_TIMEOUT_MS = 5000;
+
+const OPERATING_MODES = ['Normal', 'Restricted', 'Halted'];
+
+export type AlertRule = 'transfer-stuck' | 'solvency' | 'heartbeat-missing' | 'paused' | 'signing-refused' | 'approval-pending';
+
+export interface Alert {
+  rule: AlertRule;
+  key: string; // identifies one incident, e.g. "transfer-stuck:{transferId}"
+  severity: 'warning' | 'critical';
+  summary: string;
+  details: Record<string, unknown>;
+}
+
+export interface AlertConfig {
+  casperRpcUrl: string;
+  vaultContract: string;
+  ethereumRpcUrl: string;
+  chainId: number;
+  wrapperContract: string;
+  webhookUrl: string; // receives each Alert as JSON, plus "status": "triggered" | "resolved"
+  slackWebhookUrl: string; // Slack incoming webhook
+  pagerDutyRoutingKey: string; // PagerDuty Events API v2 integration key
+  checkIntervalMs: number;
+  stuckAfterMinutes: number;
+  heartbeatMaxAgeMs: number; // 0 disables the heartbeat rule
+}
+
+export class AlertManager {
+  private config: AlertConfig;
+  private casperClient: CasperClient;
+  private wrapper: ethers.Contract;
+  private transfers: TransferStore;
+  private open: Map<string, Alert> = new Map();
+  private timer?: NodeJS.Timeout;
+
+  constructor(config: AlertConfig, transfers: TransferStore) {
+    this.config = config;
+    this.transfers = transfers;
+    this.casperClient = new CasperClient(config.casperRpcUrl);
+    this.wrapper = AlertManager.wrapperContract(config);
+  }
+
+  /**
+   * Apply reloaded settings; takes effect from the next check
+   */
+  updateConfig(config: AlertConfig): void {
+    this.config = config;
+    this.casperClient = new CasperClient(config.casperRpcUrl);
+    this.wrapper = AlertManager.wrapperContract(config);
+  }
+
+  start(): void {
+    this.stop();
+    this.timer = setInterval(() => void this.check(), this.config.checkIntervalMs);
+    void this.check();
+  }
+
+  stop(): void {
+    clearInterval(this.timer);
+  }
+
+  /**
+   * Raise an alert from an event rather than a check; it stays open until resolve() is called
+   */
+  async raise(alert: Alert): Promise<void> {
+    if (this.open.has(alert.key)) {
+      return;
+    }
+    this.open.set(alert.key, alert);
+    logger.warn('🚨 Alert triggered', { rule: alert.rule, key: alert.key, summary: alert.summary });
+    await this.deliver(alert, 'triggered');
+  }
+
+  async resolve(key: string): Promise<void> {
+    const alert = this.open.get(key);
+    if (!alert) {
+      return;
+    }
+    this.open.delete(key);
+    logger.info('Alert resolved', { rule: alert.rule, key });
+    await this.deliver(alert, 'resolved');
+  }
+
+  /**
+   * Evaluate every periodic rule once
+   */
+  async check(): Promise<void> {
+    await this.checkStuckTransfers();
+    await this.guarded('solvency', () => this.checkSolvency());
+    await this.guarded('heartbeat-missing', () => this.checkHeartbeat());
+    await this.guarded('paused', () => this.checkOperatingMode());
+  }
+
+  private async checkStuckTransfers(): Promise<void> {
+    const stuck = this.transfers.stuck(this.config.stuckAfterMinutes * 60 * 1000);
+    const keys = new Set(stuck.map((transfer) => `transfer-stuck:${transfer.id}`));
+
+    for (const transfer of stuck) {
+      await this.raise({
+        rule: 'transfer-stuck',
+        key: `transfer-stuck:${transfer.id}`,
+        severity: 'warning',
+        summary: `Transfer ${transfer.id} from ${transfer.sourceChain} has been ${transfer.status} for over ${this.config.stuckAfterMinutes} minutes`,
+        details: { transfer },
+      });
+    }
+    for (const alert of Array.from(this.open.values())) {
+      if (alert.rule === 'transfer-stuck' && !keys.has(alert.key)) {
+        await this.resolve(alert.key);
+      }
+    }
+  }
+
+  private async checkSolvency(): Promise<void> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    const stored = await this.casperClient.nodeClient.getBlockState(stateRootHash, this.config.vaultContract, [
+      'total_locked',
+    ]);
+    const totalLocked = BigInt(stored.CLValue!.value().toString());
+    const supply = convertDecimals(BigInt(await this.wrapper.totalSupply()), WCSPR_DECIMALS, CSPR_DECIMALS, 'ceil');
+
+    if (supply <= totalLocked) {
+      await this.resolve('solvency');
+      return;
+    }
+    await this.raise({
+      rule: 'solvency',
+      key: 'solvency',
+      severity: 'critical',
+      summary: `wCSPR supply (${motesToCspr(supply)} CSPR) exceeds CSPR locked in the vault (${motesToCspr(totalLocked)} CSPR)`,
+      details: { supplyMotes: supply.toString(), totalLockedMotes: totalLocked.toString() },
+    });
+  }
+
+  private async checkHeartbeat(): Promise<void> {
+    if (this.config.heartbeatMaxAgeMs === 0) {
+      return;
+    }
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    let attestedAt: number | null = null;
+    let head: string | null = null;
+    try {
+      const item = await this.casperClient.nodeClient.getDictionaryItemByName(
+        stateRootHash,
+        this.config.vaultContract,
+        'chain_heads',
+        'ethereum'
+      );
+      // (block number, blocktime attested at)
+      const [blockNumber, blocktime] = item.CLValue!.value();
+      head = blockNumber.value().toString();
+      attestedAt = Number(blocktime.value().toString());
+    } catch {
+      // Missing dictionary items are reported as query errors: no heartbeat yet
+    }
+
+    const age = attestedAt === null ? null : Date.now() - attestedAt;
+    if (age !== null && age <= this.config.heartbeatMaxAgeMs) {
+      await this.resolve('heartbeat-missing');
+      return;
+    }
+    await this.raise({
+      rule: 'heartbeat-missing',
+      key: 'heartbeat-missing',
+      severity: 'critical',
+      summary:
+        age === null
+          ? 'Validators have never attested an Ethereum head'
+          : `No validator-attested Ethereum head for ${Math.round(age / 60000)} minutes`,
+      details: { head, attestedAt, heartbeatMaxAgeMs: this.config.heartbeatMaxAgeMs },
+    });
+  }
+
+  private async checkOperatingMode(): Promise<void> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    const stored = await this.casperClient.nodeClient.getBlockState(stateRootHash, this.config.vaultContract, [
+      'operating_mode',
+    ]);
+    const mode = Number(stored.CLValue!.value().toString());
+
+    if (mode === 0) {
+      await this.resolve('paused');
+      return;
+    }
+    await this.raise({
+      rule: 'paused',
+      key: 'paused',
+      severity: mode === 2 ? 'critical' : 'warning',
+      summary: `Vault is in ${OPERATING_MODES[mode] ?? `mode ${mode}`} operating mode`,
+      details: { mode },
+    });
+  }
+
+  private async guarded(rule: AlertRule, check: () => Promise<void>): Promise<void> {
+    try {
+      await check();
+    } catch (error: any) {
+      logger.warn('Could not evaluate alert rule', { rule, error: error.message });
+    }
+  }
+
+  // Send to every configured destination; failures are logged, not retried
+  private async deliver(alert: Alert, status: 'triggered' | 'resolved'): Promise<void> {
+    const { webhookUrl, slackWebhookUrl, pagerDutyRoutingKey } = this.config;
+    const deliveries: Array<[string, string, unknown]> = [];
+
+    if (webhookUrl) {
+      deliveries.push(['webhook', webhookUrl, { ...alert, status }]);
+    }
+    if (slackWebhookUrl) {
+      const prefix = status === 'resolved' ? '✅ Resolved' : alert.severity === 'critical' ? '🚨' : '⚠️';
+      deliveries.push(['slack', slackWebhookUrl, { text: `${prefix} [${alert.rule}] ${alert.summary}` }]);
+    }
+    if (pagerDutyRoutingKey) {
+      deliveries.push([
+        'pagerduty',
+        PAGERDUTY_EVENTS_URL,
+        {
+          routing_key: pagerDutyRoutingKey,
+          event_action: status === 'resolved' ? 'resolve' : 'trigger',
+          dedup_key: alert.key,
+          payload: {
+            summary: alert.summary,
+            source: this.config.vaultContract,
+            severity: alert.severity,
+            custom_details: alert.details,
+          },
+        },
+      ]);
+    }
+
+    await Promise.allSettled(
+      deliveries.map(async ([destination, url, body]) => {
+        try {
+          const response = await fetch(url, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify(body),
+            signal: AbortSignal.timeout(DELIVERY_TIMEOUT_MS),
+          });
+          if (!response.ok) {
+            throw new Error(`HTTP ${response.status}`);
+          }
+        } catch (error: any) {
+          logger.error('Failed to deliver alert', { destination, key: alert.key, status, error: error.message });
+        }
+      })
+    );
+  }
+
+  private static wrapperContract(config: AlertConfig): ethers.Contract {
+    const network = new ethers.Network('sepolia', config.chainId);
+    const provider = new ethers.JsonRpcProvider(config.ethereumRpcUrl, network, { staticNetwork: network });
+    return new ethers.Contract(config.wrapperContract, ['function totalSupply() view returns (uint256)'], provider);
+  }
+}
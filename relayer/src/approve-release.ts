@@ -0,0 +1,93 @@
+/**
+ * Release Approval CLI
+ *
+ * Lists the releases a running relayer holds for operator approval and
+ * decides them through its approval API, asking for confirmation first:
+ *
+ *   npm run approvals -- list
+ *   npm run approvals -- approve --id ethereum_7_0 --operator alice
+ *   npm run approvals -- reject --id ethereum_7_0 --operator alice --reason "unknown recipient"
+ *
+ * Talks to the relayer on the configured port unless --relayer <url> is
+ * given, authenticating with RELEASE_APPROVAL_TOKEN.
+ */
+
+import * as readline from 'readline/promises';
+import { loadConfig } from './config';
+import { ApprovalRecord } from './release-approval';
+import { motesToCspr } from './units';
+
+function parseArgs(argv: string[]): { command: string; flags: Record<string, string> } {
+  const [command, ...rest] = argv;
+  const flags: Record<string, string> = {};
+  for (let i = 0; i < rest.length; i++) {
+    if (rest[i].startsWith('--')) {
+      flags[rest[i].slice(2)] = rest[++i];
+    }
+  }
+  return { command, flags };
+}
+
+function describe(record: ApprovalRecord): string {
+  const { claim } = record;
+  return [
+    `${record.transferId}: ${motesToCspr(claim.amount)} CSPR to ${claim.recipient}`,
+    `  burn ${claim.sourceTxHash} log ${claim.logIndex} by ${claim.sourceSender ?? 'unknown'}`,
+    `  waiting since ${new Date(record.requestedAt).toISOString()}`,
+  ].join('\n');
+}
+
+async function main() {
+  const { command, flags } = parseArgs(process.argv.slice(2));
+  const config = loadConfig();
+  const relayer = (flags.relayer ?? `http://localhost:${config.relayer.port}`).replace(/\/$/, '');
+
+  const call = async (path: string, body?: object): Promise<any> => {
+    const response = await fetch(`${relayer}/api/approvals${path}`, {
+      method: body ? 'POST' : 'GET',
+      headers: { authorization: `Bearer ${config.approvals.apiToken}`, 'content-type': 'application/json' },
+      body: body ? JSON.stringify(body) : undefined,
+    });
+    const result: any = await response.json();
+    if (!response.ok) {
+      throw new Error(result.error ?? `HTTP ${response.status}`);
+    }
+    return result;
+  };
+
+  if (command === 'list') {
+    const { pending } = (await call('')) as { pending: ApprovalRecord[] };
+    console.log(pending.length === 0 ? 'No releases waiting for approval' : pending.map(describe).join('\n'));
+  } else if (command === 'approve' || command === 'reject') {
+    if (!flags.id || !flags.operator || (command === 'reject' && !flags.reason)) {
+      const reason = command === 'reject' ? ' --reason <text>' : '';
+      throw new Error(`Usage: approvals ${command} --id <transfer-id> --operator <name>${reason}`);
+    }
+    const { pending } = (await call('')) as { pending: ApprovalRecord[] };
+    const record = pending.find((candidate) => candidate.transferId === flags.id);
+    if (!record) {
+      throw new Error(`No release ${flags.id} is waiting for approval`);
+    }
+
+    console.log(describe(record));
+    const prompt = readline.createInterface({ input: process.stdin, output: process.stdout });
+    const answer = await prompt.question(`${command === 'approve' ? 'Approve' : 'Reject'} this release? [y/N] `);
+    prompt.close();
+    if (answer.trim().toLowerCase() !== 'y') {
+      console.log('Nothing decided');
+      return;
+    }
+
+    await call(`/${encodeURIComponent(flags.id)}/${command}`, { operator: flags.operator, reason: flags.reason });
+    console.log(`Release ${flags.id} ${command === 'approve' ? 'approved' : 'rejected'}`);
+  } else {
+    throw new Error('Usage: approvals <list|approve|reject> ...');
+  }
+}
+
+if (require.main === module) {
+  main().catch((error) => {
+    console.error('Approval failed:', error.message);
+    process.exit(1);
+  });
+}
@@ -0,0 +1,105 @@
+/**
+ * Burn Evidence
+ *
+ * Re-derives release parameters from the Ethereum burn receipt instead of
+ * trusting the event that asked for them: the source transaction must have
+ * succeeded and contain an AssetBurned log from the wrapper contract at the
+ * claimed log index, with the same nonce, amount, recipient and, when
+ * given, burner and block. Used before signing a release proof and by the
+ * watchtower after a release executes.
+ */
+
+import { ethers } from 'ethers';
+import { FaultInjector } from './fault-injection';
+import { toAccountHashString } from './signature-utils';
+import { CSPR_DECIMALS, WCSPR_DECIMALS, convertDecimals } from './units';
+
+const BURN_EVENT_ABI = [
+  'event AssetBurned(address indexed user, uint256 amount, string destinationChain, string destinationAddress, uint256 indexed nonce)',
+];
+
+// Release parameters as they are signed and submitted to the vault
+export interface BurnClaim {
+  sourceTxHash: string;
+  logIndex: number;
+  nonce: string;
+  amount: string; // motes
+  recipient: string; // account hash
+  sourceSender?: string; // burner on Ethereum
+  sourceBlock?: number;
+}
+
+export class BurnEvidence {
+  private provider: ethers.JsonRpcProvider;
+  private wrapperContract: string;
+  private burnInterface = new ethers.Interface(BURN_EVENT_ABI);
+
+  constructor(rpcUrl: string, chainId: number, wrapperContract: string, faults: FaultInjector = new FaultInjector()) {
+    const network = new ethers.Network('sepolia', chainId);
+    this.provider = faults.wrap(new ethers.JsonRpcProvider(rpcUrl, network, { staticNetwork: network }), 'ethereum');
+    this.wrapperContract = wrapperContract;
+  }
+
+  /**
+   * List every field of the claim the burn receipt does not back, empty when it matches
+   * Throws when the receipt cannot be read, so RPC failures are not mistaken for missing burns
+   */
+  async mismatches(claim: BurnClaim): Promise<string[]> {
+    const receipt = await this.provider.getTransactionReceipt(claim.sourceTxHash);
+    if (!receipt) {
+      return ['source transaction has no receipt'];
+    }
+    if (receipt.status !== 1) {
+      return ['source transaction reverted'];
+    }
+
+    const entry = receipt.logs.find((candidate) => candidate.index === claim.logIndex);
+    if (!entry) {
+      return [`source transaction has no log ${claim.logIndex}`];
+    }
+    if (entry.address.toLowerCase() !== this.wrapperContract.toLowerCase()) {
+      return [`log ${claim.logIndex} was emitted by ${entry.address}, not the wrapper contract`];
+    }
+
+    let parsed: ethers.LogDescription | null = null;
+    try {
+      parsed = this.burnInterface.parseLog({ topics: [...entry.topics], data: entry.data });
+    } catch {
+      // Undecodable data is reported below like any other log
+    }
+    if (parsed?.name !== 'AssetBurned') {
+      return [`log ${claim.logIndex} is not an AssetBurned event`];
+    }
+
+    const problems: string[] = [];
+    const nonce = parsed.args.nonce.toString();
+    if (nonce !== claim.nonce) {
+      problems.push(`nonce: burn has ${nonce}, claim has ${claim.nonce}`);
+    }
+
+    const amount = convertDecimals(BigInt(parsed.args.amount), WCSPR_DECIMALS, CSPR_DECIMALS, 'floor').toString();
+    if (amount !== claim.amount) {
+      problems.push(`amount: burn has ${amount} motes, claim has ${claim.amount}`);
+    }
+
+    let recipient: string;
+    try {
+      recipient = toAccountHashString(parsed.args.destinationAddress);
+    } catch {
+      recipient = `unparseable "${parsed.args.destinationAddress}"`;
+    }
+    if (recipient !== claim.recipient) {
+      problems.push(`recipient: burn has ${recipient}, claim has ${claim.recipient}`);
+    }
+
+    const sender: string = parsed.args.user;
+    if (claim.sourceSender !== undefined && sender.toLowerCase() !== claim.sourceSender.toLowerCase()) {
+      problems.push(`source sender: burn has ${sender}, claim has ${claim.sourceSender}`);
+    }
+    if (claim.sourceBlock !== undefined && receipt.blockNumber !== claim.sourceBlock) {
+      problems.push(`source block: burn is in ${receipt.blockNumber}, claim has ${claim.sourceBlock}`);
+    }
+
+    return problems;
+  }
+}
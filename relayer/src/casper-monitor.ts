@@ -5,8 +5,16 @@
 
 import { EventEmitter } from 'events';
 import { Logger } from './logger';
-import { CasperSigner } from './signature-utils';
+import { CasperSigner, RELEASE_DIGEST_VERSION, toAccountHashString, vaultBridgeId } from './signature-utils';
+import { ProofCache, ValidatorSignature } from './proof-cache';
+import { SignatureGossip } from './signature-gossip';
+import { submitDeploy } from './deploy-tracker';
+import { BurnClaim, BurnEvidence } from './burn-evidence';
+import { ReleaseApprovals } from './release-approval';
+import { CSPR_DECIMALS, WCSPR_DECIMALS, convertDecimals } from './units';
 import { CasperClient, CLPublicKey } from 'casper-js-sdk';
+import { blake2b } from '@noble/hashes/blake2b';
+import { FaultInjector, FaultSettings, NO_FAULTS } from './fault-injection';
 
 const logger = Logger.getInstance();
 
@@ -17,9 +25,11 @@ export interface CasperMonitorConfig {
   privateKeyPath: string;
   pollInterval: number;
   confirmationBlocks: number;
+  submissionJitterMs: number;
+  faults?: FaultSettings; // chaos testing only
 }
 
-interface LockEvent {
+export interface LockEvent {
   sourceChain: string;
   sourceTxHash: string;
   amount: string;
@@ -29,6 +39,45 @@ interface LockEvent {
   sender: string;
 }
 
+// A release that executed on the vault, as submitted in the deploy args
+export interface ReleaseExecution {
+  deployHash: string;
+  blockHeight: number;
+  sourceChain: string;
+  sourceTxHash: string;
+  amount: string; // motes
+  recipient: string; // account hash
+  nonce: string;
+  logIndex: number;
+  signatures: ValidatorSignature[];
+  digestVersion: number;
+  sourceSender?: string; // digest v2+
+  memo?: string; // digest v3+
+  sourceBlock?: number; // digest v4+
+}
+
+// Vault default for max_signatures until the vault has been read
+const DEFAULT_MAX_SIGNATURES = 32;
+
+// Entry points that release CSPR from the vault against a proof
+const RELEASE_ENTRY_POINTS = ['release_cspr', 'release_cspr_idempotent'];
+
+// A detected lock waiting for enough blocks on top before it is relayed
+interface HeldLock {
+  event: LockEvent;
+  blockHash: string;
+  blockHeight: number;
+}
+
+export interface BlockSummary {
+  hash: string;
+  height: number;
+  deployHashes: string[];
+}
+
+// Most blocks scanned per poll when catching up
+const MAX_BLOCKS_PER_POLL = 50;
+
 export class CasperMonitor extends EventEmitter {
   private config: CasperMonitorConfig;
   private signer: CasperSigner;
@@ -37,12 +86,28 @@ export class CasperMonitor extends EventEmitter {
   private casperClient: CasperClient;
   private processedDeploys: Set<string> = new Set();
   private pendingDeploys: Set<string> = new Set(); // Track submitted deploys
-
-  constructor(config: CasperMonitorConfig, casperPrivateKeyHex: string) {
+  private proofCache: ProofCache = new ProofCache();
+  private heldLocks: Map<string, HeldLock> = new Map(); // deployHash -> lock awaiting finality
+  private gossip?: SignatureGossip;
+  private maxSignatures: number = DEFAULT_MAX_SIGNATURES;
+  private burnEvidence: BurnEvidence; // checked before every release signature
+  private approvals?: ReleaseApprovals; // operator approval for large releases
+  private faults: FaultInjector;
+
+  constructor(config: CasperMonitorConfig, casperPrivateKeyHex: string, burnEvidence: BurnEvidence) {
     super();
     this.config = config;
     this.signer = new CasperSigner(casperPrivateKeyHex);
-    this.casperClient = new CasperClient(config.rpcUrl);
+    this.burnEvidence = burnEvidence;
+    this.faults = new FaultInjector(config.faults ?? NO_FAULTS);
+    this.casperClient = this.connect(config.rpcUrl);
+  }
+
+  // Node client for the RPC URL, with faults injected into its JSON-RPC calls when enabled
+  private connect(rpcUrl: string): CasperClient {
+    const client = new CasperClient(rpcUrl);
+    client.nodeClient = this.faults.wrap(client.nodeClient, 'casper');
+    return client;
   }
 
   async start(): Promise<void> {
@@ -62,6 +127,52 @@ export class CasperMonitor extends EventEmitter {
     logger.info('Casper monitor stopped');
   }
 
+  /**
+   * Apply reloaded non-key settings; the signing key is fixed for the process lifetime
+   */
+  updateConfig(config: CasperMonitorConfig): void {
+    if (JSON.stringify(config.faults) !== JSON.stringify(this.config.faults)) {
+      this.faults = new FaultInjector(config.faults ?? NO_FAULTS);
+      this.casperClient = this.connect(config.rpcUrl);
+    } else if (config.rpcUrl !== this.config.rpcUrl) {
+      this.casperClient = this.connect(config.rpcUrl);
+    }
+    this.config = { ...config, privateKeyPath: this.config.privateKeyPath };
+
+    logger.info('Casper monitor config reloaded', {
+      rpc: this.config.rpcUrl,
+      contract: this.config.vaultContract,
+    });
+  }
+
+  /**
+   * Replace the burn receipt source, e.g. after the Ethereum RPC URL is reloaded
+   */
+  useBurnEvidence(burnEvidence: BurnEvidence): void {
+    this.burnEvidence = burnEvidence;
+  }
+
+  /**
+   * Hold releases above the approval threshold until an operator approves them
+   */
+  useApprovals(approvals: ReleaseApprovals): void {
+    this.approvals = approvals;
+  }
+
+  /**
+   * Share release signatures with peer relayers and use the ones they send
+   */
+  useGossip(gossip: SignatureGossip): void {
+    this.gossip = gossip;
+  }
+
+  /**
+   * Largest signature batch the vault accepts; longer proofs revert
+   */
+  setMaxSignatures(maxSignatures: number): void {
+    this.maxSignatures = maxSignatures;
+  }
+
   // Add a deploy to track
   trackDeploy(deployHash: string): void {
     this.pendingDeploys.add(deployHash);
@@ -135,13 +246,20 @@ export class CasperMonitor extends EventEmitter {
         const lockEvent = this.parseLockEventFromDeploy(deploy, deployHash);
 
         if (lockEvent) {
-          logger.info('🔒 Detected lock event!', { lockEvent });
-
-          // Emit event for minting
-          this.emit('AssetLocked', lockEvent);
-
-          // Mark as processed
-          this.processedDeploys.add(deployHash);
+          // Hold it like scanned locks until its block is final
+          const blockHash =
+            (deployResult as any).execution_info?.block_hash ?? deployResult.execution_results?.[0]?.block_hash;
+          const blockData = await this.casperClient.nodeClient.getBlockInfo(blockHash);
+          const block = CasperMonitor.summarizeBlock(blockData?.block);
+          if (!block) {
+            continue; // Retry next cycle
+          }
+
+          Logger.forTransfer(deployHash).info('🔒 Detected lock event, waiting for finality', {
+            lockEvent,
+            blockHeight: block.height,
+          });
+          this.heldLocks.set(deployHash, { event: lockEvent, blockHash: block.hash, blockHeight: block.height });
           this.pendingDeploys.delete(deployHash);
         } else {
           // Not a lock event, just remove from pending
@@ -164,24 +282,32 @@ export class CasperMonitor extends EventEmitter {
     }
   }
 
-  private parseLockEventFromDeploy(deploy: any, deployHash: string): any | null {
-    try {
-      // Check if this is a lock_cspr call
-      const session = deploy?.session;
-      if (!(session as any)?.StoredContractByHash) {
-        return null;
-      }
+  /**
+   * Get lock args if the deploy locks into the vault, either by calling
+   * lock_cspr directly or through the lock_cspr_session wasm
+   */
+  static lockArgs(session: any, vaultContract: string): any[] | null {
+    const vaultHash = vaultContract.replace('hash-', '');
 
-      const contractHash = (session as any).StoredContractByHash.hash;
-      const entryPoint = (session as any).StoredContractByHash.entry_point;
+    const stored = session?.StoredContractByHash;
+    if (stored) {
+      return stored.hash === vaultHash && stored.entry_point === 'lock_cspr' ? stored.args || null : null;
+    }
 
-      // Check if it's our vault contract and lock_cspr entry point
-      if (contractHash !== this.config.vaultContract.replace('hash-', '') || entryPoint !== 'lock_cspr') {
-        return null;
-      }
+    const moduleBytes = session?.ModuleBytes;
+    if (moduleBytes?.args) {
+      const target = moduleBytes.args.find(([name]: [string]) => name === 'vault_contract_hash');
+      // ByteArray args are parsed as hex
+      return target && target[1].parsed === vaultHash ? moduleBytes.args : null;
+    }
+
+    return null;
+  }
 
-      // Parse args
-      const args = (session as any).StoredContractByHash.args;
+  private parseLockEventFromDeploy(deploy: any, deployHash: string): any | null {
+    try {
+      // Check if this locks into our vault
+      const args = CasperMonitor.lockArgs(deploy?.session, this.config.vaultContract);
       if (!args) return null;
 
       let destinationChain = '';
@@ -226,11 +352,12 @@ export class CasperMonitor extends EventEmitter {
   private async pollEvents(): Promise<void> {
     while (this.isRunning) {
       try {
-        // Get latest block to check for executed deploys
         const latestBlock = await this.casperClient.nodeClient.getLatestBlockInfo();
+        const latest = CasperMonitor.summarizeBlock(latestBlock?.block);
 
-        if (latestBlock?.block?.hash) {
-          await this.checkBlockForLockEvents(latestBlock.block.hash);
+        if (latest) {
+          await this.scanToHeight(latest.height);
+          await this.relayFinalLocks(latest.height);
         }
 
         // Check pending deploys that were submitted directly
@@ -244,33 +371,116 @@ export class CasperMonitor extends EventEmitter {
     }
   }
 
-  private async checkBlockForLockEvents(blockHash: string): Promise<void> {
-    try {
-      // Get block details
-      const blockData = await this.casperClient.nodeClient.getBlockInfo(blockHash);
+  /**
+   * Hash, height and deploy hashes of a block in either the legacy or Casper 2.0 shape
+   */
+  static summarizeBlock(block: any): BlockSummary | null {
+    const inner = block?.Version2 ?? block?.Version1 ?? block;
+    if (!inner?.hash || inner.header?.height === undefined) {
+      return null;
+    }
 
-      if (!(blockData?.block as any)?.body?.deploy_hashes) {
-        return;
+    let deployHashes: string[] = inner.body?.deploy_hashes || [];
+    if (inner.body?.transactions) {
+      // 2.0 bodies group transaction hashes by lane: { "0": [{ Deploy: "..." }], ... }
+      deployHashes = Object.values(inner.body.transactions)
+        .flat()
+        .map((tx: any) => tx.Deploy ?? tx.Version1)
+        .filter((hash: any): hash is string => typeof hash === 'string');
+    }
+
+    return { hash: inner.hash, height: Number(inner.header.height), deployHashes };
+  }
+
+  /**
+   * Scan every block since the last one processed, so locks in skipped blocks are not missed
+   */
+  private async scanToHeight(latestHeight: number): Promise<void> {
+    if (latestHeight < this.lastProcessedBlock) {
+      // The chain was restarted from an earlier block; held locks are rechecked against
+      // the new canonical blocks before they are relayed
+      logger.warn('Casper block height went backwards, rescanning from the new tip', {
+        previous: this.lastProcessedBlock,
+        latest: latestHeight,
+      });
+      this.lastProcessedBlock = latestHeight - 1;
+    }
+
+    const from = this.lastProcessedBlock === 0 ? latestHeight : this.lastProcessedBlock + 1;
+    const to = Math.min(latestHeight, from + MAX_BLOCKS_PER_POLL - 1);
+
+    for (let height = from; height <= to; height++) {
+      const blockData = await this.casperClient.nodeClient.getBlockInfoByHeight(height);
+      const block = CasperMonitor.summarizeBlock(blockData?.block);
+      if (!block) {
+        return; // Retry this height next poll
       }
+      await this.checkBlockForLockEvents(block);
+      this.lastProcessedBlock = height;
+    }
+  }
 
+  private async checkBlockForLockEvents(block: BlockSummary): Promise<void> {
+    try {
       // Check each deploy in the block
-      for (const deployHash of (blockData.block as any).body.deploy_hashes) {
-        // Skip if already processed
-        if (this.processedDeploys.has(deployHash)) {
+      for (const deployHash of block.deployHashes) {
+        // Skip if already processed or waiting for finality
+        if (this.processedDeploys.has(deployHash) || this.heldLocks.has(deployHash)) {
           continue;
         }
 
-        await this.checkDeployForLockEvent(deployHash);
+        await this.checkDeployForLockEvent(deployHash, block);
       }
     } catch (error: any) {
       logger.error('Error checking block for lock events', {
-        blockHash,
+        blockHash: block.hash,
         error: error.message
       });
     }
   }
 
-  private async checkDeployForLockEvent(deployHash: string): Promise<void> {
+  /**
+   * Relay held locks with confirmationBlocks blocks on top, after confirming their block is
+   * still the canonical one at that height; locks whose block was replaced are dropped
+   */
+  private async relayFinalLocks(latestHeight: number): Promise<void> {
+    for (const [deployHash, held] of this.heldLocks) {
+      if (latestHeight - held.blockHeight < this.config.confirmationBlocks) {
+        continue;
+      }
+
+      try {
+        const blockData = await this.casperClient.nodeClient.getBlockInfoByHeight(held.blockHeight);
+        const canonical = CasperMonitor.summarizeBlock(blockData?.block);
+        if (!canonical) {
+          continue;
+        }
+
+        this.heldLocks.delete(deployHash);
+
+        if (canonical.hash !== held.blockHash || !canonical.deployHashes.includes(deployHash)) {
+          // Forget the deploy so it is picked up again if it lands in a later block
+          Logger.forTransfer(deployHash).warn('Lock block is no longer canonical, dropping lock', {
+            blockHeight: held.blockHeight,
+            heldBlockHash: held.blockHash,
+            canonicalBlockHash: canonical.hash,
+          });
+          this.processedDeploys.delete(deployHash);
+          continue;
+        }
+
+        Logger.forTransfer(deployHash).info('🔒 Lock event final on Casper', { lockEvent: held.event });
+
+        // Emit event for the main relayer to handle minting
+        this.emit('AssetLocked', held.event);
+        this.processedDeploys.add(deployHash);
+      } catch (error: any) {
+        logger.error('Error confirming held lock', { deployHash, error: error.message });
+      }
+    }
+  }
+
+  private async checkDeployForLockEvent(deployHash: string, block: BlockSummary): Promise<void> {
     try {
       const [deploy, deployResult] = await this.casperClient.getDeploy(deployHash);
 
@@ -286,32 +496,27 @@ export class CasperMonitor extends EventEmitter {
         return;
       }
 
-      // Check if this deploy called the lock_cspr entry point
-      const session = deploy?.session;
-      if (!(session as any)?.storedContractByHash) {
-        this.processedDeploys.add(deployHash);
-        return;
+      // Releases are reported for independent verification (see Watchtower)
+      const release = CasperMonitor.parseReleaseExecution(deploy, deployHash, block.height, this.config.vaultContract);
+      if (release) {
+        this.emit('ReleaseExecuted', release);
       }
 
-      const contractHash = (session as any).storedContractByHash.hash;
-      const entryPoint = (session as any).storedContractByHash.entry_point;
-
-      // Check if it's our vault contract and lock_cspr entry point
-      if (contractHash !== this.config.vaultContract || entryPoint !== 'lock_cspr') {
+      // Check if this deploy locked into our vault
+      if (!CasperMonitor.lockArgs(deploy?.session, this.config.vaultContract)) {
         this.processedDeploys.add(deployHash);
         return;
       }
 
       // Parse the lock event from the deploy args
-      const lockEvent = this.parseLockEvent(deploy, deployHash);
+      const lockEvent = CasperMonitor.parseLockEvent(deploy, deployHash, this.config.vaultContract);
 
       if (lockEvent) {
-        logger.info('🔒 Detected lock event on Casper', { lockEvent });
-
-        // Emit event for the main relayer to handle minting
-        this.emit('AssetLocked', lockEvent);
-
-        this.processedDeploys.add(deployHash);
+        Logger.forTransfer(deployHash).info('🔒 Detected lock event on Casper, waiting for finality', {
+          lockEvent,
+          blockHeight: block.height,
+        });
+        this.heldLocks.set(deployHash, { event: lockEvent, blockHash: block.hash, blockHeight: block.height });
       }
     } catch (error: any) {
       logger.error('Error checking deploy for lock event', {
@@ -321,9 +526,49 @@ export class CasperMonitor extends EventEmitter {
     }
   }
 
-  private parseLockEvent(deploy: any, deployHash: string): LockEvent | null {
+  /**
+   * Read a release proof from a deploy that called a release entry point on the vault
+   */
+  static parseReleaseExecution(
+    deploy: any,
+    deployHash: string,
+    blockHeight: number,
+    vaultContract: string
+  ): ReleaseExecution | null {
+    const vaultHash = vaultContract.replace('hash-', '');
+    const stored = deploy?.session?.StoredContractByHash;
+    if (!stored || stored.hash !== vaultHash || !RELEASE_ENTRY_POINTS.includes(stored.entry_point)) {
+      return null;
+    }
+
+    const args = new Map<string, any>((stored.args || []).map(([name, value]: [string, any]) => [name, value.parsed]));
+    // Key args are parsed as { Account: "account-hash-..." }
+    const recipient = args.get('recipient');
+    // Vec<u8> args are parsed as byte arrays, older nodes render them as hex
+    const bytes = (value: any): number[] => (Array.isArray(value) ? value : Array.from(Buffer.from(String(value), 'hex')));
+    return {
+      deployHash,
+      blockHeight,
+      sourceChain: args.get('source_chain') ?? '',
+      sourceTxHash: args.get('source_tx_hash') ?? '',
+      amount: String(args.get('amount') ?? ''),
+      recipient: String(recipient?.Account ?? recipient ?? '').toLowerCase(),
+      nonce: String(args.get('nonce') ?? ''),
+      logIndex: Number(args.get('log_index') ?? 0),
+      signatures: (args.get('signatures') || []).map(([publicKey, signature]: [any, any]) => ({
+        public_key: bytes(publicKey),
+        signature: bytes(signature),
+      })),
+      digestVersion: Number(args.get('digest_version') ?? 1),
+      sourceSender: args.get('source_sender') ?? undefined,
+      memo: args.get('memo') ?? undefined,
+      sourceBlock: args.get('source_block') == null ? undefined : Number(args.get('source_block')),
+    };
+  }
+
+  static parseLockEvent(deploy: any, deployHash: string, vaultContract: string): LockEvent | null {
     try {
-      const args = deploy.session?.StoredContractByHash?.args;
+      const args = CasperMonitor.lockArgs(deploy.session, vaultContract);
       if (!args) return null;
 
       let destinationChain = '';
@@ -364,8 +609,109 @@ export class CasperMonitor extends EventEmitter {
     }
   }
 
+  /**
+   * Check the vault's processed_proofs dictionary for a release proof
+   */
+  async isProofProcessed(sourceChain: string, nonce: string | number, logIndex: number): Promise<boolean> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    try {
+      await this.casperClient.nodeClient.getDictionaryItemByName(
+        stateRootHash,
+        this.config.vaultContract,
+        'processed_proofs',
+        ProofCache.releaseTransferId(sourceChain, nonce, logIndex)
+      );
+      return true;
+    } catch (error: any) {
+      // Missing dictionary items are reported as query errors
+      return false;
+    }
+  }
+
+  /**
+   * Resolve a compact lock or release event, (nonce, record hash), to the serialized record the
+   * vault stored alongside it; id is the lock nonce or the release's "{sourceChain}_{nonce}_{logIndex}" key
+   * Returns null if no record is stored, and throws if the stored bytes do not match the hash
+   */
+  async resolveCompactEvent(kind: 'lock' | 'release', id: string, recordHash: string): Promise<Uint8Array | null> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    let item: any;
+    try {
+      item = await this.casperClient.nodeClient.getDictionaryItemByName(
+        stateRootHash,
+        this.config.vaultContract,
+        kind === 'lock' ? 'lock_records' : 'release_records',
+        id
+      );
+    } catch (error: any) {
+      // Missing dictionary items are reported as query errors
+      return null;
+    }
+
+    // Stored as Bytes, which the SDK parses as a list of u8
+    const record = Uint8Array.from(item.CLValue!.value().map((byte: any) => Number(byte.value().toString())));
+    if (Buffer.from(blake2b(record, { dkLen: 32 })).toString('hex') !== recordHash.toLowerCase()) {
+      throw new Error(`Stored ${kind} record ${id} does not match its event hash`);
+    }
+    return record;
+  }
+
+  /**
+   * Finality parameters the vault prescribes for a chain, or null if none are set
+   */
+  async getChainFinality(chain: string): Promise<{ minConfirmations: number; challengeWindowMs: number } | null> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    const readU64 = async (dictionary: string): Promise<number> => {
+      const item = await this.casperClient.nodeClient.getDictionaryItemByName(
+        stateRootHash,
+        this.config.vaultContract,
+        dictionary,
+        chain
+      );
+      return Number(item.CLValue!.value().toString());
+    };
+
+    try {
+      return {
+        minConfirmations: await readU64('chain_min_confirmations'),
+        challengeWindowMs: await readU64('chain_challenge_window'),
+      };
+    } catch (error: any) {
+      // Missing dictionary items are reported as query errors
+      return null;
+    }
+  }
+
   async submitReleaseProof(burnEvent: any): Promise<void> {
-    logger.info('Submitting release proof to Casper', { burnEvent });
+    const logIndex = burnEvent.logIndex ?? 0;
+    const transferId = ProofCache.releaseTransferId('ethereum', burnEvent.nonce, logIndex);
+    const log = Logger.forTransfer(transferId);
+
+    if (this.proofCache.isCompleted(transferId)) {
+      log.info('Skipping already submitted release proof');
+      return;
+    }
+
+    if (await this.isProofProcessed('ethereum', burnEvent.nonce, logIndex)) {
+      log.info('Release proof already processed on Casper, skipping');
+      this.proofCache.markCompleted(transferId);
+      this.emit('ReleaseProcessed', { transferId });
+      return;
+    }
+
+    // Back off a random delay so redundant relayers don't all pay for the same proof
+    if (this.config.submissionJitterMs > 0) {
+      await this.sleep(Math.floor(Math.random() * this.config.submissionJitterMs));
+
+      if (await this.isProofProcessed('ethereum', burnEvent.nonce, logIndex)) {
+        log.info('Peer relayer submitted release proof first, backing off');
+        this.proofCache.markCompleted(transferId);
+        this.emit('ReleaseProcessed', { transferId });
+        return;
+      }
+    }
+
+    log.info('Submitting release proof to Casper', { burnEvent });
 
     try {
       // CRITICAL: Convert from Ethereum decimals (18) to Casper decimals (9)
@@ -373,23 +719,77 @@ export class CasperMonitor extends EventEmitter {
       // 1 CSPR = 1,000,000,000 motes (9 decimals)
       // Therefore: amountInMotes = amountInWei / 10^9
       const amountInWei = BigInt(burnEvent.amount);
-      const amountInMotes = amountInWei / BigInt(1_000_000_000); // Divide by 10^9
+      const amountInMotes = convertDecimals(amountInWei, WCSPR_DECIMALS, CSPR_DECIMALS, 'floor');
+      const droppedWei = amountInWei - convertDecimals(amountInMotes, CSPR_DECIMALS, WCSPR_DECIMALS);
+      if (droppedWei !== BigInt(0)) {
+        log.warn('Burn amount has sub-mote precision that cannot be released', {
+          amountInWei: amountInWei.toString(),
+          droppedWei: droppedWei.toString(),
+        });
+      }
+
+      // The contract signs over the formatted account hash of the recipient
+      const recipient = toAccountHashString(burnEvent.destinationAddress);
+
+      // Never sign what the burn receipt does not back, whatever the event said
+      const claim: BurnClaim = {
+        sourceTxHash: burnEvent.txHash || '0x0',
+        logIndex,
+        nonce: burnEvent.nonce.toString(),
+        amount: amountInMotes.toString(),
+        recipient,
+        sourceSender: burnEvent.user,
+        sourceBlock: burnEvent.blockNumber,
+      };
+      let mismatches: string[];
+      try {
+        mismatches = await this.burnEvidence.mismatches(claim);
+      } catch (error: any) {
+        log.error('Refusing to sign: could not read the burn receipt', { claim, error: error.message });
+        this.emit('ReleaseRefused', { transferId, reasons: [`burn receipt unreadable: ${error.message}`] });
+        return;
+      }
+      if (mismatches.length > 0) {
+        log.error('🚨 Refusing to sign: release request does not match its burn receipt', { claim, mismatches });
+        this.emit('ReleaseRefused', { transferId, reasons: mismatches });
+        return;
+      }
 
-      // STEP 1: Create message (MUST match contract's get_message_hash)
+      // Large releases also need an operator; they are replayed here once decided
+      const approval = this.approvals?.check(transferId, claim, burnEvent) ?? 'approved';
+      if (approval === 'pending') {
+        log.warn('⏸️ Holding release for operator approval', { claim });
+        this.emit('ReleaseHeld', { transferId, claim });
+        return;
+      }
+      if (approval === 'rejected') {
+        const { operator, reason } = this.approvals!.get(transferId)!;
+        log.error('Refusing to sign: release rejected by operator', { claim, operator, reason });
+        this.emit('ReleaseRefused', { transferId, reasons: [`rejected by ${operator}: ${reason}`] });
+        return;
+      }
+
+      // STEP 1: Create message (MUST match contract's release_message)
       // IMPORTANT: Use the converted amount in motes for the signature
       const message = this.signer.createMessage(
         'ethereum',                         // sourceChain
         burnEvent.txHash || '0x0',          // sourceTxHash
         amountInMotes.toString(),           // amount in MOTES (9 decimals)
-        burnEvent.destinationAddress,       // recipient (Casper address)
-        burnEvent.nonce.toString()          // nonce (as string)
+        recipient,                          // recipient account hash
+        burnEvent.nonce.toString(),         // nonce (as string)
+        logIndex,                           // log index within the burn tx
+        RELEASE_DIGEST_VERSION,             // message format version
+        burnEvent.user,                     // burner on Ethereum, carried to AssetReleased
+        undefined,                          // memo
+        burnEvent.blockNumber,              // burn block, checked against attested checkpoints
+        vaultBridgeId(this.config.vaultContract) // vault the proof is for
       );
 
       // STEP 2: Sign the message with Ed25519
-      const signature = await this.signer.signMessage(message);
+      const signature = await this.faults.sign(() => this.signer.signMessage(message));
       const publicKey = this.signer.getPublicKey();
 
-      logger.info('Generated Ed25519 signature for release proof', {
+      log.info('Generated Ed25519 signature for release proof', {
         messageHex: Buffer.from(message).toString('hex'),
         signatureHex: Buffer.from(signature).toString('hex'),
         publicKeyHex: this.signer.getPublicKeyHex(),
@@ -397,42 +797,74 @@ export class CasperMonitor extends EventEmitter {
         amountInMotes: amountInMotes.toString(),
       });
 
-      // STEP 3: Create proof with signature
+      // STEP 3: Create proof with signatures merged from every signer seen for this transfer,
+      // including those peer relayers gossiped for this exact message
+      const mergedSignatures = this.proofCache.addSignatures(transferId, [
+        {
+          public_key: Array.from(publicKey),     // 32-byte Ed25519 public key
+          signature: Array.from(signature),      // 64-byte Ed25519 signature
+        },
+        ...(this.gossip?.signaturesFor(transferId, message) ?? []),
+      ]);
+      // Our own signature is merged first, so it survives the cut
+      const validatorSignatures = mergedSignatures.slice(0, this.maxSignatures);
+      if (validatorSignatures.length < mergedSignatures.length) {
+        log.warn('Dropping signatures beyond the vault limit', {
+          collected: mergedSignatures.length,
+          maxSignatures: this.maxSignatures,
+        });
+      }
+      this.emit('ReleaseAttested', { transferId, signatures: validatorSignatures.length });
+      if (this.gossip) {
+        await this.gossip.broadcast({
+          transfer_id: transferId,
+          message_hex: Buffer.from(message).toString('hex'),
+          signatures: validatorSignatures,
+        });
+      }
+
       const proof = {
         source_chain: 'ethereum',
         source_tx_hash: burnEvent.txHash || '0x0',
         amount: amountInMotes.toString(),
-        recipient: burnEvent.destinationAddress,
+        recipient,
         nonce: burnEvent.nonce,
-        validator_signatures: [
-          {
-            public_key: Array.from(publicKey),     // 32-byte Ed25519 public key
-            signature: Array.from(signature),      // 64-byte Ed25519 signature
-          },
-        ],
+        log_index: logIndex,
+        validator_signatures: validatorSignatures,
+        digest_version: RELEASE_DIGEST_VERSION,
+        source_sender: burnEvent.user as string,
+        source_block: burnEvent.blockNumber as number,
       };
 
       // STEP 4: Submit to Casper vault contract
-      logger.info('📝 Proof ready for Casper submission', { proof });
+      log.info('📝 Proof ready for Casper submission', { proof });
+
+      const { DeployUtil, CLValueBuilder, CLAccountHash, RuntimeArgs } = require('casper-js-sdk');
 
-      const { DeployUtil, CLValueBuilder, CLPublicKey, RuntimeArgs } = require('casper-js-sdk');
+      const u8List = (bytes: number[]) => CLValueBuilder.list(bytes.map((b) => CLValueBuilder.u8(b)));
 
       // Prepare runtime args for release_cspr
       const runtimeArgs = RuntimeArgs.fromMap({
         source_chain: CLValueBuilder.string(proof.source_chain),
         source_tx_hash: CLValueBuilder.string(proof.source_tx_hash),
         amount: CLValueBuilder.u512(proof.amount),
-        recipient: CLValueBuilder.string(proof.recipient),
+        recipient: CLValueBuilder.key(
+          new CLAccountHash(Uint8Array.from(Buffer.from(recipient.replace('account-hash-', ''), 'hex')))
+        ),
         nonce: CLValueBuilder.u64(proof.nonce),
-        validator_public_keys: CLValueBuilder.list([
-          CLValueBuilder.byteArray(Buffer.from(proof.validator_signatures[0].public_key))
-        ]),
-        validator_signatures: CLValueBuilder.list([
-          CLValueBuilder.byteArray(Buffer.from(proof.validator_signatures[0].signature))
-        ]),
+        log_index: CLValueBuilder.u32(proof.log_index),
+        // Vec<(Vec<u8>, Vec<u8>)> of (Ed25519 public key, signature)
+        signatures: CLValueBuilder.list(
+          proof.validator_signatures.map((sig) =>
+            CLValueBuilder.tuple2([u8List(sig.public_key), u8List(sig.signature)])
+          )
+        ),
+        digest_version: CLValueBuilder.u8(proof.digest_version),
+        source_sender: CLValueBuilder.string(proof.source_sender),
+        source_block: CLValueBuilder.u64(proof.source_block),
       });
 
-      // Create deploy to call release_cspr entry point
+      // Create deploy to call release_cspr_idempotent entry point
       const deployParams = new DeployUtil.DeployParams(
         this.signer.getPublicKeyCL(),
         this.config.networkName,
@@ -448,9 +880,11 @@ export class CasperMonitor extends EventEmitter {
         })
       );
 
+      // Other relayers may land the same proof first; the idempotent entry point
+      // turns that into a no-op instead of a reverted deploy
       const session = DeployUtil.ExecutableDeployItem.newStoredContractByHash(
         Uint8Array.from(Buffer.from(this.config.vaultContract.replace('hash-', ''), 'hex')),
-        'release_cspr',
+        'release_cspr_idempotent',
         runtimeArgs
       );
 
@@ -459,15 +893,18 @@ export class CasperMonitor extends EventEmitter {
       // Sign the deploy
       const signedDeploy = DeployUtil.signDeploy(deploy, this.signer.getKeyPair());
 
-      // Submit to Casper network
-      const deployHash = await this.casperClient.putDeploy(signedDeploy);
+      // Submit to Casper network, retrying transient node errors
+      const deployHash = await submitDeploy(this.casperClient, signedDeploy);
+      this.proofCache.markCompleted(transferId);
+      this.gossip?.forget(transferId);
+      this.emit('ReleaseSubmitted', { transferId, deployHash });
 
-      logger.info('✅ Release transaction submitted to Casper', {
+      log.info('✅ Release transaction submitted to Casper', {
         deployHash,
         explorerUrl: `https://testnet.cspr.live/deploy/${deployHash}`
       });
     } catch (error) {
-      logger.error('❌ Error submitting release proof to Casper', { error });
+      log.error('❌ Error submitting release proof to Casper', { error });
       throw error;
     }
   }
@@ -476,6 +913,7 @@ export class CasperMonitor extends EventEmitter {
     return {
       isRunning: this.isRunning,
       lastProcessedBlock: this.lastProcessedBlock,
+      locksAwaitingFinality: this.heldLocks.size,
       contract: this.config.vaultContract,
     };
   }
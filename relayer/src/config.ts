@@ -0,0 +1,269 @@
+/**
+ * Relayer Configuration
+ *
+ * Typed schema shared by the monitors and signers, loaded from an optional
+ * JSON file (RELAYER_CONFIG_PATH) with environment variables as fallback.
+ * Non-key settings can be hot-reloaded; key material requires a restart.
+ */
+
+import * as fs from 'fs';
+import { FaultSettings } from './fault-injection';
+import { csprToMotes } from './units';
+
+export interface CasperChainConfig {
+  rpcUrl: string;
+  networkName: string;
+  vaultContract: string;
+  privateKeyPath: string;
+  privateKeyHex: string;
+  confirmationBlocks: number;
+}
+
+export interface EthereumChainConfig {
+  rpcUrl: string;
+  chainId: number;
+  wrapperContract: string;
+  privateKey: string;
+  confirmationBlocks: number;
+}
+
+export interface RelayerSettings {
+  port: number;
+  pollIntervalMs: number;
+  submissionJitterMs: number;
+  configCheckIntervalMs: number; // re-check config against the vault this often, 0 for startup only
+  peers: string[]; // base URLs of peer relayers to gossip partial proofs with
+  archiveDir: string; // where signed history archives are written, empty to disable
+  archiveIntervalMs: number; // length of one archive epoch
+  transferStorePath: string; // JSON file that keeps transfer history across restarts, empty for memory only
+}
+
+export interface WatchtowerSettings {
+  enabled: boolean;
+  guardianKeyHex: string; // Ed25519 key of an account registered with add_guardian
+  action: 'halt' | 'dispute'; // response to a release without a matching burn
+  dryRun: boolean; // log the response instead of submitting it
+  maxActionsPerHour: number;
+}
+
+export interface AlertSettings {
+  webhookUrl: string; // generic JSON webhook, empty to disable
+  slackWebhookUrl: string; // Slack incoming webhook, empty to disable
+  pagerDutyRoutingKey: string; // PagerDuty Events API v2 integration key, empty to disable
+  checkIntervalMs: number;
+  stuckAfterMinutes: number; // alert on transfers that have not moved for this long
+  heartbeatMaxAgeMs: number; // alert when the attested Ethereum head is older, 0 to disable
+}
+
+export interface ApprovalSettings {
+  thresholdCspr: string; // releases above this many CSPR wait for an operator, empty to disable
+  apiToken: string; // bearer token the approval API requires
+  storePath: string; // JSON file keeping the approval queue across restarts, empty for memory only
+}
+
+export interface RelayerConfig {
+  casper: CasperChainConfig;
+  ethereum: EthereumChainConfig;
+  relayer: RelayerSettings;
+  watchtower: WatchtowerSettings;
+  alerts: AlertSettings;
+  approvals: ApprovalSettings;
+  faults: FaultSettings; // chaos testing only, see fault-injection.ts
+}
+
+// Settings that hold or locate signing keys; changing them needs a restart
+const KEY_SETTINGS: Array<[keyof RelayerConfig, string]> = [
+  ['casper', 'privateKeyPath'],
+  ['casper', 'privateKeyHex'],
+  ['ethereum', 'privateKey'],
+  ['watchtower', 'guardianKeyHex'],
+];
+
+export class ConfigError extends Error {
+  constructor(public readonly problems: string[]) {
+    super(`Invalid relayer configuration:\n  - ${problems.join('\n  - ')}`);
+    this.name = 'ConfigError';
+  }
+}
+
+function int(value: string | undefined, fallback: number): number {
+  return value === undefined || value === '' ? fallback : parseInt(value);
+}
+
+function float(value: string | undefined, fallback: number): number {
+  return value === undefined || value === '' ? fallback : parseFloat(value);
+}
+
+/**
+ * Build the configuration from environment variables
+ */
+function fromEnv(): RelayerConfig {
+  const env = process.env;
+  return {
+    casper: {
+      rpcUrl: env.CASPER_RPC_URL || '',
+      networkName: env.CASPER_NETWORK_NAME || '',
+      vaultContract: env.CASPER_VAULT_CONTRACT || '',
+      privateKeyPath: env.CASPER_PRIVATE_KEY_PATH || '',
+      privateKeyHex: env.CASPER_PRIVATE_KEY_HEX || '',
+      confirmationBlocks: int(env.CONFIRMATION_BLOCKS_CASPER, 3),
+    },
+    ethereum: {
+      rpcUrl: env.ETHEREUM_RPC_URL || '',
+      chainId: int(env.ETHEREUM_CHAIN_ID, 11155111),
+      wrapperContract: env.ETHEREUM_WRAPPER_CONTRACT || '',
+      privateKey: env.ETHEREUM_PRIVATE_KEY || '',
+      confirmationBlocks: int(env.CONFIRMATION_BLOCKS_ETHEREUM, 12),
+    },
+    relayer: {
+      port: int(env.RELAYER_PORT, 3001),
+      pollIntervalMs: int(env.POLL_INTERVAL_MS, 5000),
+      submissionJitterMs: int(env.SUBMISSION_JITTER_MS, 0),
+      configCheckIntervalMs: int(env.CONFIG_CHECK_INTERVAL_MS, 300000),
+      peers: (env.RELAYER_PEERS || '').split(',').map((peer) => peer.trim()).filter(Boolean),
+      archiveDir: env.ARCHIVE_DIR || '',
+      archiveIntervalMs: int(env.ARCHIVE_INTERVAL_MS, 86400000),
+      transferStorePath: env.TRANSFER_STORE_PATH || '',
+    },
+    watchtower: {
+      enabled: env.WATCHTOWER_ENABLED === 'true',
+      guardianKeyHex: env.WATCHTOWER_GUARDIAN_KEY_HEX || '',
+      action: env.WATCHTOWER_ACTION === 'dispute' ? 'dispute' : 'halt',
+      dryRun: env.WATCHTOWER_DRY_RUN !== 'false',
+      maxActionsPerHour: int(env.WATCHTOWER_MAX_ACTIONS_PER_HOUR, 1),
+    },
+    alerts: {
+      webhookUrl: env.ALERT_WEBHOOK_URL || '',
+      slackWebhookUrl: env.ALERT_SLACK_WEBHOOK_URL || '',
+      pagerDutyRoutingKey: env.ALERT_PAGERDUTY_ROUTING_KEY || '',
+      checkIntervalMs: int(env.ALERT_CHECK_INTERVAL_MS, 60000),
+      stuckAfterMinutes: int(env.ALERT_STUCK_AFTER_MINUTES, 30),
+      heartbeatMaxAgeMs: int(env.ALERT_HEARTBEAT_MAX_AGE_MS, 0),
+    },
+    approvals: {
+      thresholdCspr: env.RELEASE_APPROVAL_THRESHOLD_CSPR || '',
+      apiToken: env.RELEASE_APPROVAL_TOKEN || '',
+      storePath: env.RELEASE_APPROVAL_STORE_PATH || '',
+    },
+    faults: {
+      enabled: env.FAULT_INJECTION_ENABLED === 'true',
+      rpcDropRate: float(env.FAULT_RPC_DROP_RATE, 0),
+      rpcDelayMs: int(env.FAULT_RPC_DELAY_MS, 0),
+      signerFailureRate: float(env.FAULT_SIGNER_FAILURE_RATE, 0),
+    },
+  };
+}
+
+/**
+ * Load configuration: file values override environment values section by section
+ */
+export function loadConfig(): RelayerConfig {
+  const config = fromEnv();
+  const path = process.env.RELAYER_CONFIG_PATH;
+
+  if (path) {
+    const file = JSON.parse(fs.readFileSync(path, 'utf8')) as Partial<RelayerConfig>;
+    config.casper = { ...config.casper, ...file.casper };
+    config.ethereum = { ...config.ethereum, ...file.ethereum };
+    config.relayer = { ...config.relayer, ...file.relayer };
+    config.watchtower = { ...config.watchtower, ...file.watchtower };
+    config.alerts = { ...config.alerts, ...file.alerts };
+    config.approvals = { ...config.approvals, ...file.approvals };
+    config.faults = { ...config.faults, ...file.faults };
+  }
+
+  validateConfig(config);
+  return config;
+}
+
+/**
+ * Validate configuration, collecting every problem instead of failing on the first
+ */
+export function validateConfig(config: RelayerConfig): void {
+  const problems: string[] = [];
+
+  const requireString = (section: string, field: string, value: string) => {
+    if (!value) problems.push(`${section}.${field} is required`);
+  };
+  const requireNonNegative = (section: string, field: string, value: number) => {
+    if (!Number.isInteger(value) || value < 0) problems.push(`${section}.${field} must be a non-negative integer`);
+  };
+
+  requireString('casper', 'rpcUrl', config.casper.rpcUrl);
+  requireString('casper', 'networkName', config.casper.networkName);
+  requireString('casper', 'privateKeyHex', config.casper.privateKeyHex);
+  if (!config.casper.vaultContract.startsWith('hash-')) {
+    problems.push('casper.vaultContract must be a "hash-" prefixed contract hash');
+  }
+  requireNonNegative('casper', 'confirmationBlocks', config.casper.confirmationBlocks);
+
+  requireString('ethereum', 'rpcUrl', config.ethereum.rpcUrl);
+  requireString('ethereum', 'privateKey', config.ethereum.privateKey);
+  if (!/^0x[0-9a-fA-F]{40}$/.test(config.ethereum.wrapperContract)) {
+    problems.push('ethereum.wrapperContract must be a 0x-prefixed 20-byte address');
+  }
+  requireNonNegative('ethereum', 'chainId', config.ethereum.chainId);
+  requireNonNegative('ethereum', 'confirmationBlocks', config.ethereum.confirmationBlocks);
+
+  requireNonNegative('relayer', 'port', config.relayer.port);
+  requireNonNegative('relayer', 'submissionJitterMs', config.relayer.submissionJitterMs);
+  requireNonNegative('relayer', 'configCheckIntervalMs', config.relayer.configCheckIntervalMs);
+  for (const peer of config.relayer.peers) {
+    if (!/^https?:\/\//.test(peer)) problems.push(`relayer.peers entry "${peer}" must be an http(s) URL`);
+  }
+  if (!Number.isInteger(config.relayer.pollIntervalMs) || config.relayer.pollIntervalMs <= 0) {
+    problems.push('relayer.pollIntervalMs must be a positive integer');
+  }
+  const { archiveDir, archiveIntervalMs } = config.relayer;
+  if (archiveDir && (!Number.isInteger(archiveIntervalMs) || archiveIntervalMs <= 0)) {
+    problems.push('relayer.archiveIntervalMs must be a positive integer');
+  }
+
+  if (config.watchtower.enabled) {
+    if (!config.watchtower.dryRun) requireString('watchtower', 'guardianKeyHex', config.watchtower.guardianKeyHex);
+    if (!['halt', 'dispute'].includes(config.watchtower.action)) {
+      problems.push('watchtower.action must be "halt" or "dispute"');
+    }
+    requireNonNegative('watchtower', 'maxActionsPerHour', config.watchtower.maxActionsPerHour);
+  }
+
+  const { webhookUrl, slackWebhookUrl, checkIntervalMs, stuckAfterMinutes, heartbeatMaxAgeMs } = config.alerts;
+  for (const [field, url] of [['webhookUrl', webhookUrl], ['slackWebhookUrl', slackWebhookUrl]]) {
+    if (url && !/^https?:\/\//.test(url)) problems.push(`alerts.${field} must be an http(s) URL`);
+  }
+  if (!Number.isInteger(checkIntervalMs) || checkIntervalMs <= 0) {
+    problems.push('alerts.checkIntervalMs must be a positive integer');
+  }
+  requireNonNegative('alerts', 'stuckAfterMinutes', stuckAfterMinutes);
+  requireNonNegative('alerts', 'heartbeatMaxAgeMs', heartbeatMaxAgeMs);
+
+  if (config.approvals.thresholdCspr) {
+    try {
+      csprToMotes(config.approvals.thresholdCspr);
+    } catch {
+      problems.push('approvals.thresholdCspr must be a CSPR amount with at most 9 decimals');
+    }
+    requireString('approvals', 'apiToken', config.approvals.apiToken);
+  }
+
+  if (config.faults.enabled) {
+    for (const field of ['rpcDropRate', 'signerFailureRate'] as const) {
+      const rate = config.faults[field];
+      if (!(rate >= 0 && rate <= 1)) problems.push(`faults.${field} must be between 0 and 1`);
+    }
+    requireNonNegative('faults', 'rpcDelayMs', config.faults.rpcDelayMs);
+  }
+
+  if (problems.length > 0) {
+    throw new ConfigError(problems);
+  }
+}
+
+/**
+ * List key settings that differ between two configurations
+ */
+export function changedKeySettings(current: RelayerConfig, next: RelayerConfig): string[] {
+  return KEY_SETTINGS
+    .filter(([section, field]) => (current[section] as any)[field] !== (next[section] as any)[field])
+    .map(([section, field]) => `${section}.${field}`);
+}
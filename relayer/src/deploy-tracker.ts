@@ -0,0 +1,175 @@
+/**
+ * Deploy Lifecycle
+ *
+ * Submits deploys with retry on transient node errors, polls them until
+ * executed, and decodes vault reverts into VaultError:
+ *
+ *   const deployHash = await submitDeploy(client, signedDeploy);
+ *   const outcome = await waitForDeploy(client, deployHash);
+ *   if (!outcome.success) throw outcome.error;
+ */
+
+import { CasperClient, DeployUtil } from 'casper-js-sdk';
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export interface DeployPolicy {
+  maxAttempts: number; // submit/poll attempts per transient failure run
+  backoffMs: number; // first retry delay, doubled on each retry
+  maxBackoffMs: number;
+  pollIntervalMs: number;
+  timeoutMs: number; // give up waiting for execution after this long
+}
+
+export const DEFAULT_DEPLOY_POLICY: DeployPolicy = {
+  maxAttempts: 5,
+  backoffMs: 1000,
+  maxBackoffMs: 30000,
+  pollIntervalMs: 5000,
+  timeoutMs: 1800000, // deploy ttl used across the relayer
+};
+
+// User error codes reverted by the vault contract
+const VAULT_USER_ERRORS: Record<number, string> = {
+  1: 'ChurnLimitExceeded',
+  2: 'TimelockPending',
+  3: 'TvlCapReached',
+  4: 'Sunset',
+  5: 'SourceChainStale',
+  6: 'ReleaseDisputed',
+  7: 'Restricted',
+  8: 'SignerDiversity',
+  9: 'ProofTooOld',
+};
+
+/**
+ * A failed deploy execution, with the vault's error name when it is a
+ * known user error ("User error: N") or a standard ApiError
+ */
+export class VaultError extends Error {
+  constructor(
+    readonly deployHash: string,
+    readonly errorMessage: string,
+    readonly code?: string,
+    readonly userCode?: number
+  ) {
+    super(`Deploy ${deployHash} failed: ${code ?? errorMessage}`);
+    this.name = 'VaultError';
+  }
+
+  static fromExecution(deployHash: string, errorMessage: string): VaultError {
+    const user = /User error: (\d+)/.exec(errorMessage);
+    if (user) {
+      const userCode = Number(user[1]);
+      return new VaultError(deployHash, errorMessage, VAULT_USER_ERRORS[userCode] ?? `User(${userCode})`, userCode);
+    }
+    // Standard ApiErrors come through by name, e.g. "ApiError::PermissionDenied [3]"
+    const api = /ApiError::(\w+)/.exec(errorMessage);
+    return new VaultError(deployHash, errorMessage, api?.[1]);
+  }
+}
+
+export interface DeployOutcome {
+  deployHash: string;
+  success: boolean;
+  cost?: string;
+  error?: VaultError;
+}
+
+// Connection resets, timeouts, rate limits and 5xx responses are worth retrying
+export function isTransientError(error: any): boolean {
+  const message = String(error?.message ?? error);
+  const status = error?.response?.status ?? error?.statusCode;
+  return (
+    (typeof status === 'number' && (status === 429 || status >= 500)) ||
+    /ECONNRESET|ECONNREFUSED|ETIMEDOUT|EAI_AGAIN|socket hang up|timeout|network/i.test(message)
+  );
+}
+
+async function withRetry<T>(label: string, policy: DeployPolicy, fn: () => Promise<T>): Promise<T> {
+  let delay = policy.backoffMs;
+  for (let attempt = 1; ; attempt++) {
+    try {
+      return await fn();
+    } catch (error: any) {
+      if (attempt >= policy.maxAttempts || !isTransientError(error)) {
+        throw error;
+      }
+      logger.warn(`${label} failed, retrying`, { attempt, delayMs: delay, error: error.message });
+      await sleep(delay);
+      delay = Math.min(delay * 2, policy.maxBackoffMs);
+    }
+  }
+}
+
+/**
+ * Submit a signed deploy, retrying transient node errors
+ * Resubmitting the same deploy is safe: the node dedupes it by hash
+ */
+export async function submitDeploy(
+  client: CasperClient,
+  deploy: DeployUtil.Deploy,
+  policy: DeployPolicy = DEFAULT_DEPLOY_POLICY
+): Promise<string> {
+  return withRetry('Deploy submission', policy, () => client.putDeploy(deploy));
+}
+
+/**
+ * Poll a deploy until it executes or policy.timeoutMs elapses
+ * Handles both Casper 2.0 (execution_info) and legacy (execution_results) responses
+ */
+export async function waitForDeploy(
+  client: CasperClient,
+  deployHash: string,
+  policy: DeployPolicy = DEFAULT_DEPLOY_POLICY
+): Promise<DeployOutcome> {
+  const deadline = Date.now() + policy.timeoutMs;
+
+  while (Date.now() < deadline) {
+    let deployResult: any;
+    try {
+      [, deployResult] = await withRetry('Deploy status query', policy, () => client.getDeploy(deployHash));
+    } catch (error: any) {
+      // Freshly submitted deploys can briefly be unknown to the node we query
+      if (!/not found|No such deploy/i.test(error.message)) {
+        throw error;
+      }
+    }
+
+    const outcome = deployResult && parseExecution(deployHash, deployResult);
+    if (outcome) {
+      return outcome;
+    }
+    await sleep(policy.pollIntervalMs);
+  }
+
+  throw new Error(`Deploy ${deployHash} not executed within ${policy.timeoutMs}ms`);
+}
+
+function parseExecution(deployHash: string, deployResult: any): DeployOutcome | undefined {
+  const v2 = deployResult.execution_info?.execution_result?.Version2;
+  if (v2) {
+    return v2.error_message
+      ? { deployHash, success: false, cost: v2.cost, error: VaultError.fromExecution(deployHash, v2.error_message) }
+      : { deployHash, success: true, cost: v2.cost };
+  }
+
+  const legacy = deployResult.execution_info?.execution_result?.Version1 ?? deployResult.execution_results?.[0]?.result;
+  if (legacy?.Success) {
+    return { deployHash, success: true, cost: legacy.Success.cost };
+  }
+  if (legacy?.Failure) {
+    return {
+      deployHash,
+      success: false,
+      cost: legacy.Failure.cost,
+      error: VaultError.fromExecution(deployHash, legacy.Failure.error_message),
+    };
+  }
+  return undefined;
+}
+
+function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
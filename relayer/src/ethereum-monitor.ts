@@ -7,6 +7,8 @@ import { EventEmitter } from 'events';
 import { ethers } from 'ethers';
 import { Logger } from './logger';
 import { EthereumSigner } from './signature-utils';
+import { ProofCache } from './proof-cache';
+import { CSPR_DECIMALS, WCSPR_DECIMALS, convertDecimals } from './units';
 
 const logger = Logger.getInstance();
 
@@ -17,6 +19,7 @@ export interface EthereumMonitorConfig {
   privateKey: string;
   pollInterval: number;
   confirmationBlocks: number;
+  submissionJitterMs: number;
 }
 
 export class EthereumMonitor extends EventEmitter {
@@ -27,12 +30,14 @@ export class EthereumMonitor extends EventEmitter {
   private contract: ethers.Contract | null = null;
   private isRunning: boolean = false;
   private lastProcessedBlock: number = 0;
+  private proofCache: ProofCache = new ProofCache();
 
   // Simplified ABI for the events we care about
   private readonly WRAPPER_ABI = [
     'event AssetBurned(address indexed user, uint256 amount, string destinationChain, string destinationAddress, uint256 indexed nonce)',
     'event AssetMinted(address indexed user, uint256 amount, string sourceChain, string sourceTxHash, uint256 indexed nonce)',
     'function mint((string sourceChain, string sourceTxHash, uint256 amount, address recipient, uint256 nonce, bytes[] validatorSignatures) proof)',
+    'function isNonceProcessed(uint256 _nonce) view returns (bool)',
   ];
 
   constructor(config: EthereumMonitorConfig) {
@@ -83,6 +88,30 @@ export class EthereumMonitor extends EventEmitter {
     logger.info('Ethereum monitor stopped');
   }
 
+  /**
+   * Apply reloaded non-key settings; the signing key is fixed for the process lifetime
+   */
+  updateConfig(config: EthereumMonitorConfig): void {
+    const privateKey = this.config.privateKey;
+    const reconnect = config.rpcUrl !== this.config.rpcUrl || config.chainId !== this.config.chainId;
+    this.config = { ...config, privateKey };
+
+    if (reconnect) {
+      const network = new ethers.Network('sepolia', this.config.chainId);
+      this.provider = new ethers.JsonRpcProvider(this.config.rpcUrl, network, { staticNetwork: network });
+      this.wallet = new ethers.Wallet(privateKey, this.provider);
+    }
+    if (this.contract) {
+      this.contract = new ethers.Contract(this.config.wrapperContract, this.WRAPPER_ABI, this.wallet);
+    }
+
+    logger.info('Ethereum monitor config reloaded', {
+      rpc: this.config.rpcUrl,
+      chainId: this.config.chainId,
+      contract: this.config.wrapperContract,
+    });
+  }
+
   private async pollEvents(): Promise<void> {
     while (this.isRunning) {
       try {
@@ -125,7 +154,8 @@ export class EthereumMonitor extends EventEmitter {
               amount: event.args.amount.toString(),
               nonce: event.args.nonce.toString(),
               block: event.blockNumber,
-              tx: event.transactionHash
+              tx: event.transactionHash,
+              logIndex: event.index
             });
 
             this.emit('AssetBurned', {
@@ -136,6 +166,7 @@ export class EthereumMonitor extends EventEmitter {
               nonce: event.args.nonce.toString(),
               blockNumber: event.blockNumber,
               txHash: event.transactionHash,
+              logIndex: event.index,
             });
           }
         }
@@ -154,7 +185,35 @@ export class EthereumMonitor extends EventEmitter {
       return;
     }
 
-    logger.info('Submitting mint proof to Ethereum', { lockEvent });
+    // Lock events are identified by their Casper deploy hash
+    const transferId = lockEvent.sourceTxHash;
+    const log = Logger.forTransfer(transferId);
+
+    if (this.proofCache.isCompleted(transferId)) {
+      log.info('Skipping already submitted mint proof');
+      return;
+    }
+
+    if (await this.contract.isNonceProcessed(lockEvent.nonce)) {
+      log.info('Mint proof already processed on Ethereum, skipping');
+      this.proofCache.markCompleted(transferId);
+      this.emit('MintProcessed', { transferId });
+      return;
+    }
+
+    // Back off a random delay so redundant relayers don't all pay for the same proof
+    if (this.config.submissionJitterMs > 0) {
+      await this.sleep(Math.floor(Math.random() * this.config.submissionJitterMs));
+
+      if (await this.contract.isNonceProcessed(lockEvent.nonce)) {
+        log.info('Peer relayer submitted mint proof first, backing off');
+        this.proofCache.markCompleted(transferId);
+        this.emit('MintProcessed', { transferId });
+        return;
+      }
+    }
+
+    log.info('Submitting mint proof to Ethereum', { lockEvent });
 
     try {
       // CRITICAL: Convert from Casper decimals (9) to Ethereum decimals (18)
@@ -162,7 +221,7 @@ export class EthereumMonitor extends EventEmitter {
       // 1 wCSPR = 1,000,000,000,000,000,000 wei (18 decimals)
       // Therefore: amountInWei = amountInMotes * 10^9
       const amountInMotes = BigInt(lockEvent.amount);
-      const amountInWei = amountInMotes * BigInt(1_000_000_000); // Multiply by 10^9
+      const amountInWei = convertDecimals(amountInMotes, CSPR_DECIMALS, WCSPR_DECIMALS);
 
       // STEP 1: Create message hash (MUST match contract's _getMessageHash)
       // IMPORTANT: Use the converted amount in wei for the signature
@@ -177,7 +236,7 @@ export class EthereumMonitor extends EventEmitter {
       // STEP 2: Sign the message hash
       const signature = await this.signer.signMessage(messageHash);
 
-      logger.info('Generated signature for mint proof', {
+      log.info('Generated signature for mint proof', {
         messageHash,
         signature,
         amountInMotes: lockEvent.amount,
@@ -199,16 +258,19 @@ export class EthereumMonitor extends EventEmitter {
 
       // STEP 4: Submit to Ethereum contract
       const tx = await this.contract.mint(proof);
-      logger.info('Mint transaction submitted', { txHash: tx.hash });
+      this.proofCache.markCompleted(transferId);
+      log.info('Mint transaction submitted', { txHash: tx.hash });
+      this.emit('MintSubmitted', { transferId, txHash: tx.hash });
 
       const receipt = await tx.wait();
-      logger.info('✅ Mint transaction confirmed', {
+      log.info('✅ Mint transaction confirmed', {
         txHash: receipt.hash,
         blockNumber: receipt.blockNumber,
         validator: this.signer.getAddress()
       });
+      this.emit('MintConfirmed', { transferId, txHash: receipt.hash });
     } catch (error) {
-      logger.error('❌ Error submitting mint proof', { error });
+      log.error('❌ Error submitting mint proof', { error });
       throw error;
     }
   }
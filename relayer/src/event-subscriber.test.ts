@@ -0,0 +1,171 @@
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { BridgeEvent, CursorStore, FileCursorStore, Subscription } from './event-subscriber';
+
+const VAULT = 'hash-' + 'aa'.repeat(32);
+const deployHash = (n: number) => n.toString(16).padStart(64, '0');
+
+const lockDeploy = (amount: string) => ({
+  header: { account: '01' + 'bb'.repeat(32) },
+  session: {
+    StoredContractByHash: {
+      hash: 'aa'.repeat(32),
+      entry_point: 'lock_cspr',
+      args: [
+        ['destination_chain', { parsed: 'ethereum' }],
+        ['destination_address', { parsed: '0x' + '22'.repeat(20) }],
+        ['amount', { parsed: amount }],
+      ],
+    },
+  },
+});
+
+const releaseDeploy = {
+  session: {
+    StoredContractByHash: {
+      hash: 'aa'.repeat(32),
+      entry_point: 'release_cspr',
+      args: [
+        ['source_chain', { parsed: 'ethereum' }],
+        ['amount', { parsed: '500' }],
+        ['nonce', { parsed: '7' }],
+      ],
+    },
+  },
+};
+
+const transfer = { session: { Transfer: { args: [] } } };
+
+// Chain of blocks 0..9; block 3 holds a lock, a failed lock and a transfer, block 5 a release, block 8 a lock
+function fakeChain() {
+  const deploys: Record<string, [any, any]> = {
+    [deployHash(1)]: [lockDeploy('100'), { execution_results: [{ result: { Success: {} } }] }],
+    [deployHash(2)]: [lockDeploy('200'), { execution_results: [{ result: { Failure: {} } }] }],
+    [deployHash(3)]: [transfer, { execution_results: [{ result: { Success: {} } }] }],
+    [deployHash(4)]: [releaseDeploy, { execution_info: { execution_result: { Version2: { error_message: null } } } }],
+    [deployHash(5)]: [lockDeploy('300'), { execution_results: [{ result: { Success: {} } }] }],
+  };
+  const blockDeploys: Record<number, string[]> = {
+    3: [deployHash(1), deployHash(2), deployHash(3)],
+    5: [deployHash(4)],
+    8: [deployHash(5)],
+  };
+  const block = (height: number) => ({
+    block: { hash: `block-${height}`, header: { height }, body: { deploy_hashes: blockDeploys[height] ?? [] } },
+  });
+  let tip = 9;
+  const client = {
+    nodeClient: {
+      getLatestBlockInfo: jest.fn(async () => block(tip)),
+      getBlockInfoByHeight: jest.fn(async (height: number) => block(height)),
+    },
+    getDeploy: jest.fn(async (hash: string) => deploys[hash]),
+  };
+  return { client: client as any, setTip: (height: number) => (tip = height) };
+}
+
+class MemoryCursors implements CursorStore {
+  cursors: Record<string, string> = {};
+  load(vaultContract: string) {
+    return this.cursors[vaultContract];
+  }
+  save(vaultContract: string, cursor: string) {
+    this.cursors[vaultContract] = cursor;
+  }
+}
+
+describe('Subscription', () => {
+  it('backfills typed events after the given event id', async () => {
+    const { client } = fakeChain();
+    const events: BridgeEvent[] = [];
+    const subscription = new Subscription(client, VAULT, '2', (event) => {
+      events.push(event);
+    });
+    await subscription.poll();
+
+    expect(events.map((event) => [event.type, event.id])).toEqual([
+      ['AssetLocked', `3:${deployHash(1)}`],
+      ['ReleaseExecuted', `5:${deployHash(4)}`],
+      ['AssetLocked', `8:${deployHash(5)}`],
+    ]);
+    expect(events[0]).toMatchObject({ lock: { amount: '100', destinationChain: 'ethereum' } });
+    expect(events[1]).toMatchObject({ blockHeight: 5, release: { amount: '500', nonce: '7' } });
+    expect(subscription.cursor).toBe('9');
+  });
+
+  it('only reads blocks with enough confirmations on top', async () => {
+    const { client, setTip } = fakeChain();
+    const events: BridgeEvent[] = [];
+    const subscription = new Subscription(client, VAULT, '2', (event) => {
+      events.push(event);
+    }, { confirmationBlocks: 3 });
+    await subscription.poll();
+    expect(events.map((event) => event.blockHeight)).toEqual([3, 5]);
+
+    setTip(11);
+    await subscription.poll();
+    expect(events.map((event) => event.blockHeight)).toEqual([3, 5, 8]);
+  });
+
+  it('starts at the final tip without a cursor', async () => {
+    const { client, setTip } = fakeChain();
+    const events: BridgeEvent[] = [];
+    const subscription = new Subscription(client, VAULT, null, (event) => {
+      events.push(event);
+    });
+    setTip(7);
+    await subscription.poll();
+    expect(subscription.cursor).toBe('7');
+
+    setTip(9);
+    await subscription.poll();
+    expect(events.map((event) => event.id)).toEqual([`8:${deployHash(5)}`]);
+  });
+
+  it('resumes after the last delivered event when the handler throws', async () => {
+    const { client } = fakeChain();
+    const cursors = new MemoryCursors();
+    const events: string[] = [];
+    let failOnce = true;
+    const handler = (event: BridgeEvent) => {
+      if (event.type === 'ReleaseExecuted' && failOnce) {
+        failOnce = false;
+        throw new Error('indexer unavailable');
+      }
+      events.push(event.id);
+    };
+
+    const first = new Subscription(client, VAULT, '2', handler, { cursors });
+    await expect(first.poll()).rejects.toThrow('indexer unavailable');
+    expect(cursors.load(VAULT)).toBe('4');
+
+    // A new subscription picks up the stored cursor and redelivers the failed event
+    const second = new Subscription(client, VAULT, null, handler, { cursors });
+    await second.poll();
+    expect(events).toEqual([`3:${deployHash(1)}`, `5:${deployHash(4)}`, `8:${deployHash(5)}`]);
+  });
+
+  it('skips the deploys of a partly delivered block', async () => {
+    const { client } = fakeChain();
+    const events: BridgeEvent[] = [];
+    const subscription = new Subscription(client, VAULT, `3:${deployHash(1)}`, (event) => {
+      events.push(event);
+    });
+    await subscription.poll();
+    expect(events.map((event) => event.blockHeight)).toEqual([5, 8]);
+  });
+
+  it('rejects malformed event ids', () => {
+    const { client } = fakeChain();
+    expect(() => new Subscription(client, VAULT, 'latest', () => {})).toThrow('Invalid event cursor');
+  });
+});
+
+describe('FileCursorStore', () => {
+  it('keeps cursors across restarts', () => {
+    const file = path.join(fs.mkdtempSync(path.join(os.tmpdir(), 'cursors-')), 'cursors.json');
+    new FileCursorStore(file).save(VAULT, `3:${deployHash(1)}`);
+    expect(new FileCursorStore(file).load(VAULT)).toBe(`3:${deployHash(1)}`);
+  });
+});
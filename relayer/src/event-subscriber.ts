@@ -0,0 +1,234 @@
+/**
+ * Vault Event Subscriber
+ *
+ * Typed lock and release events of one vault, in block order, for wallets,
+ * indexers and other services that follow the bridge without running a relayer:
+ *
+ *   const subscriber = new EventSubscriber(new CasperClient(rpcUrl), { cursors: new FileCursorStore('cursors.json') });
+ *   subscriber.subscribe('hash-...', null, async (event) => { ... });
+ *
+ * Event ids are "{blockHeight}:{deployHash}". A subscription starts after
+ * from_event_id, or after the cursor stored for the vault when that is null,
+ * or at the chain tip when neither exists; blocks in between are backfilled
+ * before live events follow. Only blocks with confirmationBlocks on top are
+ * read. The cursor advances after each delivered event and each finished
+ * block; a failed RPC call or a throwing handler leaves it where it was, so
+ * the next poll resumes from there and no event is skipped. Delivery is at
+ * least once: an event whose handler threw is delivered again.
+ */
+
+import * as fs from 'fs';
+import { CasperClient } from 'casper-js-sdk';
+import { CasperMonitor, LockEvent, ReleaseExecution } from './casper-monitor';
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export type BridgeEvent =
+  | { type: 'AssetLocked'; id: string; blockHeight: number; lock: LockEvent }
+  | { type: 'ReleaseExecuted'; id: string; blockHeight: number; release: ReleaseExecution };
+
+export type BridgeEventHandler = (event: BridgeEvent) => void | Promise<void>;
+
+/**
+ * Where subscriptions keep their position, keyed by vault contract hash
+ */
+export interface CursorStore {
+  load(vaultContract: string): string | undefined;
+  save(vaultContract: string, cursor: string): void;
+}
+
+export interface SubscriberOptions {
+  pollIntervalMs?: number;
+  confirmationBlocks?: number;
+  cursors?: CursorStore; // none keeps positions in memory only
+}
+
+// Most blocks read per poll when backfilling
+const MAX_BLOCKS_PER_POLL = 50;
+
+// Position after an event ("{height}:{deployHash}") or after a whole block ("{height}")
+interface Cursor {
+  height: number;
+  deployHash?: string;
+}
+
+function parseCursor(cursor: string): Cursor {
+  const match = /^(\d+)(?::([0-9a-fA-F]{64}))?$/.exec(cursor);
+  if (!match) {
+    throw new Error(`Invalid event cursor "${cursor}", expected "{blockHeight}" or "{blockHeight}:{deployHash}"`);
+  }
+  return { height: Number(match[1]), deployHash: match[2]?.toLowerCase() };
+}
+
+function formatCursor(cursor: Cursor): string {
+  return cursor.deployHash ? `${cursor.height}:${cursor.deployHash}` : `${cursor.height}`;
+}
+
+// Whether a deploy executed successfully, in either the legacy or the Casper 2.0 result shape
+function executedSuccessfully(deployResult: any): boolean {
+  const version2 = deployResult?.execution_info?.execution_result?.Version2;
+  if (version2) {
+    return version2.error_message === null;
+  }
+  return Boolean(deployResult?.execution_results?.[0]?.result?.Success);
+}
+
+/**
+ * Cursors kept in a JSON file, rewritten through a temporary file on every save
+ */
+export class FileCursorStore implements CursorStore {
+  private path: string;
+  private cursors: Record<string, string> = {};
+
+  constructor(path: string) {
+    this.path = path;
+    if (fs.existsSync(path)) {
+      this.cursors = JSON.parse(fs.readFileSync(path, 'utf8'));
+    }
+  }
+
+  load(vaultContract: string): string | undefined {
+    return this.cursors[vaultContract];
+  }
+
+  save(vaultContract: string, cursor: string): void {
+    this.cursors[vaultContract] = cursor;
+    const temporary = `${this.path}.tmp`;
+    fs.writeFileSync(temporary, JSON.stringify(this.cursors));
+    fs.renameSync(temporary, this.path);
+  }
+}
+
+export class Subscription {
+  private client: CasperClient;
+  private vaultContract: string;
+  private handler: BridgeEventHandler;
+  private confirmationBlocks: number;
+  private cursors?: CursorStore;
+  private position: Cursor | null;
+  private active: boolean = true;
+
+  constructor(
+    client: CasperClient,
+    vaultContract: string,
+    fromEventId: string | null,
+    handler: BridgeEventHandler,
+    options: SubscriberOptions = {}
+  ) {
+    this.client = client;
+    this.vaultContract = vaultContract;
+    this.handler = handler;
+    this.confirmationBlocks = options.confirmationBlocks ?? 0;
+    this.cursors = options.cursors;
+    const from = fromEventId ?? this.cursors?.load(vaultContract) ?? null;
+    this.position = from === null ? null : parseCursor(from);
+  }
+
+  /**
+   * Id of the last delivered event or finished block, null before the first poll of a fresh subscription
+   */
+  get cursor(): string | null {
+    return this.position && formatCursor(this.position);
+  }
+
+  get isActive(): boolean {
+    return this.active;
+  }
+
+  stop(): void {
+    this.active = false;
+  }
+
+  /**
+   * Deliver the events of every final block after the cursor, up to MAX_BLOCKS_PER_POLL blocks
+   * Throws on RPC and handler errors, with the cursor left after the last event delivered
+   */
+  async poll(): Promise<void> {
+    const latest = CasperMonitor.summarizeBlock((await this.client.nodeClient.getLatestBlockInfo())?.block);
+    if (!latest) {
+      return;
+    }
+    const finalHeight = latest.height - this.confirmationBlocks;
+    if (this.position === null) {
+      // Nothing to backfill from: follow live events from the current final block
+      this.advance({ height: finalHeight });
+      return;
+    }
+
+    const from = this.position.deployHash ? this.position.height : this.position.height + 1;
+    const to = Math.min(finalHeight, from + MAX_BLOCKS_PER_POLL - 1);
+    for (let height = from; height <= to && this.active; height++) {
+      const block = CasperMonitor.summarizeBlock((await this.client.nodeClient.getBlockInfoByHeight(height))?.block);
+      if (!block) {
+        return; // Retry this height next poll
+      }
+
+      // Resume after the last delivered deploy of a partly delivered block
+      const resumeAfter = height === this.position.height ? this.position.deployHash : undefined;
+      const start = resumeAfter ? block.deployHashes.indexOf(resumeAfter) + 1 : 0;
+      for (const deployHash of block.deployHashes.slice(start)) {
+        const event = await this.readEvent(deployHash, block.height);
+        if (event) {
+          await this.handler(event);
+        }
+        this.advance({ height, deployHash });
+      }
+      this.advance({ height });
+    }
+  }
+
+  // Typed event for a deploy that locked into or released from the vault, null for anything else
+  private async readEvent(deployHash: string, blockHeight: number): Promise<BridgeEvent | null> {
+    const [deploy, deployResult] = await this.client.getDeploy(deployHash);
+    if (!executedSuccessfully(deployResult)) {
+      return null;
+    }
+
+    const id = `${blockHeight}:${deployHash}`;
+    const release = CasperMonitor.parseReleaseExecution(deploy, deployHash, blockHeight, this.vaultContract);
+    if (release) {
+      return { type: 'ReleaseExecuted', id, blockHeight, release };
+    }
+    const lock = CasperMonitor.parseLockEvent(deploy, deployHash, this.vaultContract);
+    return lock ? { type: 'AssetLocked', id, blockHeight, lock } : null;
+  }
+
+  private advance(position: Cursor): void {
+    this.position = position;
+    this.cursors?.save(this.vaultContract, formatCursor(position));
+  }
+}
+
+export class EventSubscriber {
+  private client: CasperClient;
+  private options: SubscriberOptions;
+
+  constructor(client: CasperClient, options: SubscriberOptions = {}) {
+    this.client = client;
+    this.options = options;
+  }
+
+  /**
+   * Deliver the vault's events after fromEventId (or the stored cursor) to the handler until stopped
+   */
+  subscribe(vaultContract: string, fromEventId: string | null, handler: BridgeEventHandler): Subscription {
+    const subscription = new Subscription(this.client, vaultContract, fromEventId, handler, this.options);
+    void this.run(subscription);
+    return subscription;
+  }
+
+  private async run(subscription: Subscription): Promise<void> {
+    while (subscription.isActive) {
+      try {
+        await subscription.poll();
+      } catch (error: any) {
+        logger.warn('Event subscription interrupted, resuming from its cursor', {
+          cursor: subscription.cursor,
+          error: error.message,
+        });
+      }
+      await new Promise((resolve) => setTimeout(resolve, this.options.pollIntervalMs ?? 5000));
+    }
+  }
+}
@@ -0,0 +1,124 @@
+import { FaultInjectedError, FaultInjector, FaultSettings, NO_FAULTS } from './fault-injection';
+import { ProofCache, ValidatorSignature } from './proof-cache';
+
+// Random source replaying the given draws in a loop, so every fault is deterministic
+const draws = (...values: number[]) => {
+  let next = 0;
+  return () => values[next++ % values.length];
+};
+
+const faulty = (settings: Partial<FaultSettings>, random: () => number) =>
+  new FaultInjector({ ...NO_FAULTS, enabled: true, ...settings }, random);
+
+// Repeat a call until it stops failing with an injected fault
+async function retry<T>(call: () => Promise<T>, attempts: number = 20): Promise<T> {
+  for (let attempt = 1; ; attempt++) {
+    try {
+      return await call();
+    } catch (error) {
+      if (!(error instanceof FaultInjectedError) || attempt === attempts) {
+        throw error;
+      }
+    }
+  }
+}
+
+describe('FaultInjector', () => {
+  it('passes everything through when disabled', async () => {
+    const faults = new FaultInjector({ ...NO_FAULTS, rpcDropRate: 1, rpcDelayMs: 60000, signerFailureRate: 1 });
+    const client = { getBlock: async () => 7 };
+
+    await expect(faults.rpc('getBlock', async () => 7)).resolves.toBe(7);
+    await expect(faults.sign(async () => 'signed')).resolves.toBe('signed');
+    expect(faults.wrap(client, 'casper')).toBe(client);
+  });
+
+  it('drops RPC responses at the configured rate, after the call ran', async () => {
+    const faults = faulty({ rpcDropRate: 0.5 }, draws(0.1, 0.9));
+    const call = jest.fn(async () => 'head');
+
+    await expect(faults.rpc('getBlock', call)).rejects.toThrow(FaultInjectedError);
+    await expect(faults.rpc('getBlock', call)).resolves.toBe('head');
+    expect(call).toHaveBeenCalledTimes(2);
+  });
+
+  it('delays RPC calls', async () => {
+    jest.useFakeTimers();
+    try {
+      const faults = faulty({ rpcDelayMs: 1000 }, draws(0.9));
+      let resolved = false;
+      const pending = faults.rpc('getBlock', async () => 'head').then(() => (resolved = true));
+
+      await jest.advanceTimersByTimeAsync(999);
+      expect(resolved).toBe(false);
+      await jest.advanceTimersByTimeAsync(1);
+      await pending;
+      expect(resolved).toBe(true);
+    } finally {
+      jest.useRealTimers();
+    }
+  });
+
+  it('fails signatures at the configured rate without calling the signer', async () => {
+    const faults = faulty({ signerFailureRate: 0.5 }, draws(0.4, 0.6));
+    const sign = jest.fn(async () => 'signed');
+
+    await expect(faults.sign(sign)).rejects.toThrow('Injected fault: signer failed');
+    await expect(faults.sign(sign)).resolves.toBe('signed');
+    expect(sign).toHaveBeenCalledTimes(1);
+  });
+
+  it('wraps client methods so they still run on the client', async () => {
+    class Client {
+      #height = 41;
+      async nextHeight(): Promise<number> {
+        return ++this.#height;
+      }
+      readonly url = 'http://node';
+    }
+    const faults = faulty({ rpcDropRate: 0.5 }, draws(0.9, 0.1));
+    const client = faults.wrap(new Client(), 'casper');
+
+    expect(client.url).toBe('http://node');
+    await expect(client.nextHeight()).resolves.toBe(42);
+    await expect(client.nextHeight()).rejects.toThrow('Injected fault: RPC casper.nextHeight failed');
+  });
+});
+
+describe('relaying under injected faults', () => {
+  it('reaches the same state once retries get through dropped responses', async () => {
+    const faults = faulty({ rpcDropRate: 0.5 }, draws(0.1, 0.2, 0.3, 0.9));
+    const processed = new Set<string>();
+    // Recording a proof twice is harmless, like release_cspr_idempotent
+    const markProcessed = async (transferId: string) => {
+      processed.add(transferId);
+      return processed.size;
+    };
+
+    await expect(retry(() => faults.rpc('markProcessed', () => markProcessed('ethereum_7_0')))).resolves.toBe(1);
+    expect(processed).toEqual(new Set(['ethereum_7_0']));
+  });
+
+  it('collects a full signature set when some signers fail', async () => {
+    const faults = faulty({ signerFailureRate: 0.5 }, draws(0.1, 0.9, 0.2, 0.3, 0.8));
+    const cache = new ProofCache();
+    const signers: ValidatorSignature[] = [1, 2, 3].map((key) => ({
+      public_key: Array(32).fill(key),
+      signature: Array(64).fill(key),
+    }));
+
+    let merged: ValidatorSignature[] = [];
+    for (let round = 0; round < 4 && merged.length < signers.length; round++) {
+      for (const signer of signers) {
+        try {
+          merged = cache.addSignatures('ethereum_7_0', [await faults.sign(async () => signer)]);
+        } catch (error) {
+          expect(error).toBeInstanceOf(FaultInjectedError);
+        }
+      }
+    }
+
+    expect(merged).toHaveLength(3);
+    expect(merged.map((sig) => sig.public_key[0]).sort()).toEqual([1, 2, 3]);
+  });
+});
@@ -0,0 +1,98 @@
+/**
+ * Fault Injection
+ *
+ * Chaos hooks for testnets and scenario tests: with faults enabled, RPC calls
+ * to the Casper node and the Ethereum receipt provider are dropped or delayed,
+ * and release signatures fail, at configured rates, so partial failures can
+ * be rehearsed before they happen for real:
+ *
+ *   FAULT_INJECTION_ENABLED=true FAULT_RPC_DROP_RATE=0.2 FAULT_RPC_DELAY_MS=3000 FAULT_SIGNER_FAILURE_RATE=0.1
+ *
+ * Disabled by default; when disabled every call passes straight through.
+ * Dropped RPC responses are lost after the request ran, so retries must be
+ * safe to repeat.
+ * Injected failures throw FaultInjectedError, which callers handle like any
+ * other RPC or signer error.
+ */
+
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export interface FaultSettings {
+  enabled: boolean;
+  rpcDropRate: number; // share of RPC calls that fail, 0-1
+  rpcDelayMs: number; // added to every RPC call that goes through
+  signerFailureRate: number; // share of release signatures that fail, 0-1
+}
+
+export const NO_FAULTS: FaultSettings = { enabled: false, rpcDropRate: 0, rpcDelayMs: 0, signerFailureRate: 0 };
+
+export class FaultInjectedError extends Error {
+  constructor(public readonly target: string) {
+    super(`Injected fault: ${target} failed`);
+    this.name = 'FaultInjectedError';
+  }
+}
+
+export class FaultInjector {
+  private settings: FaultSettings;
+  private random: () => number;
+
+  constructor(settings: FaultSettings = NO_FAULTS, random: () => number = Math.random) {
+    this.settings = settings;
+    this.random = random;
+    if (settings.enabled) {
+      logger.warn('⚠️ Fault injection is enabled', { ...settings });
+    }
+  }
+
+  /**
+   * Run an RPC call, possibly delayed first; a dropped response fails after the call was made,
+   * like a timeout on a request the node did receive
+   */
+  async rpc<T>(name: string, call: () => Promise<T>): Promise<T> {
+    if (!this.settings.enabled) {
+      return call();
+    }
+    if (this.settings.rpcDelayMs > 0) {
+      await new Promise((resolve) => setTimeout(resolve, this.settings.rpcDelayMs));
+    }
+    const result = await call();
+    if (this.random() < this.settings.rpcDropRate) {
+      logger.debug('Dropping RPC response', { name });
+      throw new FaultInjectedError(`RPC ${name}`);
+    }
+    return result;
+  }
+
+  /**
+   * Run a signing operation, possibly failing instead
+   */
+  async sign<T>(call: () => Promise<T>): Promise<T> {
+    if (this.settings.enabled && this.random() < this.settings.signerFailureRate) {
+      logger.debug('Failing signature');
+      throw new FaultInjectedError('signer');
+    }
+    return call();
+  }
+
+  /**
+   * Route every method of an RPC client through rpc(); methods run on the client itself,
+   * so clients with private fields keep working. Returns the client unchanged when disabled
+   */
+  wrap<T extends object>(client: T, label: string): T {
+    if (!this.settings.enabled) {
+      return client;
+    }
+    return new Proxy(client, {
+      get: (target, property) => {
+        const value = Reflect.get(target, property, target);
+        if (typeof value !== 'function') {
+          return value;
+        }
+        return (...args: unknown[]) => this.rpc(`${label}.${String(property)}`, async () => value.apply(target, args));
+      },
+    });
+  }
+}
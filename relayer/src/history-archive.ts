@@ -0,0 +1,396 @@
+/**
+ * History Archive
+ *
+ * Records every lock and executed release (with its full proof) the monitor
+ * sees, and at the end of each epoch writes them together with the vault's
+ * validator set to a signed archive file, {archiveDir}/epoch-{n}.cbha.
+ * `npm run verify-archive` audits those files offline.
+ *
+ * Format version 1. Integers are little-endian; strings (UTF-8) and byte
+ * arrays carry a u32 length prefix and options a u8 tag (0 none, 1 some),
+ * as in Casper's bytesrepr. Amounts are decimal motes strings.
+ *
+ *   magic "CBHA" | version u8 | epoch u64 | from_ms u64 | to_ms u64 | vault string
+ *   required_signatures u32 | validator count u32 | validator account hashes (string)
+ *   lock count u32 | per lock:
+ *     deploy_hash string | amount string | destination_chain string |
+ *     destination_address string | nonce u64 | sender string
+ *   release count u32 | per release:
+ *     deploy_hash string | block_height u64 | source_chain string | source_tx_hash string |
+ *     amount string | recipient string | nonce u64 | log_index u32 | digest_version u8 |
+ *     source_sender option<string> | memo option<string> | source_block option<u64> |
+ *     signature count u32 | per signature: public_key bytes | signature bytes
+ *   signer public key (32 bytes) | Ed25519 signature (64 bytes) over every preceding byte
+ *
+ * The validator set is read when the epoch closes. Only what this relayer
+ * observed while running is included, so run it from the vault's first block
+ * for a complete history.
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { CasperClient } from 'casper-js-sdk';
+import { Logger } from './logger';
+import { CasperSigner } from './signature-utils';
+import { LockEvent, ReleaseExecution } from './casper-monitor';
+import { ValidatorSignature } from './proof-cache';
+
+const logger = Logger.getInstance();
+
+const MAGIC = 'CBHA';
+export const ARCHIVE_FORMAT_VERSION = 1;
+const SIGNATURE_TRAILER_LENGTH = 32 + 64;
+
+export interface ArchivedLock {
+  deploy_hash: string;
+  amount: string;
+  destination_chain: string;
+  destination_address: string;
+  nonce: string;
+  sender: string;
+}
+
+export interface ArchivedRelease {
+  deploy_hash: string;
+  block_height: number;
+  source_chain: string;
+  source_tx_hash: string;
+  amount: string;
+  recipient: string;
+  nonce: string;
+  log_index: number;
+  digest_version: number;
+  source_sender?: string;
+  memo?: string;
+  source_block?: number;
+  signatures: ValidatorSignature[];
+}
+
+export interface HistoryEpoch {
+  version: number;
+  epoch: number;
+  from_ms: number;
+  to_ms: number;
+  vault: string;
+  required_signatures: number;
+  validators: string[]; // account-hash-...
+  locks: ArchivedLock[];
+  releases: ArchivedRelease[];
+}
+
+export interface SignedArchive {
+  epoch: HistoryEpoch;
+  signer: Uint8Array; // Ed25519 public key
+  signature: Uint8Array;
+  signed: Uint8Array; // the bytes the signature covers
+}
+
+class ArchiveWriter {
+  private chunks: Buffer[] = [];
+
+  raw(value: Uint8Array): this {
+    this.chunks.push(Buffer.from(value));
+    return this;
+  }
+
+  u8(value: number): this {
+    this.chunks.push(Buffer.from([value]));
+    return this;
+  }
+
+  u32(value: number): this {
+    const chunk = Buffer.alloc(4);
+    chunk.writeUInt32LE(value);
+    this.chunks.push(chunk);
+    return this;
+  }
+
+  u64(value: number | string): this {
+    const chunk = Buffer.alloc(8);
+    chunk.writeBigUInt64LE(BigInt(value));
+    this.chunks.push(chunk);
+    return this;
+  }
+
+  bytes(value: Uint8Array | number[]): this {
+    this.u32(value.length);
+    this.chunks.push(Buffer.from(value));
+    return this;
+  }
+
+  string(value: string): this {
+    return this.bytes(Buffer.from(value, 'utf8'));
+  }
+
+  option<T>(value: T | undefined, write: (value: T) => void): this {
+    if (value === undefined) {
+      return this.u8(0);
+    }
+    this.u8(1);
+    write(value);
+    return this;
+  }
+
+  list<T>(items: T[], write: (item: T) => void): this {
+    this.u32(items.length);
+    items.forEach(write);
+    return this;
+  }
+
+  finish(): Buffer {
+    return Buffer.concat(this.chunks);
+  }
+}
+
+class ArchiveReader {
+  private offset = 0;
+
+  constructor(private readonly data: Buffer) {}
+
+  private take(length: number): Buffer {
+    if (this.offset + length > this.data.length) {
+      throw new Error(`Archive truncated at byte ${this.offset}`);
+    }
+    const chunk = this.data.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return chunk;
+  }
+
+  u8(): number {
+    return this.take(1)[0];
+  }
+
+  u32(): number {
+    return this.take(4).readUInt32LE();
+  }
+
+  u64(): string {
+    return this.take(8).readBigUInt64LE().toString();
+  }
+
+  bytes(): number[] {
+    return Array.from(this.take(this.u32()));
+  }
+
+  string(): string {
+    return this.take(this.u32()).toString('utf8');
+  }
+
+  option<T>(read: () => T): T | undefined {
+    const tag = this.u8();
+    if (tag > 1) {
+      throw new Error(`Invalid option tag ${tag}`);
+    }
+    return tag === 1 ? read() : undefined;
+  }
+
+  list<T>(read: () => T): T[] {
+    const count = this.u32();
+    return Array.from({ length: count }, read);
+  }
+
+  raw(length: number): Buffer {
+    return this.take(length);
+  }
+
+  done(): boolean {
+    return this.offset === this.data.length;
+  }
+}
+
+/**
+ * Serialize an epoch without the signature trailer
+ */
+export function encodeEpoch(epoch: HistoryEpoch): Buffer {
+  const writer = new ArchiveWriter();
+  writer.raw(Buffer.from(MAGIC)).u8(ARCHIVE_FORMAT_VERSION);
+  writer.u64(epoch.epoch).u64(epoch.from_ms).u64(epoch.to_ms).string(epoch.vault);
+  writer.u32(epoch.required_signatures).list(epoch.validators, (validator) => writer.string(validator));
+  writer.list(epoch.locks, (lock) => {
+    writer.string(lock.deploy_hash).string(lock.amount).string(lock.destination_chain);
+    writer.string(lock.destination_address).u64(lock.nonce).string(lock.sender);
+  });
+  writer.list(epoch.releases, (release) => {
+    writer.string(release.deploy_hash).u64(release.block_height).string(release.source_chain);
+    writer.string(release.source_tx_hash).string(release.amount).string(release.recipient);
+    writer.u64(release.nonce).u32(release.log_index).u8(release.digest_version);
+    writer.option(release.source_sender, (sender) => writer.string(sender));
+    writer.option(release.memo, (memo) => writer.string(memo));
+    writer.option(release.source_block, (block) => writer.u64(block));
+    writer.list(release.signatures, (sig) => writer.bytes(sig.public_key).bytes(sig.signature));
+  });
+  return writer.finish();
+}
+
+/**
+ * Parse a signed archive file; does not check the signature
+ */
+export function decodeArchive(data: Buffer): SignedArchive {
+  if (data.length < SIGNATURE_TRAILER_LENGTH) {
+    throw new Error('Archive too short');
+  }
+  const signed = data.subarray(0, data.length - SIGNATURE_TRAILER_LENGTH);
+  const reader = new ArchiveReader(signed);
+
+  if (reader.raw(MAGIC.length).toString() !== MAGIC) {
+    throw new Error('Not a history archive');
+  }
+  const version = reader.u8();
+  if (version !== ARCHIVE_FORMAT_VERSION) {
+    throw new Error(`Unsupported archive format version ${version}`);
+  }
+
+  const epoch: HistoryEpoch = {
+    version,
+    epoch: Number(reader.u64()),
+    from_ms: Number(reader.u64()),
+    to_ms: Number(reader.u64()),
+    vault: reader.string(),
+    required_signatures: reader.u32(),
+    validators: reader.list(() => reader.string()),
+    locks: reader.list(() => ({
+      deploy_hash: reader.string(),
+      amount: reader.string(),
+      destination_chain: reader.string(),
+      destination_address: reader.string(),
+      nonce: reader.u64(),
+      sender: reader.string(),
+    })),
+    releases: reader.list(() => ({
+      deploy_hash: reader.string(),
+      block_height: Number(reader.u64()),
+      source_chain: reader.string(),
+      source_tx_hash: reader.string(),
+      amount: reader.string(),
+      recipient: reader.string(),
+      nonce: reader.u64(),
+      log_index: reader.u32(),
+      digest_version: reader.u8(),
+      source_sender: reader.option(() => reader.string()),
+      memo: reader.option(() => reader.string()),
+      source_block: reader.option(() => Number(reader.u64())),
+      signatures: reader.list(() => ({ public_key: reader.bytes(), signature: reader.bytes() })),
+    })),
+  };
+  if (!reader.done()) {
+    throw new Error('Unexpected bytes after the release records');
+  }
+
+  const trailer = data.subarray(data.length - SIGNATURE_TRAILER_LENGTH);
+  return {
+    epoch,
+    signer: Uint8Array.from(trailer.subarray(0, 32)),
+    signature: Uint8Array.from(trailer.subarray(32)),
+    signed: Uint8Array.from(signed),
+  };
+}
+
+export class HistoryArchive {
+  private archiveDir: string;
+  private vaultContract: string;
+  private casperClient: CasperClient;
+  private signer: CasperSigner;
+  private epochStart: number = Date.now();
+  private locks: ArchivedLock[] = [];
+  private releases: ArchivedRelease[] = [];
+
+  constructor(archiveDir: string, vaultContract: string, casperClient: CasperClient, signer: CasperSigner) {
+    this.archiveDir = archiveDir;
+    this.vaultContract = vaultContract;
+    this.casperClient = casperClient;
+    this.signer = signer;
+    fs.mkdirSync(archiveDir, { recursive: true });
+  }
+
+  updateClient(casperClient: CasperClient): void {
+    this.casperClient = casperClient;
+  }
+
+  recordLock(event: LockEvent): void {
+    this.locks.push({
+      deploy_hash: event.sourceTxHash,
+      amount: event.amount,
+      destination_chain: event.destinationChain,
+      destination_address: event.destinationAddress,
+      nonce: String(event.nonce),
+      sender: event.sender,
+    });
+  }
+
+  recordRelease(release: ReleaseExecution): void {
+    this.releases.push({
+      deploy_hash: release.deployHash,
+      block_height: release.blockHeight,
+      source_chain: release.sourceChain,
+      source_tx_hash: release.sourceTxHash,
+      amount: release.amount,
+      recipient: release.recipient,
+      nonce: release.nonce,
+      log_index: release.logIndex,
+      digest_version: release.digestVersion,
+      source_sender: release.sourceSender,
+      memo: release.memo,
+      source_block: release.sourceBlock,
+      signatures: release.signatures,
+    });
+  }
+
+  /**
+   * Close the current epoch and write it as the next archive file
+   * Records stay buffered for the next attempt if the vault cannot be read
+   */
+  async exportEpoch(): Promise<string> {
+    const { requiredSignatures, validators } = await this.readValidatorSet();
+    const now = Date.now();
+    const epoch: HistoryEpoch = {
+      version: ARCHIVE_FORMAT_VERSION,
+      epoch: this.nextEpochNumber(),
+      from_ms: this.epochStart,
+      to_ms: now,
+      vault: this.vaultContract,
+      required_signatures: requiredSignatures,
+      validators,
+      locks: this.locks,
+      releases: this.releases,
+    };
+
+    const body = encodeEpoch(epoch);
+    const signature = await this.signer.signMessage(body);
+    const file = path.join(this.archiveDir, `epoch-${epoch.epoch}.cbha`);
+    fs.writeFileSync(file, Buffer.concat([body, Buffer.from(this.signer.getPublicKey()), Buffer.from(signature)]), {
+      flag: 'wx',
+    });
+
+    this.epochStart = now;
+    this.locks = [];
+    this.releases = [];
+    logger.info('Wrote history archive', {
+      file,
+      locks: epoch.locks.length,
+      releases: epoch.releases.length,
+    });
+    return file;
+  }
+
+  private nextEpochNumber(): number {
+    const epochs = fs
+      .readdirSync(this.archiveDir)
+      .map((name) => /^epoch-(\d+)\.cbha$/.exec(name))
+      .filter((match): match is RegExpExecArray => match !== null)
+      .map((match) => parseInt(match[1]));
+    return epochs.length > 0 ? Math.max(...epochs) + 1 : 0;
+  }
+
+  private async readValidatorSet(): Promise<{ requiredSignatures: number; validators: string[] }> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    const readKey = async (name: string): Promise<any> => {
+      const stored = await this.casperClient.nodeClient.getBlockState(stateRootHash, this.vaultContract, [name]);
+      return stored.CLValue!.value();
+    };
+
+    const validators = (await readKey('validator_list')).map(
+      (key: any) => `account-hash-${Buffer.from(key.value().data).toString('hex')}`
+    );
+    return { requiredSignatures: Number((await readKey('required_signatures')).toString()), validators };
+  }
+}
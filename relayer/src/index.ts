@@ -5,12 +5,27 @@
  * cross-chain transaction proofs to enable bridging.
  */
 
+import * as crypto from 'crypto';
 import * as dotenv from 'dotenv';
 import express from 'express';
 import cors from 'cors';
-import { CasperMonitor } from './casper-monitor';
-import { EthereumMonitor } from './ethereum-monitor';
+import { CasperMonitor, CasperMonitorConfig } from './casper-monitor';
+import { EthereumMonitor, EthereumMonitorConfig } from './ethereum-monitor';
 import { Logger } from './logger';
+import { RelayerConfig, loadConfig, changedKeySettings } from './config';
+import { ProofCache } from './proof-cache';
+import { csprToMotes, motesToCspr } from './units';
+import { CasperSigner, RELEASE_DIGEST_VERSION, toAccountHashString, vaultBridgeId } from './signature-utils';
+import { RelayerExpectations, StaleConfigError, requireCurrentVaultConfig } from './vault-config-check';
+import { GOSSIP_PATH, SignatureGossip } from './signature-gossip';
+import { decodePartialProof } from './partial-proof';
+import { Watchtower, WatchtowerConfig } from './watchtower';
+import { BurnEvidence } from './burn-evidence';
+import { FaultInjector } from './fault-injection';
+import { ReleaseApprovals } from './release-approval';
+import { TRANSFER_STATUSES, TransferStatus, TransferStore } from './transfer-store';
+import { AlertConfig, AlertManager } from './alerts';
+import { HistoryArchive } from './history-archive';
 import { CasperClient, DeployUtil } from 'casper-js-sdk';
 
 dotenv.config();
@@ -18,43 +33,87 @@ dotenv.config();
 const logger = Logger.getInstance();
 
 class BridgeRelayer {
+  private config: RelayerConfig;
   private casperMonitor: CasperMonitor;
   private ethereumMonitor: EthereumMonitor;
   private isRunning: boolean = false;
   private app: express.Application;
   private httpServer: any;
   private casperClient: CasperClient;
+  private configCheckTimer?: NodeJS.Timeout;
+  private gossip: SignatureGossip;
+  private watchtower?: Watchtower;
+  private archive?: HistoryArchive;
+  private archiveTimer?: NodeJS.Timeout;
+  private transfers: TransferStore;
+  private approvals: ReleaseApprovals;
+  private alerts?: AlertManager;
+  private requiredSignatures: number = 0; // from the vault, 0 until read
 
   constructor() {
     logger.info('Initializing CasperBridge Relayer...');
 
+    this.config = loadConfig();
+
     this.casperMonitor = new CasperMonitor(
-      {
-        rpcUrl: process.env.CASPER_RPC_URL!,
-        networkName: process.env.CASPER_NETWORK_NAME!,
-        vaultContract: process.env.CASPER_VAULT_CONTRACT!,
-        privateKeyPath: process.env.CASPER_PRIVATE_KEY_PATH!,
-        pollInterval: parseInt(process.env.POLL_INTERVAL_MS || '5000'),
-        confirmationBlocks: parseInt(process.env.CONFIRMATION_BLOCKS_CASPER || '3'),
-      },
-      process.env.CASPER_PRIVATE_KEY_HEX!  // Ed25519 private key as hex string
+      BridgeRelayer.casperMonitorConfig(this.config),
+      this.config.casper.privateKeyHex,  // Ed25519 private key as hex string
+      BridgeRelayer.burnEvidence(this.config)
     );
 
-    this.ethereumMonitor = new EthereumMonitor({
-      rpcUrl: process.env.ETHEREUM_RPC_URL!,
-      chainId: parseInt(process.env.ETHEREUM_CHAIN_ID || '11155111'),
-      wrapperContract: process.env.ETHEREUM_WRAPPER_CONTRACT!,
-      privateKey: process.env.ETHEREUM_PRIVATE_KEY!,
-      pollInterval: parseInt(process.env.POLL_INTERVAL_MS || '5000'),
-      confirmationBlocks: parseInt(process.env.CONFIRMATION_BLOCKS_ETHEREUM || '12'),
-    });
+    this.ethereumMonitor = new EthereumMonitor(BridgeRelayer.ethereumMonitorConfig(this.config));
+
+    // Partial release proofs shared with peer relayers
+    this.gossip = new SignatureGossip(this.config.relayer.peers);
+    this.casperMonitor.useGossip(this.gossip);
+
+    // Optional guardian that halts the vault on releases without a matching burn
+    if (this.config.watchtower.enabled) {
+      this.watchtower = new Watchtower(BridgeRelayer.watchtowerConfig(this.config));
+    }
+
+    // Status of every transfer seen, for wallets and operators
+    this.transfers = new TransferStore(this.config.relayer.transferStorePath);
+
+    // Operator approval for releases above the configured threshold
+    this.approvals = new ReleaseApprovals(
+      BridgeRelayer.approvalThreshold(this.config),
+      this.config.approvals.storePath
+    );
+    this.casperMonitor.useApprovals(this.approvals);
+
+    // Optional alerts to webhooks, Slack and PagerDuty
+    if (BridgeRelayer.alertsConfigured(this.config)) {
+      this.alerts = new AlertManager(BridgeRelayer.alertConfig(this.config), this.transfers);
+    }
 
     // Initialize Casper client for deploy submission
-    this.casperClient = new CasperClient(process.env.CASPER_RPC_URL || 'http://34.220.83.153:7777/rpc');
+    this.casperClient = new CasperClient(this.config.casper.rpcUrl);
+
+    // Optional signed export of everything the monitor sees, one file per epoch
+    if (this.config.relayer.archiveDir) {
+      this.archive = new HistoryArchive(
+        this.config.relayer.archiveDir,
+        this.config.casper.vaultContract,
+        this.casperClient,
+        new CasperSigner(this.config.casper.privateKeyHex)
+      );
+    }
 
     // Setup HTTP server for deploy submission endpoint
     this.app = express();
     this.app.use(cors());
+
+    // Partial proofs gossiped by peer relayers; registered ahead of the JSON parser
+    // because the raw body has to be checked against the canonical encoding
+    this.app.post(GOSSIP_PATH, express.text({ type: 'application/json', limit: '1mb' }), (req, res) => {
+      try {
+        res.json({ success: true, accepted: this.gossip.receive(decodePartialProof(req.body)) });
+      } catch (error: any) {
+        res.status(400).json({ error: 'Rejected partial proof', message: error.message });
+      }
+    });
+
     this.app.use(express.json({ limit: '1mb' }));
 
     // Health check endpoint
@@ -62,6 +121,78 @@ class BridgeRelayer {
       res.json({ status: 'ok', relayer: this.getStatus() });
     });
 
+    // Hot reload of non-key configuration
+    this.app.post('/api/reload-config', (req, res) => {
+      try {
+        res.json({ success: true, ignoredKeySettings: this.reloadConfig() });
+      } catch (error: any) {
+        res.status(400).json({ error: 'Failed to reload config', message: error.message });
+      }
+    });
+
+    // Transfer history: GET /transfers?user=&status=&chain=&limit=
+    this.app.get('/transfers', (req, res) => {
+      const { user, status, chain, limit } = req.query;
+      if (status !== undefined && !TRANSFER_STATUSES.includes(status as TransferStatus)) {
+        return res.status(400).json({ error: `status must be one of ${TRANSFER_STATUSES.join(', ')}` });
+      }
+      const parsedLimit = limit === undefined ? undefined : Number(limit);
+      if (parsedLimit !== undefined && (!Number.isInteger(parsedLimit) || parsedLimit <= 0)) {
+        return res.status(400).json({ error: 'limit must be a positive integer' });
+      }
+      res.json({
+        transfers: this.transfers.query({
+          user: user as string | undefined,
+          status: status as TransferStatus | undefined,
+          chain: chain as string | undefined,
+          limit: parsedLimit,
+        }),
+      });
+    });
+
+    this.app.get('/transfers/:id', (req, res) => {
+      const transfer = this.transfers.get(req.params.id);
+      if (!transfer) {
+        return res.status(404).json({ error: 'Unknown transfer' });
+      }
+      res.json(transfer);
+    });
+
+    // Release approval queue; every route needs the approval token as a bearer token
+    this.app.use('/api/approvals', (req, res, next) => {
+      const token = this.config.approvals.apiToken;
+      const given = Buffer.from(req.get('authorization')?.replace(/^Bearer /, '') ?? '');
+      if (!token || given.length !== token.length || !crypto.timingSafeEqual(given, Buffer.from(token))) {
+        return res.status(401).json({ error: 'Missing or wrong approval token' });
+      }
+      next();
+    });
+
+    this.app.get('/api/approvals', (req, res) => {
+      res.json({ pending: this.approvals.pending() });
+    });
+
+    this.app.post('/api/approvals/:id/:decision', (req, res) => {
+      const { decision, id } = req.params;
+      const { operator, reason } = req.body ?? {};
+      if (decision !== 'approve' && decision !== 'reject') {
+        return res.status(404).json({ error: 'Decision must be approve or reject' });
+      }
+      if (!operator || (decision === 'reject' && !reason)) {
+        return res.status(400).json({ error: `Missing ${operator ? 'reason' : 'operator'}` });
+      }
+      try {
+        const record =
+          decision === 'approve' ? this.approvals.approve(id, operator) : this.approvals.reject(id, operator, reason);
+        void this.alerts?.resolve(`approval-pending:${id}`);
+        // Replayed through the monitor, which now signs or refuses it
+        void this.relayRelease(record.burnEvent);
+        res.json({ success: true, approval: record });
+      } catch (error: any) {
+        res.status(409).json({ error: error.message });
+      }
+    });
+
     // Deploy submission endpoint
     this.app.post('/api/submit-deploy', async (req, res) => {
       try {
@@ -109,7 +240,7 @@ class BridgeRelayer {
         // Submit to Casper network
         const deployHash = await this.casperClient.putDeploy(deploy);
 
-        logger.info('✅ Deploy submitted successfully', { deployHash });
+        Logger.forTransfer(deployHash).info('✅ Deploy submitted successfully', { deployHash });
 
         // Track this deploy for lock event detection
         this.casperMonitor.trackDeploy(deployHash);
@@ -208,7 +339,7 @@ class BridgeRelayer {
 
           // Convert motes to CSPR
           const balanceInMotes = BigInt(balance.toString());
-          const balanceInCSPR = (Number(balanceInMotes) / 1_000_000_000).toFixed(2);
+          const balanceInCSPR = motesToCspr(balanceInMotes, 2);
           res.json({ balance: balanceInCSPR });
         } catch (balanceError: any) {
           logger.warn('Could not fetch balance, account may be empty or invalid', {
@@ -225,6 +356,248 @@ class BridgeRelayer {
     });
   }
 
+  private static casperMonitorConfig(config: RelayerConfig): CasperMonitorConfig {
+    return {
+      rpcUrl: config.casper.rpcUrl,
+      networkName: config.casper.networkName,
+      vaultContract: config.casper.vaultContract,
+      privateKeyPath: config.casper.privateKeyPath,
+      pollInterval: config.relayer.pollIntervalMs,
+      confirmationBlocks: config.casper.confirmationBlocks,
+      submissionJitterMs: config.relayer.submissionJitterMs,
+      faults: config.faults,
+    };
+  }
+
+  private static burnEvidence(config: RelayerConfig): BurnEvidence {
+    return new BurnEvidence(
+      config.ethereum.rpcUrl,
+      config.ethereum.chainId,
+      config.ethereum.wrapperContract,
+      new FaultInjector(config.faults)
+    );
+  }
+
+  private static approvalThreshold(config: RelayerConfig): bigint | null {
+    return config.approvals.thresholdCspr ? csprToMotes(config.approvals.thresholdCspr) : null;
+  }
+
+  private static watchtowerConfig(config: RelayerConfig): WatchtowerConfig {
+    return {
+      casperRpcUrl: config.casper.rpcUrl,
+      networkName: config.casper.networkName,
+      vaultContract: config.casper.vaultContract,
+      ethereumRpcUrl: config.ethereum.rpcUrl,
+      chainId: config.ethereum.chainId,
+      wrapperContract: config.ethereum.wrapperContract,
+      guardianKeyHex: config.watchtower.guardianKeyHex,
+      action: config.watchtower.action,
+      dryRun: config.watchtower.dryRun,
+      maxActionsPerHour: config.watchtower.maxActionsPerHour,
+    };
+  }
+
+  private static alertsConfigured(config: RelayerConfig): boolean {
+    const { webhookUrl, slackWebhookUrl, pagerDutyRoutingKey } = config.alerts;
+    return Boolean(webhookUrl || slackWebhookUrl || pagerDutyRoutingKey);
+  }
+
+  private static alertConfig(config: RelayerConfig): AlertConfig {
+    return {
+      casperRpcUrl: config.casper.rpcUrl,
+      vaultContract: config.casper.vaultContract,
+      ethereumRpcUrl: config.ethereum.rpcUrl,
+      chainId: config.ethereum.chainId,
+      wrapperContract: config.ethereum.wrapperContract,
+      ...config.alerts,
+    };
+  }
+
+  private static ethereumMonitorConfig(config: RelayerConfig): EthereumMonitorConfig {
+    return {
+      rpcUrl: config.ethereum.rpcUrl,
+      chainId: config.ethereum.chainId,
+      wrapperContract: config.ethereum.wrapperContract,
+      privateKey: config.ethereum.privateKey,
+      pollInterval: config.relayer.pollIntervalMs,
+      confirmationBlocks: config.ethereum.confirmationBlocks,
+      submissionJitterMs: config.relayer.submissionJitterMs,
+    };
+  }
+
+  /**
+   * Reload configuration and apply everything except key settings
+   * Returns the key settings that changed but were not applied
+   */
+  reloadConfig(): string[] {
+    const next = loadConfig();
+    const ignored = changedKeySettings(this.config, next);
+    if (ignored.length > 0) {
+      logger.warn('Key settings changed; restart the relayer to apply them', { ignored });
+    }
+
+    // Keep the running keys, take everything else from the new config
+    next.casper.privateKeyPath = this.config.casper.privateKeyPath;
+    next.casper.privateKeyHex = this.config.casper.privateKeyHex;
+    next.ethereum.privateKey = this.config.ethereum.privateKey;
+    next.watchtower.guardianKeyHex = this.config.watchtower.guardianKeyHex;
+    if (next.watchtower.enabled !== this.config.watchtower.enabled) {
+      logger.warn('Enabling or disabling the watchtower takes effect after restart');
+      next.watchtower.enabled = this.config.watchtower.enabled;
+    }
+
+    if (next.casper.rpcUrl !== this.config.casper.rpcUrl) {
+      this.casperClient = new CasperClient(next.casper.rpcUrl);
+      this.archive?.updateClient(this.casperClient);
+    }
+    if (next.relayer.archiveDir !== this.config.relayer.archiveDir) {
+      logger.warn('Archive directory changes take effect after restart');
+      next.relayer.archiveDir = this.config.relayer.archiveDir;
+    }
+    if (next.relayer.transferStorePath !== this.config.relayer.transferStorePath) {
+      logger.warn('Transfer store path changes take effect after restart');
+      next.relayer.transferStorePath = this.config.relayer.transferStorePath;
+    }
+    if (next.approvals.storePath !== this.config.approvals.storePath) {
+      logger.warn('Approval store path changes take effect after restart');
+      next.approvals.storePath = this.config.approvals.storePath;
+    }
+    if (next.relayer.port !== this.config.relayer.port) {
+      logger.warn('HTTP port changes take effect after restart', { port: next.relayer.port });
+    }
+
+    this.casperMonitor.updateConfig(BridgeRelayer.casperMonitorConfig(next));
+    this.casperMonitor.useBurnEvidence(BridgeRelayer.burnEvidence(next));
+    this.approvals.updateThreshold(BridgeRelayer.approvalThreshold(next));
+    this.gossip.updatePeers(next.relayer.peers);
+    this.watchtower?.updateConfig(BridgeRelayer.watchtowerConfig(next));
+    if (BridgeRelayer.alertsConfigured(next) !== Boolean(this.alerts)) {
+      logger.warn('Enabling or disabling alerts takes effect after restart');
+    }
+    this.alerts?.updateConfig(BridgeRelayer.alertConfig(next));
+    const alertIntervalChanged = next.alerts.checkIntervalMs !== this.config.alerts.checkIntervalMs;
+    this.ethereumMonitor.updateConfig(BridgeRelayer.ethereumMonitorConfig(next));
+    const checkIntervalChanged = next.relayer.configCheckIntervalMs !== this.config.relayer.configCheckIntervalMs;
+    const archiveIntervalChanged = next.relayer.archiveIntervalMs !== this.config.relayer.archiveIntervalMs;
+    this.config = next;
+    void this.applyOnChainFinality();
+    if (this.isRunning && checkIntervalChanged) {
+      clearInterval(this.configCheckTimer);
+      this.scheduleConfigChecks();
+    }
+    if (this.isRunning && archiveIntervalChanged) {
+      clearInterval(this.archiveTimer);
+      this.scheduleArchiveExports();
+    }
+    if (this.isRunning && alertIntervalChanged) {
+      this.alerts?.start();
+    }
+
+    logger.info('Relayer configuration reloaded');
+    return ignored;
+  }
+
+  // Sign and submit the release for a burn; failures are recorded on the transfer
+  private async relayRelease(burnEvent: any): Promise<void> {
+    const transferId = ProofCache.releaseTransferId('ethereum', burnEvent.nonce, burnEvent.logIndex ?? 0);
+    try {
+      await this.casperMonitor.submitReleaseProof(burnEvent);
+    } catch (error: any) {
+      this.transfers.failed(transferId, error.message);
+    }
+  }
+
+  /**
+   * Take Ethereum finality from the vault so every relayer uses the same safety parameters
+   */
+  async applyOnChainFinality(): Promise<void> {
+    try {
+      const finality = await this.casperMonitor.getChainFinality('ethereum');
+      if (!finality) {
+        logger.info('No on-chain finality set for ethereum, using local confirmationBlocks', {
+          confirmationBlocks: this.config.ethereum.confirmationBlocks,
+        });
+        return;
+      }
+
+      if (finality.minConfirmations !== this.config.ethereum.confirmationBlocks) {
+        logger.warn('Local ethereum confirmationBlocks differs from the vault, using the vault value', {
+          local: this.config.ethereum.confirmationBlocks,
+          onChain: finality.minConfirmations,
+        });
+      }
+      this.config.ethereum.confirmationBlocks = finality.minConfirmations;
+      this.ethereumMonitor.updateConfig(BridgeRelayer.ethereumMonitorConfig(this.config));
+      logger.info('Applied on-chain finality for ethereum', finality);
+    } catch (error: any) {
+      logger.error('Failed to read on-chain finality, using local configuration', { error: error.message });
+    }
+  }
+
+  /**
+   * What this relayer assumes about the vault, from its own configuration
+   */
+  private expectations(): RelayerExpectations {
+    return {
+      chains: ['ethereum'],
+      digestVersion: RELEASE_DIGEST_VERSION,
+      bridgeId: vaultBridgeId(this.config.casper.vaultContract),
+      tokens: { ethereum: this.config.ethereum.wrapperContract },
+    };
+  }
+
+  /**
+   * Throws StaleConfigError if the vault disagrees with this relayer's configuration
+   */
+  async checkVaultConfig(): Promise<void> {
+    const vault = await requireCurrentVaultConfig(
+      this.casperClient,
+      this.config.casper.vaultContract,
+      this.expectations()
+    );
+    // Gossiped signatures and proof size follow the vault's current validator set and limit
+    this.gossip.updateValidators(vault.validators);
+    this.casperMonitor.setMaxSignatures(vault.maxSignatures);
+    this.requiredSignatures = vault.requiredSignatures;
+    logger.info('Relayer configuration matches the vault');
+  }
+
+  // Stop relaying as soon as the vault's config moves away from ours; read failures are retried next time
+  private scheduleConfigChecks(): void {
+    const interval = this.config.relayer.configCheckIntervalMs;
+    if (interval === 0) {
+      return;
+    }
+    this.configCheckTimer = setInterval(async () => {
+      try {
+        await this.checkVaultConfig();
+      } catch (error: any) {
+        if (!(error instanceof StaleConfigError)) {
+          logger.warn('Could not check configuration against the vault', { error: error.message });
+          return;
+        }
+        logger.error('Stopping: relayer configuration no longer matches the vault', { problems: error.problems });
+        await this.stop();
+        process.exit(1);
+      }
+    }, interval);
+  }
+
+  // A failed export keeps its records for the next epoch
+  private scheduleArchiveExports(): void {
+    const archive = this.archive;
+    if (!archive) {
+      return;
+    }
+    this.archiveTimer = setInterval(async () => {
+      try {
+        await archive.exportEpoch();
+      } catch (error: any) {
+        logger.error('Failed to write history archive', { error: error.message });
+      }
+    }, this.config.relayer.archiveIntervalMs);
+  }
+
   async start(): Promise<void> {
     if (this.isRunning) {
       logger.warn('Relayer is already running');
@@ -232,10 +605,13 @@ class BridgeRelayer {
     }
 
     logger.info('Starting relayer service...');
+
+    // Refuse to sign anything the vault would not verify
+    await this.checkVaultConfig();
     this.isRunning = true;
 
     // Start HTTP server for deploy submission
-    const port = parseInt(process.env.RELAYER_PORT || '3001');
+    const port = this.config.relayer.port;
     this.httpServer = this.app.listen(port, '0.0.0.0', () => {
       logger.info(`🌐 HTTP server listening on http://localhost:${port}`);
       logger.info(`Deploy submission endpoint: http://localhost:${port}/api/submit-deploy`);
@@ -243,14 +619,104 @@ class BridgeRelayer {
 
     // Setup event handlers
     this.casperMonitor.on('AssetLocked', async (event) => {
-      logger.info('Detected AssetLocked event on Casper', { event });
-      await this.ethereumMonitor.submitMintProof(event);
+      Logger.forTransfer(event.sourceTxHash).info('Detected AssetLocked event on Casper', { event });
+      this.transfers.locked({
+        id: event.sourceTxHash,
+        sourceChain: 'casper',
+        destinationChain: event.destinationChain,
+        sourceTxHash: event.sourceTxHash,
+        sender: event.sender,
+        recipient: event.destinationAddress,
+        amount: event.amount,
+      });
+      try {
+        await this.ethereumMonitor.submitMintProof(event);
+      } catch (error: any) {
+        this.transfers.failed(event.sourceTxHash, error.message);
+      }
     });
 
     this.ethereumMonitor.on('AssetBurned', async (event) => {
-      logger.info('Detected AssetBurned event on Ethereum', { event });
-      await this.casperMonitor.submitReleaseProof(event);
+      const transferId = ProofCache.releaseTransferId('ethereum', event.nonce, event.logIndex ?? 0);
+      Logger.forTransfer(transferId).info('Detected AssetBurned event on Ethereum', { event });
+      let recipient = event.destinationAddress;
+      try {
+        recipient = toAccountHashString(event.destinationAddress);
+      } catch {
+        // Kept as given; the release is refused later
+      }
+      this.transfers.locked({
+        id: transferId,
+        sourceChain: 'ethereum',
+        destinationChain: event.destinationChain,
+        sourceTxHash: event.txHash,
+        sender: event.user,
+        recipient,
+        amount: event.amount,
+      });
+      await this.relayRelease(event);
+    });
+
+    // Mints carry this relayer's signature only; the wrapper's threshold is not read
+    this.ethereumMonitor.on('MintSubmitted', ({ transferId, txHash }) => {
+      this.transfers.attested(transferId, 1, 0);
+      this.transfers.submitted(transferId, txHash);
+    });
+    this.ethereumMonitor.on('MintConfirmed', ({ transferId, txHash }) => this.transfers.released(transferId, txHash));
+    this.ethereumMonitor.on('MintProcessed', ({ transferId }) => this.transfers.released(transferId));
+    this.casperMonitor.on('ReleaseProcessed', ({ transferId }) => this.transfers.released(transferId));
+    this.casperMonitor.on('ReleaseAttested', ({ transferId, signatures }) => {
+      this.transfers.attested(transferId, signatures, this.requiredSignatures);
     });
+    this.casperMonitor.on('ReleaseSubmitted', ({ transferId, deployHash }) => this.transfers.submitted(transferId, deployHash));
+    this.casperMonitor.on('ReleaseRefused', ({ transferId, reasons }) => {
+      this.transfers.failed(transferId, `refused to sign: ${reasons.join('; ')}`);
+      void this.alerts?.raise({
+        rule: 'signing-refused',
+        key: `signing-refused:${transferId}`,
+        severity: 'critical',
+        summary: `Refused to sign release ${transferId}: ${reasons.join('; ')}`,
+        details: { transferId, reasons },
+      });
+    });
+    this.casperMonitor.on('ReleaseHeld', ({ transferId, claim }) => {
+      void this.alerts?.raise({
+        rule: 'approval-pending',
+        key: `approval-pending:${transferId}`,
+        severity: 'warning',
+        summary: `Release ${transferId} of ${motesToCspr(claim.amount)} CSPR waits for operator approval`,
+        details: { transferId, claim },
+      });
+    });
+    this.casperMonitor.on('ReleaseExecuted', (release) => {
+      this.transfers.released(ProofCache.releaseTransferId(release.sourceChain, release.nonce, release.logIndex), release.deployHash);
+    });
+
+    const archive = this.archive;
+    if (archive) {
+      this.casperMonitor.on('AssetLocked', (event) => archive.recordLock(event));
+      this.casperMonitor.on('ReleaseExecuted', (release) => archive.recordRelease(release));
+      logger.info('History archive enabled', { archiveDir: this.config.relayer.archiveDir });
+    }
+
+    const watchtower = this.watchtower;
+    if (watchtower) {
+      this.casperMonitor.on('ReleaseExecuted', async (release) => {
+        await watchtower.checkRelease(release);
+      });
+      logger.info('Watchtower enabled', {
+        action: this.config.watchtower.action,
+        dryRun: this.config.watchtower.dryRun,
+      });
+    }
+
+    await this.applyOnChainFinality();
+    this.scheduleConfigChecks();
+    this.scheduleArchiveExports();
+    if (this.alerts) {
+      this.alerts.start();
+      logger.info('Alerts enabled', { checkIntervalMs: this.config.alerts.checkIntervalMs });
+    }
 
     // Start monitoring both chains
     await Promise.all([
@@ -269,6 +735,9 @@ class BridgeRelayer {
 
     logger.info('Stopping relayer service...');
     this.isRunning = false;
+    clearInterval(this.configCheckTimer);
+    clearInterval(this.archiveTimer);
+    this.alerts?.stop();
 
     // Close HTTP server
     if (this.httpServer) {
@@ -285,6 +754,13 @@ class BridgeRelayer {
       this.ethereumMonitor.stop(),
     ]);
 
+    // Close the running epoch so nothing recorded is lost
+    try {
+      await this.archive?.exportEpoch();
+    } catch (error: any) {
+      logger.error('Failed to write history archive on shutdown', { error: error.message });
+    }
+
     logger.info('Relayer service stopped');
   }
 
@@ -314,6 +790,15 @@ async function main() {
     process.exit(0);
   });
 
+  process.on('SIGHUP', () => {
+    logger.info('Received SIGHUP, reloading configuration...');
+    try {
+      relayer.reloadConfig();
+    } catch (error: any) {
+      logger.error('Config reload failed, keeping current configuration', { error: error.message });
+    }
+  });
+
   try {
     await relayer.start();
   } catch (error) {
@@ -0,0 +1,36 @@
+import { percentile, runLoadTest } from './loadtest';
+
+const options = { events: 40, validators: 4, requiredSignatures: 3, ratePerSecond: 0, concurrency: 8 };
+
+describe('percentile', () => {
+  it('uses the nearest rank', () => {
+    const samples = Array.from({ length: 100 }, (_, i) => i + 1);
+    expect(percentile(samples, 0.5)).toBe(50);
+    expect(percentile(samples, 0.99)).toBe(99);
+    expect(percentile(samples, 1)).toBe(100);
+    expect(percentile([], 0.5)).toBe(0);
+  });
+});
+
+describe('runLoadTest', () => {
+  it('attests every burn once a quorum signed, through gossip', async () => {
+    const report = await runLoadTest(options);
+    expect(report.attested).toBe(40);
+    // Each burn takes exactly requiredSignatures signatures; all but the last signer gossip to 3 peers
+    expect(report.signatures).toBe(40 * 3);
+    expect(report.gossipMessages).toBe(40 * 2 * 3);
+    expect(report.peakBacklog).toBe(40);
+    expect(report.latencyMs.p50 <= report.latencyMs.p99 && report.latencyMs.p99 <= report.latencyMs.max).toBe(true);
+  });
+
+  it('paces burns at the given rate', async () => {
+    const report = await runLoadTest({ ...options, events: 10, ratePerSecond: 200 });
+    expect(report.attested).toBe(10);
+    expect(report.durationMs >= 45).toBe(true);
+    expect(report.peakBacklog < 10).toBe(true);
+  });
+
+  it('refuses a quorum larger than the validator set', async () => {
+    await expect(runLoadTest({ ...options, requiredSignatures: 5 })).rejects.toThrow('exceeds the 4 validators');
+  });
+});
@@ -0,0 +1,239 @@
+/**
+ * Signing Pipeline Load Test
+ *
+ * Drives synthetic Ethereum burns through the release signing path in one
+ * process, to see how many releases a validator set can attest per second
+ * before the relayers fall behind:
+ *
+ *   npm run loadtest -- [--events 2000] [--validators 5] [--required 3] [--rate 0] [--concurrency 64]
+ *
+ * Every validator is a relayer with a throwaway Ed25519 key, its own ProofCache
+ * and SignatureGossip, in a full mesh. For each burn the relayers take turns,
+ * as they would when their Ethereum monitors see it at different times: build
+ * the v5 release message, sign it, merge their signature with what peers
+ * gossiped, and send the merged partial proof to every peer through its
+ * canonical encoding. The first relayer to hold requiredSignatures submits;
+ * the rest find the proof processed and skip it. No chain is involved, so
+ * the numbers are an upper bound for signing and aggregation alone.
+ *
+ * Burns arrive at --rate per second (0 sends them all at once) and up to
+ * --concurrency are in flight. Latency runs from a burn's arrival to quorum,
+ * so it includes time spent waiting in the backlog.
+ */
+
+import { performance } from 'perf_hooks';
+import { CasperSigner, createReleaseMessage, ed25519AccountHash, RELEASE_DIGEST_VERSION } from './signature-utils';
+import { ProofCache } from './proof-cache';
+import { SignatureGossip } from './signature-gossip';
+import { decodePartialProof, encodePartialProof } from './partial-proof';
+
+const BRIDGE_ID = 'b1d9e0000000000000000000000000000000000000000000000000000000b1d9';
+
+export interface LoadTestOptions {
+  events: number;
+  validators: number;
+  requiredSignatures: number;
+  ratePerSecond: number; // 0 releases every burn at once
+  concurrency: number;
+}
+
+export interface LoadTestReport {
+  events: number;
+  attested: number;
+  durationMs: number;
+  throughputPerSecond: number;
+  latencyMs: { p50: number; p95: number; p99: number; max: number };
+  peakBacklog: number; // burns arrived but not yet at quorum
+  signatures: number;
+  gossipMessages: number;
+}
+
+interface Relayer {
+  signer: CasperSigner;
+  cache: ProofCache;
+  gossip: SignatureGossip;
+}
+
+interface SyntheticBurn {
+  nonce: number;
+  arrivesAt: number; // ms after the start
+}
+
+/**
+ * Value below which the given share of sorted samples fall (nearest rank)
+ */
+export function percentile(sorted: number[], share: number): number {
+  if (sorted.length === 0) {
+    return 0;
+  }
+  return sorted[Math.min(sorted.length - 1, Math.max(0, Math.ceil(share * sorted.length) - 1))];
+}
+
+function validateOptions(options: LoadTestOptions): void {
+  const { events, validators, requiredSignatures, ratePerSecond, concurrency } = options;
+  if (![events, validators, requiredSignatures, concurrency].every((value) => Number.isInteger(value) && value > 0)) {
+    throw new Error('--events, --validators, --required and --concurrency must be positive integers');
+  }
+  if (requiredSignatures > validators) {
+    throw new Error(`--required ${requiredSignatures} exceeds the ${validators} validators`);
+  }
+  if (!(ratePerSecond >= 0)) {
+    throw new Error('--rate must be 0 or a positive number of burns per second');
+  }
+}
+
+function createRelayers(count: number): Relayer[] {
+  // Deterministic throwaway keys, so runs are comparable
+  const relayers = Array.from({ length: count }, (_, i) => ({
+    signer: new CasperSigner((i + 1).toString(16).padStart(2, '0').repeat(32)),
+    cache: new ProofCache(),
+    gossip: new SignatureGossip([]),
+  }));
+  const validators = relayers.map((relayer) => ed25519AccountHash(relayer.signer.getPublicKey()));
+  for (const relayer of relayers) {
+    relayer.gossip.updateValidators(validators);
+  }
+  return relayers;
+}
+
+/**
+ * Run the load test and report throughput, latency percentiles and the deepest backlog
+ */
+export async function runLoadTest(options: LoadTestOptions): Promise<LoadTestReport> {
+  validateOptions(options);
+  const relayers = createRelayers(options.validators);
+  const recipient = ed25519AccountHash(relayers[0].signer.getPublicKey());
+  const burns: SyntheticBurn[] = Array.from({ length: options.events }, (_, nonce) => ({
+    nonce,
+    arrivesAt: options.ratePerSecond > 0 ? (nonce * 1000) / options.ratePerSecond : 0,
+  }));
+
+  const latencies: number[] = [];
+  let signatures = 0;
+  let gossipMessages = 0;
+  let peakBacklog = 0;
+  const started = performance.now();
+  const elapsed = () => performance.now() - started;
+
+  const attest = async (burn: SyntheticBurn): Promise<void> => {
+    const transferId = ProofCache.releaseTransferId('ethereum', burn.nonce, 0);
+    const message = createReleaseMessage(
+      'ethereum',
+      `0x${burn.nonce.toString(16).padStart(64, '0')}`,
+      '1000000000',
+      recipient,
+      burn.nonce.toString(),
+      0,
+      RELEASE_DIGEST_VERSION,
+      '0x' + '22'.repeat(20),
+      undefined,
+      1_000_000 + burn.nonce,
+      BRIDGE_ID
+    );
+    const messageHex = Buffer.from(message).toString('hex');
+
+    // Start with a different relayer for each burn, so every key sees both roles
+    for (let turn = 0; turn < relayers.length; turn++) {
+      const relayer = relayers[(burn.nonce + turn) % relayers.length];
+      const signature = await relayer.signer.signMessage(message);
+      signatures++;
+      const merged = relayer.cache.addSignatures(transferId, [
+        { public_key: Array.from(relayer.signer.getPublicKey()), signature: Array.from(signature) },
+        ...relayer.gossip.signaturesFor(transferId, message),
+      ]);
+
+      if (merged.length >= options.requiredSignatures) {
+        latencies.push(elapsed() - burn.arrivesAt);
+        for (const done of relayers) {
+          done.cache.markCompleted(transferId);
+          done.gossip.forget(transferId);
+        }
+        return;
+      }
+
+      const encoded = encodePartialProof({ transfer_id: transferId, message_hex: messageHex, signatures: merged });
+      for (const peer of relayers) {
+        if (peer !== relayer) {
+          peer.gossip.receive(decodePartialProof(encoded));
+          gossipMessages++;
+        }
+      }
+    }
+  };
+
+  let next = 0;
+  let arrived = 0;
+  const worker = async (): Promise<void> => {
+    while (next < burns.length) {
+      const burn = burns[next++];
+      const wait = burn.arrivesAt - elapsed();
+      if (wait > 0) {
+        await new Promise((resolve) => setTimeout(resolve, wait));
+      }
+      // Arrival times only grow, so count arrivals from where the last burn left off
+      while (arrived < burns.length && burns[arrived].arrivesAt <= elapsed()) {
+        arrived++;
+      }
+      peakBacklog = Math.max(peakBacklog, arrived - latencies.length);
+      await attest(burn);
+    }
+  };
+  await Promise.all(Array.from({ length: Math.min(options.concurrency, burns.length) }, worker));
+
+  const durationMs = elapsed();
+  const sorted = [...latencies].sort((a, b) => a - b);
+  return {
+    events: options.events,
+    attested: latencies.length,
+    durationMs,
+    throughputPerSecond: durationMs > 0 ? (latencies.length * 1000) / durationMs : 0,
+    latencyMs: {
+      p50: percentile(sorted, 0.5),
+      p95: percentile(sorted, 0.95),
+      p99: percentile(sorted, 0.99),
+      max: sorted.length > 0 ? sorted[sorted.length - 1] : 0,
+    },
+    peakBacklog,
+    signatures,
+    gossipMessages,
+  };
+}
+
+function parseArgs(argv: string[]): Record<string, string> {
+  const flags: Record<string, string> = {};
+  for (let i = 0; i < argv.length; i++) {
+    if (argv[i].startsWith('--')) {
+      flags[argv[i].slice(2)] = argv[++i];
+    }
+  }
+  return flags;
+}
+
+async function main() {
+  const flags = parseArgs(process.argv.slice(2));
+  const options: LoadTestOptions = {
+    events: Number(flags.events ?? 2000),
+    validators: Number(flags.validators ?? 5),
+    requiredSignatures: Number(flags.required ?? 3),
+    ratePerSecond: Number(flags.rate ?? 0),
+    concurrency: Number(flags.concurrency ?? 64),
+  };
+  const report = await runLoadTest(options);
+  const ms = (value: number) => `${value.toFixed(1)} ms`;
+
+  console.log(`${report.attested}/${report.events} releases attested in ${ms(report.durationMs)}`);
+  console.log(`  throughput   ${report.throughputPerSecond.toFixed(1)} releases/s`);
+  console.log(
+    `  latency      p50 ${ms(report.latencyMs.p50)}, p95 ${ms(report.latencyMs.p95)}, ` +
+      `p99 ${ms(report.latencyMs.p99)}, max ${ms(report.latencyMs.max)}`
+  );
+  console.log(`  peak backlog ${report.peakBacklog} burns`);
+  console.log(`  work         ${report.signatures} signatures, ${report.gossipMessages} gossiped partial proofs`);
+}
+
+if (require.main === module) {
+  main().catch((error) => {
+    console.error('Load test failed:', error.message);
+    process.exit(1);
+  });
+}
@@ -32,4 +32,12 @@ export class Logger {
     }
     return Logger.instance;
   }
+
+  /**
+   * Child logger tagging every entry with the transfer's correlation ID
+   * (Casper deploy hash for locks, "{nonce}_{logIndex}" for Ethereum burns)
+   */
+  static forTransfer(transferId: string): winston.Logger {
+    return Logger.getInstance().child({ transferId });
+  }
 }
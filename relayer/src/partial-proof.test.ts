@@ -0,0 +1,43 @@
+import * as fs from 'fs';
+import * as path from 'path';
+import { PartialProof, decodePartialProof, encodePartialProof } from './partial-proof';
+
+interface HexPartial {
+  transfer_id: string;
+  message_hex: string;
+  signatures: Array<{ public_key: string; signature: string }>;
+}
+
+const vectors: {
+  valid: Array<{ name: string; partial: HexPartial; encoded: string }>;
+  invalid: Array<{ name: string; encoded: string }>;
+} = JSON.parse(fs.readFileSync(path.join(__dirname, '../vectors/partial-proof-vectors.json'), 'utf8'));
+
+const fromVector = (partial: HexPartial): PartialProof => ({
+  ...partial,
+  signatures: partial.signatures.map((sig) => ({
+    public_key: Array.from(Buffer.from(sig.public_key, 'hex')),
+    signature: Array.from(Buffer.from(sig.signature, 'hex')),
+  })),
+});
+
+describe('encodePartialProof', () => {
+  it.each(vectors.valid.map((vector) => [vector.name, vector] as const))('encodes %s canonically', (_, vector) => {
+    expect(encodePartialProof(fromVector(vector.partial))).toBe(vector.encoded);
+  });
+
+  it('rejects a transfer id that is not {sourceChain}_{nonce}_{logIndex}', () => {
+    const partial = fromVector(vectors.valid[0].partial);
+    expect(() => encodePartialProof({ ...partial, transfer_id: 'abc' })).toThrow();
+  });
+});
+
+describe('decodePartialProof', () => {
+  it.each(vectors.valid.map((vector) => [vector.name, vector] as const))('round-trips %s', (_, vector) => {
+    expect(encodePartialProof(decodePartialProof(vector.encoded))).toBe(vector.encoded);
+  });
+
+  it.each(vectors.invalid.map((vector) => [vector.name, vector.encoded] as const))('rejects %s', (_, encoded) => {
+    expect(() => decodePartialProof(encoded)).toThrow();
+  });
+});
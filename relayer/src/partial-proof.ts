@@ -0,0 +1,103 @@
+/**
+ * Partial Proof Encoding
+ *
+ * Canonical JSON for the partial release proofs relayers gossip to each
+ * other (see signature-gossip.ts), so every implementation turns the same
+ * signatures into the same bytes and accepts each other's:
+ *
+ *   {"transfer_id":"ethereum_7_0","message_hex":"...","signatures":[{"public_key":"...","signature":"..."}]}
+ *
+ * - UTF-8 with no whitespace, keys in exactly this order, no other keys
+ * - transfer_id is "{sourceChain}_{nonce}_{logIndex}": the chain name in lowercase letters, digits
+ *   and "-", then nonce and log index in decimal without leading zeros
+ * - message_hex, public_key (32 bytes) and signature (64 bytes) are lowercase hex without "0x"
+ * - signatures are sorted by public_key, each key at most once
+ *
+ * decodePartialProof rejects every other byte string, including JSON that
+ * carries the same values in another form. Golden vectors are in
+ * vectors/partial-proof-vectors.json.
+ */
+
+import { ValidatorSignature } from './proof-cache';
+
+export interface PartialProof {
+  transfer_id: string;
+  message_hex: string; // release message the signatures cover
+  signatures: ValidatorSignature[];
+}
+
+const TRANSFER_ID_PATTERN = /^[a-z0-9-]+_(0|[1-9]\d*)_(0|[1-9]\d*)$/;
+const HEX_PATTERN = /^([0-9a-f]{2})+$/;
+
+function toHex(bytes: number[], length: number, field: string): string {
+  if (!Array.isArray(bytes) || bytes.length !== length || bytes.some((b) => !Number.isInteger(b) || b < 0 || b > 255)) {
+    throw new Error(`Partial proof ${field} must be ${length} bytes`);
+  }
+  return Buffer.from(bytes).toString('hex');
+}
+
+function fromHex(value: unknown, field: string): number[] {
+  if (typeof value !== 'string' || !HEX_PATTERN.test(value)) {
+    throw new Error(`Partial proof ${field} must be lowercase hex`);
+  }
+  return Array.from(Buffer.from(value, 'hex'));
+}
+
+/**
+ * Encode a partial proof in canonical form
+ */
+export function encodePartialProof(partial: PartialProof): string {
+  if (!TRANSFER_ID_PATTERN.test(partial.transfer_id)) {
+    throw new Error(`Partial proof transfer_id "${partial.transfer_id}" is not "{sourceChain}_{nonce}_{logIndex}"`);
+  }
+  const messageHex = partial.message_hex.toLowerCase();
+  if (!HEX_PATTERN.test(messageHex)) {
+    throw new Error('Partial proof message_hex must be hex');
+  }
+
+  const signatures = partial.signatures
+    .map((sig) => ({
+      public_key: toHex(sig.public_key, 32, 'public_key'),
+      signature: toHex(sig.signature, 64, 'signature'),
+    }))
+    .sort((a, b) => (a.public_key < b.public_key ? -1 : a.public_key > b.public_key ? 1 : 0));
+  for (let i = 1; i < signatures.length; i++) {
+    if (signatures[i].public_key === signatures[i - 1].public_key) {
+      throw new Error(`Partial proof has two signatures from ${signatures[i].public_key}`);
+    }
+  }
+
+  return JSON.stringify({ transfer_id: partial.transfer_id, message_hex: messageHex, signatures });
+}
+
+/**
+ * Decode a partial proof, throwing unless the input is exactly its canonical encoding
+ */
+export function decodePartialProof(encoded: string): PartialProof {
+  let parsed: any;
+  try {
+    parsed = JSON.parse(encoded);
+  } catch {
+    throw new Error('Partial proof is not JSON');
+  }
+  if (
+    typeof parsed?.transfer_id !== 'string' ||
+    typeof parsed.message_hex !== 'string' ||
+    !Array.isArray(parsed.signatures)
+  ) {
+    throw new Error('Malformed partial proof');
+  }
+
+  const partial: PartialProof = {
+    transfer_id: parsed.transfer_id,
+    message_hex: parsed.message_hex, // case and digits are checked by re-encoding
+    signatures: parsed.signatures.map((sig: any) => ({
+      public_key: fromHex(sig?.public_key, 'public_key'),
+      signature: fromHex(sig?.signature, 'signature'),
+    })),
+  };
+  if (encodePartialProof(partial) !== encoded) {
+    throw new Error('Partial proof is not in canonical form');
+  }
+  return partial;
+}
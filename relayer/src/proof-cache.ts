@@ -0,0 +1,80 @@
+/**
+ * Proof Cache
+ *
+ * Deduplicates proof submissions across redundant relayers:
+ * - Remembers transfers this relayer already submitted or saw processed
+ * - Merges signatures for the same transfer, dropping duplicates per validator
+ */
+
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export interface ValidatorSignature {
+  public_key: number[];
+  signature: number[];
+}
+
+export class ProofCache {
+  private ttlMs: number;
+  private completed: Map<string, number> = new Map(); // transferId -> time marked
+  private signatures: Map<string, ValidatorSignature[]> = new Map();
+
+  constructor(ttlMs: number = 24 * 60 * 60 * 1000) {
+    this.ttlMs = ttlMs;
+  }
+
+  /**
+   * Transfer ID for a release proof, matching the contract's processed-proof key
+   */
+  static releaseTransferId(sourceChain: string, nonce: string | number, logIndex: number): string {
+    return `${sourceChain}_${nonce}_${logIndex}`;
+  }
+
+  /**
+   * Whether the transfer was already submitted or found processed on-chain
+   */
+  isCompleted(transferId: string): boolean {
+    this.prune();
+    return this.completed.has(transferId);
+  }
+
+  /**
+   * Mark a transfer as done so later duplicates are dropped
+   */
+  markCompleted(transferId: string): void {
+    this.completed.set(transferId, Date.now());
+    this.signatures.delete(transferId);
+  }
+
+  /**
+   * Merge signatures for a transfer, keeping one signature per validator key
+   * Returns the merged set
+   */
+  addSignatures(transferId: string, incoming: ValidatorSignature[]): ValidatorSignature[] {
+    const merged = this.signatures.get(transferId) || [];
+    const seen = new Set(merged.map((sig) => Buffer.from(sig.public_key).toString('hex')));
+
+    for (const sig of incoming) {
+      const key = Buffer.from(sig.public_key).toString('hex');
+      if (seen.has(key)) {
+        logger.debug('Dropping duplicate signature', { transferId, publicKey: key });
+        continue;
+      }
+      seen.add(key);
+      merged.push(sig);
+    }
+
+    this.signatures.set(transferId, merged);
+    return merged;
+  }
+
+  private prune(): void {
+    const cutoff = Date.now() - this.ttlMs;
+    for (const [transferId, markedAt] of this.completed) {
+      if (markedAt < cutoff) {
+        this.completed.delete(transferId);
+      }
+    }
+  }
+}
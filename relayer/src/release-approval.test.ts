@@ -0,0 +1,80 @@
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { BurnClaim } from './burn-evidence';
+import { ReleaseApprovals } from './release-approval';
+
+const THRESHOLD = 1_000_000_000_000n; // 1000 CSPR
+
+const claim = (amount: string): BurnClaim => ({
+  sourceTxHash: '0xabc',
+  logIndex: 0,
+  nonce: '7',
+  amount,
+  recipient: 'account-hash-' + '11'.repeat(32),
+  sourceSender: '0x' + '22'.repeat(20),
+  sourceBlock: 100,
+});
+
+const burnEvent = { nonce: '7', logIndex: 0, txHash: '0xabc' };
+
+describe('ReleaseApprovals', () => {
+  it('lets releases up to the threshold through', () => {
+    const approvals = new ReleaseApprovals(THRESHOLD);
+    expect(approvals.check('ethereum_7_0', claim(THRESHOLD.toString()), burnEvent)).toBe('approved');
+    expect(approvals.pending()).toEqual([]);
+  });
+
+  it('lets every release through when disabled', () => {
+    const approvals = new ReleaseApprovals(null);
+    expect(approvals.check('ethereum_7_0', claim('9'.repeat(30)), burnEvent)).toBe('approved');
+  });
+
+  it('queues a large release once until an operator approves it', () => {
+    const approvals = new ReleaseApprovals(THRESHOLD);
+    const large = claim('1000000000001');
+    expect(approvals.check('ethereum_7_0', large, burnEvent)).toBe('pending');
+    expect(approvals.check('ethereum_7_0', large, burnEvent)).toBe('pending');
+    expect(approvals.pending().map((record) => record.transferId)).toEqual(['ethereum_7_0']);
+    expect(approvals.pending()[0].burnEvent).toEqual(burnEvent);
+
+    approvals.approve('ethereum_7_0', 'alice');
+    expect(approvals.check('ethereum_7_0', large, burnEvent)).toBe('approved');
+    expect(approvals.pending()).toEqual([]);
+  });
+
+  it('never signs a rejected release', () => {
+    const approvals = new ReleaseApprovals(THRESHOLD);
+    approvals.check('ethereum_7_0', claim('2000000000000'), burnEvent);
+    const record = approvals.reject('ethereum_7_0', 'alice', 'unknown recipient');
+    expect(record).toMatchObject({ status: 'rejected', operator: 'alice', reason: 'unknown recipient' });
+    expect(approvals.check('ethereum_7_0', claim('2000000000000'), burnEvent)).toBe('rejected');
+  });
+
+  it('asks again when the release changes after approval', () => {
+    const approvals = new ReleaseApprovals(THRESHOLD);
+    approvals.check('ethereum_7_0', claim('2000000000000'), burnEvent);
+    approvals.approve('ethereum_7_0', 'alice');
+    expect(approvals.check('ethereum_7_0', claim('3000000000000'), burnEvent)).toBe('pending');
+  });
+
+  it('decides each release only once', () => {
+    const approvals = new ReleaseApprovals(THRESHOLD);
+    expect(() => approvals.approve('ethereum_7_0', 'alice')).toThrow('No release ethereum_7_0');
+    approvals.check('ethereum_7_0', claim('2000000000000'), burnEvent);
+    approvals.approve('ethereum_7_0', 'alice');
+    expect(() => approvals.reject('ethereum_7_0', 'bob', 'too late')).toThrow('already approved');
+  });
+
+  it('keeps the queue across restarts', () => {
+    const file = path.join(fs.mkdtempSync(path.join(os.tmpdir(), 'approvals-')), 'approvals.json');
+    const first = new ReleaseApprovals(THRESHOLD, file);
+    first.check('ethereum_7_0', claim('2000000000000'), burnEvent);
+    first.check('ethereum_8_0', claim('2000000000000'), { ...burnEvent, nonce: '8' });
+    first.approve('ethereum_8_0', 'alice');
+
+    const second = new ReleaseApprovals(THRESHOLD, file);
+    expect(second.pending().map((record) => record.transferId)).toEqual(['ethereum_7_0']);
+    expect(second.get('ethereum_8_0')?.status).toBe('approved');
+  });
+});
@@ -0,0 +1,151 @@
+/**
+ * Release Approval
+ *
+ * Holds releases above a configured amount for an operator, after the burn
+ * receipt confirmed them (see burn-evidence.ts) and before this relayer signs:
+ *
+ *   burn evidence ok -> amount <= threshold -> sign
+ *                    -> amount >  threshold -> pending -> approved -> sign
+ *                                                      |-> rejected (never signed)
+ *
+ * Decisions are bound to the exact claim; a release whose parameters change
+ * after approval goes back to pending. The queue is kept in memory and, when a
+ * path is configured, rewritten to a JSON file on every change so pending and
+ * decided releases survive restarts. Operators decide through the approval
+ * API (GET /api/approvals, POST /api/approvals/:id/approve|reject), directly
+ * or with `npm run approvals`.
+ */
+
+import * as fs from 'fs';
+import { BurnClaim } from './burn-evidence';
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export type ApprovalStatus = 'pending' | 'approved' | 'rejected';
+
+export interface ApprovalRecord {
+  transferId: string;
+  claim: BurnClaim; // as checked against the burn receipt
+  burnEvent: any; // AssetBurned event the release was requested by, replayed once decided
+  status: ApprovalStatus;
+  operator?: string; // who decided
+  reason?: string; // given with a rejection
+  requestedAt: number;
+  decidedAt?: number;
+}
+
+function sameClaim(a: BurnClaim, b: BurnClaim): boolean {
+  return (
+    a.sourceTxHash === b.sourceTxHash &&
+    a.logIndex === b.logIndex &&
+    a.nonce === b.nonce &&
+    a.amount === b.amount &&
+    a.recipient === b.recipient &&
+    a.sourceSender === b.sourceSender &&
+    a.sourceBlock === b.sourceBlock
+  );
+}
+
+export class ReleaseApprovals {
+  private records: Map<string, ApprovalRecord> = new Map();
+  private thresholdMotes: bigint | null;
+  private path: string;
+
+  /**
+   * thresholdMotes null disables approvals: every release is signed without one
+   */
+  constructor(thresholdMotes: bigint | null, path: string = '') {
+    this.thresholdMotes = thresholdMotes;
+    this.path = path;
+    if (path && fs.existsSync(path)) {
+      const stored = JSON.parse(fs.readFileSync(path, 'utf8')) as ApprovalRecord[];
+      for (const record of stored) {
+        this.records.set(record.transferId, record);
+      }
+      logger.info('Loaded release approvals', { path, pending: this.pending().length });
+    }
+  }
+
+  updateThreshold(thresholdMotes: bigint | null): void {
+    this.thresholdMotes = thresholdMotes;
+  }
+
+  /**
+   * Whether a release may be signed now; releases above the threshold are queued on first sight
+   */
+  check(transferId: string, claim: BurnClaim, burnEvent: any): ApprovalStatus {
+    if (this.thresholdMotes === null || BigInt(claim.amount) <= this.thresholdMotes) {
+      return 'approved';
+    }
+
+    const record = this.records.get(transferId);
+    if (record && sameClaim(record.claim, claim)) {
+      return record.status;
+    }
+    if (record) {
+      logger.warn('Release changed since it was queued for approval, asking again', {
+        transferId,
+        previous: record.claim,
+        claim,
+      });
+    }
+    this.records.set(transferId, { transferId, claim, burnEvent, status: 'pending', requestedAt: Date.now() });
+    this.save();
+    return 'pending';
+  }
+
+  approve(transferId: string, operator: string): ApprovalRecord {
+    return this.decide(transferId, 'approved', operator);
+  }
+
+  reject(transferId: string, operator: string, reason: string): ApprovalRecord {
+    return this.decide(transferId, 'rejected', operator, reason);
+  }
+
+  get(transferId: string): ApprovalRecord | undefined {
+    return this.records.get(transferId);
+  }
+
+  /**
+   * Releases waiting for an operator, oldest first
+   */
+  pending(): ApprovalRecord[] {
+    return Array.from(this.records.values())
+      .filter((record) => record.status === 'pending')
+      .sort((a, b) => a.requestedAt - b.requestedAt);
+  }
+
+  // Record a decision; only pending releases can be decided, and each only once
+  private decide(transferId: string, status: ApprovalStatus, operator: string, reason?: string): ApprovalRecord {
+    const record = this.records.get(transferId);
+    if (!record) {
+      throw new Error(`No release ${transferId} is waiting for approval`);
+    }
+    if (record.status !== 'pending') {
+      throw new Error(`Release ${transferId} was already ${record.status}`);
+    }
+
+    record.status = status;
+    record.operator = operator;
+    record.reason = reason;
+    record.decidedAt = Date.now();
+    this.save();
+    logger.info(`Release ${status} by operator`, { transferId, operator, reason, claim: record.claim });
+    return record;
+  }
+
+  // Write through a temporary file so a crash never leaves a truncated queue
+  private save(): void {
+    if (!this.path) {
+      return;
+    }
+    try {
+      const temporary = `${this.path}.tmp`;
+      fs.writeFileSync(temporary, JSON.stringify(Array.from(this.records.values())));
+      fs.renameSync(temporary, this.path);
+    } catch (error: any) {
+      logger.error('Failed to persist release approvals', { path: this.path, error: error.message });
+    }
+  }
+}
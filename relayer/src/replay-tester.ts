@@ -0,0 +1,140 @@
+/**
+ * Proof Replay Tester
+ *
+ * Re-runs an archive of historical release proofs through today's message
+ * format and acceptance rules, flagging any proof whose outcome changed.
+ * Run after touching message construction or verification:
+ *
+ *   npm run replay -- <archive.json> [--required N] [--max N]
+ */
+
+import * as fs from 'fs';
+import * as ed25519 from '@noble/ed25519';
+import { sha512 } from '@noble/hashes/sha512';
+import { createReleaseMessage } from './signature-utils';
+import { ValidatorSignature } from './proof-cache';
+
+ed25519.etc.sha512Sync = (...m) => sha512(ed25519.etc.concatBytes(...m));
+
+export interface ArchivedProof {
+  source_chain: string;
+  source_tx_hash: string;
+  amount: string;
+  recipient: string;
+  nonce: string;
+  log_index: number;
+  validator_signatures: ValidatorSignature[];
+  digest_version?: number; // absent in archives predating versioned digests (v1)
+  source_sender?: string;
+  memo?: string;
+  source_block?: number; // digest v4+
+  bridge_id?: string; // digest v5+
+  accepted: boolean;
+}
+
+export interface ReplayRules {
+  requiredSignatures: number;
+  maxSignatures: number;
+  validators?: Set<string>; // hex public keys; when set, other signers are ignored like on-chain
+}
+
+export interface ReplayResult {
+  proof: ArchivedProof;
+  validSignatures: number;
+  accepted: boolean;
+  reason?: string;
+}
+
+/**
+ * Replay one proof: rebuild its message, verify each signature, apply the rules
+ */
+export function replayProof(proof: ArchivedProof, rules: ReplayRules): ReplayResult {
+  const signatures = proof.validator_signatures;
+
+  if (signatures.length > rules.maxSignatures) {
+    return { proof, validSignatures: 0, accepted: false, reason: 'too many signatures' };
+  }
+
+  const message = createReleaseMessage(
+    proof.source_chain,
+    proof.source_tx_hash,
+    proof.amount,
+    proof.recipient,
+    proof.nonce,
+    proof.log_index,
+    proof.digest_version ?? 1,
+    proof.source_sender,
+    proof.memo,
+    proof.source_block,
+    proof.bridge_id
+  );
+
+  // Like the contract, a validator counts once, from its first valid signature
+  const seen = new Set<string>();
+  let validSignatures = 0;
+  for (const sig of signatures) {
+    const key = Buffer.from(sig.public_key).toString('hex');
+    if (seen.has(key) || (rules.validators && !rules.validators.has(key))) continue;
+
+    try {
+      if (ed25519.verify(Uint8Array.from(sig.signature), message, Uint8Array.from(sig.public_key))) {
+        seen.add(key);
+        validSignatures++;
+      }
+    } catch {
+      // Malformed key or signature counts as invalid
+    }
+  }
+
+  if (validSignatures < rules.requiredSignatures) {
+    return { proof, validSignatures, accepted: false, reason: 'insufficient valid signatures' };
+  }
+
+  return { proof, validSignatures, accepted: true };
+}
+
+/**
+ * Replay an archive and return the proofs whose outcome differs from the record
+ */
+export function findRegressions(proofs: ArchivedProof[], rules: ReplayRules): ReplayResult[] {
+  return proofs
+    .map((proof) => replayProof(proof, rules))
+    .filter((result) => result.accepted !== result.proof.accepted);
+}
+
+function parseArgs(argv: string[]): { path: string; rules: ReplayRules } {
+  const rules: ReplayRules = { requiredSignatures: 1, maxSignatures: 32 };
+  let path = '';
+
+  for (let i = 0; i < argv.length; i++) {
+    if (argv[i] === '--required') {
+      rules.requiredSignatures = parseInt(argv[++i]);
+    } else if (argv[i] === '--max') {
+      rules.maxSignatures = parseInt(argv[++i]);
+    } else {
+      path = argv[i];
+    }
+  }
+
+  if (!path) {
+    throw new Error('Usage: replay-tester <archive.json> [--required N] [--max N]');
+  }
+  return { path, rules };
+}
+
+if (require.main === module) {
+  const { path, rules } = parseArgs(process.argv.slice(2));
+  const proofs = JSON.parse(fs.readFileSync(path, 'utf8')) as ArchivedProof[];
+  const regressions = findRegressions(proofs, rules);
+
+  for (const result of regressions) {
+    console.log(
+      `nonce ${result.proof.nonce} log ${result.proof.log_index} (${result.proof.source_tx_hash}): ` +
+        `recorded ${result.proof.accepted ? 'accepted' : 'rejected'}, now ` +
+        `${result.accepted ? 'accepted' : `rejected (${result.reason})`}`
+    );
+  }
+
+  console.log(`Replayed ${proofs.length} proofs, ${regressions.length} changed outcome`);
+  process.exit(regressions.length > 0 ? 1 : 0);
+}
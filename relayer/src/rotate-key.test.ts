@@ -0,0 +1,87 @@
+import { CLPublicKey } from 'casper-js-sdk';
+import { CasperSigner, validatorSetDigest } from './signature-utils';
+import { planRotation, rotationProblems, signRotation } from './rotate-key';
+import { VaultConfigView } from './vault-config-check';
+
+const BRIDGE_ID = '0a1e7bf8e45328bdc7aa2c9a1fafeb7ec91b9f556807f829ef409cbc6f6432ab';
+
+const signer = (byte: string) => new CasperSigner(byte.repeat(32));
+const outgoing = [signer('01'), signer('02'), signer('03')];
+const incoming = [signer('04'), signer('05'), signer('06')];
+const keys = (signers: CasperSigner[]) => signers.map((s) => s.getPublicKeyHex());
+
+const vault: VaultConfigView = {
+  supportedChains: ['ethereum'],
+  digestVersion: 5,
+  previousDigestVersion: 4,
+  previousDigestDeadline: 0,
+  bridgeId: BRIDGE_ID,
+  tokens: {},
+  validators: outgoing.map((s) => CLPublicKey.fromEd25519(s.getPublicKey()).toAccountHashStr()),
+  requiredSignatures: 2,
+  maxSignatures: 32,
+};
+
+async function signAll(signers: CasperSigner[]) {
+  let plan = planRotation(BRIDGE_ID, '7', keys(incoming), 2);
+  for (const s of signers) {
+    plan = await signRotation(plan, s);
+  }
+  return plan;
+}
+
+describe('validatorSetDigest', () => {
+  it('matches the vault', () => {
+    // Same set and threshold as validator_set_digest_covers_keys_and_threshold in the contract tests
+    const set = ['01', '02', '03'].map((byte) => byte.repeat(32));
+    expect(validatorSetDigest(set, 2)).toBe('8fa80a74cdf3089fc24ed1a5f1c0bc311462ad45bdeff86f1299d8f157cfbed9');
+  });
+});
+
+describe('planRotation', () => {
+  it('signs over the sorted set and threshold', () => {
+    const set = keys(incoming);
+    const plan = planRotation(BRIDGE_ID, '7', [...set].reverse(), 2);
+    const sorted = [...set].sort();
+    expect(plan.validators).toEqual(sorted);
+    expect(Buffer.from(plan.message_hex, 'hex').toString()).toBe(
+      `ROTATE_SET|${BRIDGE_ID}|7|${validatorSetDigest(sorted, 2)}`
+    );
+  });
+
+  it('refuses sets the vault would reject', () => {
+    const set = keys(incoming);
+    expect(() => planRotation(BRIDGE_ID, '7', [set[0], set[0], set[1]], 2)).toThrow('twice');
+    expect(() => planRotation(BRIDGE_ID, '7', [...set, 'abcd'], 3)).toThrow('32-byte');
+    expect(() => planRotation(BRIDGE_ID, '7', [...set, keys(outgoing)[0]], 2)).toThrow('majority');
+    expect(() => planRotation(BRIDGE_ID, '7', set, 4)).toThrow('majority');
+  });
+});
+
+describe('rotation signing', () => {
+  it('adds proofs of possession only for keys in the new set', async () => {
+    const plan = await signAll([outgoing[0], incoming[0], incoming[0]]);
+    expect(plan.signatures).toHaveLength(2);
+    expect(plan.proofs_of_possession!.map((entry) => entry.public_key)).toEqual([incoming[0].getPublicKeyHex()]);
+  });
+
+  it('is ready once a quorum of the current set approved and every new key proved possession', async () => {
+    const plan = await signAll([outgoing[0], outgoing[1], ...incoming]);
+    expect(rotationProblems(plan, vault)).toEqual([]);
+  });
+
+  it('reports a short quorum and missing proofs', async () => {
+    const plan = await signAll([outgoing[0], ...incoming.slice(0, 2)]);
+    expect(rotationProblems(plan, vault)).toEqual([
+      '1 current validators approved, the vault needs 2',
+      `${incoming[2].getPublicKeyHex()} has no proof of possession`,
+    ]);
+  });
+
+  it('rejects a plan for another vault', async () => {
+    const plan = await signAll([outgoing[0], outgoing[1], ...incoming]);
+    expect(rotationProblems(plan, { ...vault, bridgeId: '11'.repeat(32) })).toEqual([
+      `plan is for bridge id ${BRIDGE_ID}, the vault's is ${'11'.repeat(32)}`,
+    ]);
+  });
+});
@@ -0,0 +1,265 @@
+/**
+ * Validator Key Rotation
+ *
+ * Generates a new Ed25519 signing key, proves possession of it, and submits
+ * rotate_validator_key from the current validator account, so the validator
+ * keeps its place in the set without a remove + add:
+ *
+ *   npm run rotate-key -- --bridge-id <id> --out <new-key-file>
+ *
+ * The bridge id is the vault's get_metadata bridge_id (hex of its contract hash).
+ * The script waits for the deploy to execute. Once it succeeds, point
+ * CASPER_PRIVATE_KEY_HEX at the new key and restart the relayer.
+ *
+ * Whole validator sets are rotated offline (rotate_validator_set): a
+ * coordinator plans the new set and threshold, every outgoing and incoming
+ * validator signs the plan on its own machine, and the coordinator submits:
+ *
+ *   npm run rotate-key -- plan --bridge-id <id> --admin-nonce <n> --validators <key,...> --required <k> --out set.json
+ *   npm run rotate-key -- sign --plan set.json --key <private-key-file>
+ *   npm run rotate-key -- submit --plan set.json
+ *
+ * sign adds the outgoing quorum's approval and, for keys in the new set,
+ * their proof of possession. submit checks both against the vault's current
+ * set before sending the plan as an admin bundle (see admin-bundle.ts).
+ */
+
+import * as fs from 'fs';
+import * as ed25519 from '@noble/ed25519';
+import { sha512 } from '@noble/hashes/sha512';
+import { CasperClient, CLPublicKey, CLValueBuilder, DeployUtil, RuntimeArgs } from 'casper-js-sdk';
+import { loadConfig } from './config';
+import { CasperSigner, RELEASE_DIGEST_VERSION } from './signature-utils';
+import { submitDeploy, waitForDeploy } from './deploy-tracker';
+import { AdminBundle, invalidSignatures, proposeBundle, signBundle, submitBundle } from './admin-bundle';
+import { VaultConfigView, readVaultConfig } from './vault-config-check';
+
+ed25519.etc.sha512Sync = (...m) => sha512(ed25519.etc.concatBytes(...m));
+
+/**
+ * Build the message the new key signs to prove possession
+ * MUST match the contract's rotate_validator_key: "ROTATE|{bridgeId}|{old account hash}"
+ */
+export function createRotationMessage(bridgeId: string, oldPublicKey: Uint8Array): Uint8Array {
+  const oldAccountHash = CLPublicKey.fromEd25519(oldPublicKey).toAccountHashStr();
+  return new TextEncoder().encode(`ROTATE|${bridgeId}|${oldAccountHash}`);
+}
+
+/**
+ * Build the message each key of a new validator set signs to prove possession
+ * MUST match the contract's rotate_validator_set: "JOIN|{bridgeId}|{account hash}"
+ */
+export function createPossessionMessage(bridgeId: string, publicKey: Uint8Array): Uint8Array {
+  const accountHash = CLPublicKey.fromEd25519(publicKey).toAccountHashStr();
+  return new TextEncoder().encode(`JOIN|${bridgeId}|${accountHash}`);
+}
+
+/**
+ * Plan a validator set rotation, refusing sets the vault would reject:
+ * distinct 32-byte keys and a threshold that is a majority of the new set
+ */
+export function planRotation(
+  bridgeId: string,
+  adminNonce: string,
+  validators: string[],
+  requiredSignatures: number
+): AdminBundle {
+  const keys = validators.map((key) => key.toLowerCase());
+  const malformed = keys.filter((key) => !/^[0-9a-f]{64}$/.test(key));
+  if (malformed.length > 0) {
+    throw new Error(`Not 32-byte hex Ed25519 public keys: ${malformed.join(', ')}`);
+  }
+  if (new Set(keys).size !== keys.length) {
+    throw new Error('The new validator set lists a key twice');
+  }
+  const majority = requiredSignatures * 2 > keys.length && requiredSignatures <= keys.length;
+  if (!Number.isInteger(requiredSignatures) || !majority) {
+    throw new Error(`--required must be a majority of the ${keys.length} new validators, got ${requiredSignatures}`);
+  }
+  const rotation = { validators: keys, requiredSignatures };
+  return proposeBundle('rotate-set', bridgeId, adminNonce, undefined, undefined, rotation);
+}
+
+/**
+ * Sign a rotation plan: approve it as an outgoing validator and, if the key is
+ * in the new set, prove possession of it
+ */
+export async function signRotation(bundle: AdminBundle, signer: CasperSigner): Promise<AdminBundle> {
+  const signed = await signBundle(bundle, signer);
+  const publicKey = signer.getPublicKeyHex();
+  if (!bundle.validators!.includes(publicKey)) {
+    return signed;
+  }
+
+  const proof = await signer.signMessage(createPossessionMessage(bundle.bridge_id, signer.getPublicKey()));
+  return {
+    ...signed,
+    proofs_of_possession: [
+      ...bundle.proofs_of_possession!.filter((entry) => entry.public_key !== publicKey),
+      { public_key: publicKey, signature: Buffer.from(proof).toString('hex') },
+    ],
+  };
+}
+
+/**
+ * Everything that would make the vault reject the rotation, empty when it is ready to submit
+ */
+export function rotationProblems(bundle: AdminBundle, vault: VaultConfigView): string[] {
+  const problems = invalidSignatures(bundle).map((key) => `approval by ${key} does not verify`);
+
+  const approvers = bundle.signatures.filter((entry) =>
+    vault.validators.includes(CLPublicKey.fromEd25519(Buffer.from(entry.public_key, 'hex')).toAccountHashStr())
+  );
+  if (approvers.length < vault.requiredSignatures) {
+    problems.push(`${approvers.length} current validators approved, the vault needs ${vault.requiredSignatures}`);
+  }
+  if (approvers.length > vault.maxSignatures) {
+    problems.push(`${approvers.length} approvals exceed the vault's max_signatures of ${vault.maxSignatures}`);
+  }
+  if (bundle.required_signatures! > vault.maxSignatures) {
+    problems.push(`threshold ${bundle.required_signatures} exceeds the vault's max_signatures ${vault.maxSignatures}`);
+  }
+  if (vault.bridgeId !== bundle.bridge_id) {
+    problems.push(`plan is for bridge id ${bundle.bridge_id}, the vault's is ${vault.bridgeId}`);
+  }
+
+  for (const key of bundle.validators!) {
+    const proof = bundle.proofs_of_possession!.find((entry) => entry.public_key === key);
+    let valid = false;
+    try {
+      const message = createPossessionMessage(bundle.bridge_id, Buffer.from(key, 'hex'));
+      valid = !!proof && ed25519.verify(proof.signature, message, key);
+    } catch {
+      // Reported below
+    }
+    if (!valid) {
+      problems.push(`${key} has ${proof ? 'an invalid' : 'no'} proof of possession`);
+    }
+  }
+  return problems;
+}
+
+function parseArgs(argv: string[]): { command: string; flags: Record<string, string> } {
+  const command = argv[0]?.startsWith('--') ? 'key' : argv[0];
+  const flags: Record<string, string> = {};
+  for (let i = command === 'key' ? 0 : 1; i < argv.length; i++) {
+    if (argv[i].startsWith('--')) {
+      flags[argv[i].slice(2)] = argv[++i];
+    }
+  }
+  return { command, flags };
+}
+
+function requireFlags(flags: Record<string, string>, names: string[], usage: string): void {
+  if (names.some((name) => !flags[name])) {
+    throw new Error(`Usage: rotate-key ${usage}`);
+  }
+}
+
+async function rotateKey(bridgeId: string, out: string): Promise<void> {
+  const config = loadConfig();
+  const oldSigner = new CasperSigner(config.casper.privateKeyHex);
+
+  // Persist the new key before submitting, so a successful rotation never loses it
+  const newPrivateKey = ed25519.utils.randomPrivateKey();
+  fs.writeFileSync(out, Buffer.from(newPrivateKey).toString('hex'), { mode: 0o600, flag: 'wx' });
+  const newSigner = new CasperSigner(Buffer.from(newPrivateKey).toString('hex'));
+
+  const proofOfPossession = await newSigner.signMessage(
+    createRotationMessage(bridgeId, oldSigner.getPublicKey())
+  );
+
+  const u8List = (bytes: Uint8Array) => CLValueBuilder.list(Array.from(bytes).map((b) => CLValueBuilder.u8(b)));
+
+  const session = DeployUtil.ExecutableDeployItem.newStoredContractByHash(
+    Uint8Array.from(Buffer.from(config.casper.vaultContract.replace('hash-', ''), 'hex')),
+    'rotate_validator_key',
+    RuntimeArgs.fromMap({
+      old_public_key: u8List(oldSigner.getPublicKey()),
+      new_public_key: u8List(newSigner.getPublicKey()),
+      proof_of_possession: u8List(proofOfPossession),
+    })
+  );
+
+  const deploy = DeployUtil.makeDeploy(
+    new DeployUtil.DeployParams(oldSigner.getPublicKeyCL(), config.casper.networkName, 1, 1800000),
+    session,
+    DeployUtil.standardPayment(3_000_000_000)
+  );
+
+  // The vault only lets the current validator account rotate its own key
+  const signedDeploy = DeployUtil.signDeploy(deploy, oldSigner.getKeyPair());
+  const client = new CasperClient(config.casper.rpcUrl);
+  const deployHash = await submitDeploy(client, signedDeploy);
+
+  console.log(`New key written to ${out}`);
+  console.log(`New public key: ${newSigner.getPublicKeyHex()}`);
+  console.log(`Rotation deploy: ${deployHash}`);
+
+  const outcome = await waitForDeploy(client, deployHash);
+  if (!outcome.success) {
+    throw outcome.error!;
+  }
+  console.log('Rotation executed');
+}
+
+async function main() {
+  const { command, flags } = parseArgs(process.argv.slice(2));
+  const readPlan = (): AdminBundle => JSON.parse(fs.readFileSync(flags.plan, 'utf8'));
+
+  if (command === 'key') {
+    requireFlags(flags, ['bridge-id', 'out'], '--bridge-id <id> --out <new-key-file>');
+    await rotateKey(flags['bridge-id'], flags.out);
+  } else if (command === 'plan') {
+    requireFlags(
+      flags,
+      ['bridge-id', 'admin-nonce', 'validators', 'required', 'out'],
+      'plan --bridge-id <id> --admin-nonce <n> --validators <key,key,...> --required <k> --out <file>'
+    );
+    const plan = planRotation(
+      flags['bridge-id'],
+      flags['admin-nonce'],
+      flags.validators.split(',').map((key) => key.trim()),
+      Number(flags.required)
+    );
+    fs.writeFileSync(flags.out, JSON.stringify(plan, null, 2), { flag: 'wx' });
+    console.log(`Rotation plan written to ${flags.out}; message ${plan.message_hex}`);
+  } else if (command === 'sign') {
+    requireFlags(flags, ['plan', 'key'], 'sign --plan <file> --key <private-key-file>');
+    const signer = new CasperSigner(fs.readFileSync(flags.key, 'utf8').trim());
+    const plan = await signRotation(readPlan(), signer);
+    fs.writeFileSync(flags.plan, JSON.stringify(plan, null, 2));
+    const incoming = plan.validators!.includes(signer.getPublicKeyHex()) ? ' with proof of possession' : '';
+    console.log(`Signed as ${signer.getPublicKeyHex()}${incoming} (${plan.signatures.length} approvals)`);
+  } else if (command === 'submit') {
+    requireFlags(flags, ['plan'], 'submit --plan <file>');
+    const config = loadConfig();
+    const plan = readPlan();
+    const vault = await readVaultConfig(new CasperClient(config.casper.rpcUrl), config.casper.vaultContract, {
+      chains: [],
+      digestVersion: RELEASE_DIGEST_VERSION,
+      bridgeId: plan.bridge_id,
+      tokens: {},
+    });
+    const problems = rotationProblems(plan, vault);
+    if (problems.length > 0) {
+      throw new Error(`Rotation plan is not ready:\n  - ${problems.join('\n  - ')}`);
+    }
+
+    // Approvals from keys outside the current set only take up room in the batch
+    const approvals = plan.signatures.filter((entry) =>
+      vault.validators.includes(CLPublicKey.fromEd25519(Buffer.from(entry.public_key, 'hex')).toAccountHashStr())
+    );
+    await submitBundle({ ...plan, signatures: approvals });
+    console.log('Point each new validator at its key and restart the relayers');
+  } else {
+    throw new Error('Usage: rotate-key [--bridge-id <id> --out <file> | plan | sign | submit] ...');
+  }
+}
+
+if (require.main === module) {
+  main().catch((error) => {
+    console.error('Key rotation failed:', error.message);
+    process.exit(1);
+  });
+}
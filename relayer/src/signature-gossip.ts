@@ -0,0 +1,183 @@
+/**
+ * Partial Proof Gossip
+ *
+ * Redundant relayers share the release signatures they collected, so a proof
+ * reaches quorum from whichever signers any relayer heard from. Each relayer
+ * POSTs its partial proofs to its configured peers:
+ *
+ *   POST {peer}/api/gossip/partial-proof
+ *   {"transfer_id":"{sourceChain}_{nonce}_{logIndex}","message_hex":"...","signatures":[...]}
+ *
+ * The body is the canonical encoding from partial-proof.ts; anything else is
+ * rejected, so other relayer implementations interoperate byte for byte.
+ * Gossip is one hop over a full mesh of configured peers: received partials
+ * are stored, not forwarded. Signatures are only kept if they come from a
+ * current vault validator and verify against the message they came with, and
+ * are only used for a proof whose own message is byte-identical. Anyone can
+ * sign a public message with a fresh key, so without the validator check a
+ * peer could pad proofs past max_signatures and make every release revert.
+ */
+
+import * as ed25519 from '@noble/ed25519';
+import { sha512 } from '@noble/hashes/sha512';
+import { Logger } from './logger';
+import { ValidatorSignature } from './proof-cache';
+import { ed25519AccountHash } from './signature-utils';
+import { PartialProof, encodePartialProof } from './partial-proof';
+
+ed25519.etc.sha512Sync = (...m) => sha512(ed25519.etc.concatBytes(...m));
+
+const logger = Logger.getInstance();
+
+export const GOSSIP_PATH = '/api/gossip/partial-proof';
+
+// Peers are asked in parallel; a slow one must not hold up submission
+const GOSSIP_TIMEOUT_MS = 5000;
+
+// The endpoint is unauthenticated, so bound what peers can make us store
+const MAX_SIGNATURES_PER_PARTIAL = 32;
+const MAX_STORED_PARTIALS = 10000;
+
+interface StoredPartial {
+  receivedAt: number;
+  signatures: Map<string, ValidatorSignature>; // public key hex -> signature
+}
+
+function verifies(signature: ValidatorSignature, message: Uint8Array): boolean {
+  try {
+    return ed25519.verify(Uint8Array.from(signature.signature), message, Uint8Array.from(signature.public_key));
+  } catch {
+    return false;
+  }
+}
+
+export class SignatureGossip {
+  private peers: string[];
+  private validators: Set<string> = new Set(); // account hashes; empty until the vault is read
+  private ttlMs: number;
+  private partials: Map<string, StoredPartial> = new Map(); // "{transferId}|{messageHex}" -> signatures
+
+  constructor(peers: string[], ttlMs: number = 24 * 60 * 60 * 1000) {
+    this.peers = peers;
+    this.ttlMs = ttlMs;
+  }
+
+  updatePeers(peers: string[]): void {
+    this.peers = peers;
+  }
+
+  /**
+   * Replace the vault validator set; signatures from other keys are dropped on receipt
+   */
+  updateValidators(validators: string[]): void {
+    this.validators = new Set(validators.map((validator) => validator.toLowerCase()));
+  }
+
+  /**
+   * Store a partial proof from a peer, returning how many signatures were new and valid
+   */
+  receive(partial: PartialProof): number {
+    if (
+      typeof partial?.transfer_id !== 'string' ||
+      typeof partial.message_hex !== 'string' ||
+      !/^([0-9a-f]{2})+$/i.test(partial.message_hex) ||
+      !Array.isArray(partial.signatures)
+    ) {
+      throw new Error('Malformed partial proof');
+    }
+
+    const message = Uint8Array.from(Buffer.from(partial.message_hex, 'hex'));
+    const storeKey = `${partial.transfer_id}|${partial.message_hex.toLowerCase()}`;
+    this.prune();
+    const stored = this.partials.get(storeKey) ?? { receivedAt: Date.now(), signatures: new Map() };
+    if (!this.partials.has(storeKey) && this.partials.size >= MAX_STORED_PARTIALS) {
+      throw new Error('Partial proof store is full');
+    }
+
+    let added = 0;
+    for (const signature of partial.signatures) {
+      if (!Array.isArray(signature?.public_key) || !Array.isArray(signature?.signature)) {
+        continue;
+      }
+      const key = Buffer.from(signature.public_key).toString('hex');
+      if (stored.signatures.size >= MAX_SIGNATURES_PER_PARTIAL) {
+        break;
+      }
+      if (stored.signatures.has(key) || !this.validators.has(ed25519AccountHash(signature.public_key))) {
+        continue;
+      }
+      if (!verifies(signature, message)) {
+        continue;
+      }
+      stored.signatures.set(key, { public_key: signature.public_key, signature: signature.signature });
+      added++;
+    }
+
+    if (stored.signatures.size > 0) {
+      this.partials.set(storeKey, stored);
+    }
+    return added;
+  }
+
+  /**
+   * Signatures peers sent for this transfer over exactly this message
+   */
+  signaturesFor(transferId: string, message: Uint8Array): ValidatorSignature[] {
+    const stored = this.partials.get(`${transferId}|${Buffer.from(message).toString('hex')}`);
+    // Validators removed since the signature arrived no longer count
+    return stored
+      ? Array.from(stored.signatures.values()).filter((sig) => this.validators.has(ed25519AccountHash(sig.public_key)))
+      : [];
+  }
+
+  /**
+   * Drop everything stored for a transfer once it is done
+   */
+  forget(transferId: string): void {
+    for (const storeKey of this.partials.keys()) {
+      if (storeKey.startsWith(`${transferId}|`)) {
+        this.partials.delete(storeKey);
+      }
+    }
+  }
+
+  /**
+   * Send a partial proof to every peer; unreachable peers are logged and skipped
+   */
+  async broadcast(partial: PartialProof): Promise<void> {
+    let body: string;
+    try {
+      body = encodePartialProof(partial);
+    } catch (error: any) {
+      logger.error('Cannot encode partial proof, not gossiping it', { transferId: partial.transfer_id, error: error.message });
+      return;
+    }
+
+    await Promise.allSettled(
+      this.peers.map(async (peer) => {
+        try {
+          const response = await fetch(`${peer.replace(/\/$/, '')}${GOSSIP_PATH}`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body,
+            signal: AbortSignal.timeout(GOSSIP_TIMEOUT_MS),
+          });
+          if (!response.ok) {
+            throw new Error(`HTTP ${response.status}`);
+          }
+        } catch (error: any) {
+          logger.warn('Failed to gossip partial proof', { peer, transferId: partial.transfer_id, error: error.message });
+        }
+      })
+    );
+  }
+
+  private prune(): void {
+    const cutoff = Date.now() - this.ttlMs;
+    for (const [storeKey, stored] of this.partials) {
+      if (stored.receivedAt < cutoff) {
+        this.partials.delete(storeKey);
+      }
+    }
+  }
+}
@@ -9,7 +9,10 @@
 import { ethers } from 'ethers';
 import * as ed25519 from '@noble/ed25519';
 import { sha512 } from '@noble/hashes/sha512';
+import { blake2b } from '@noble/hashes/blake2b';
+import { CLPublicKey } from 'casper-js-sdk';
 import { Logger } from './logger';
+import { motesToCspr } from './units';
 
 // Setup sha512 for ed25519 (required in Node.js)
 ed25519.etc.sha512Sync = (...m) => sha512(ed25519.etc.concatBytes(...m));
@@ -89,6 +92,248 @@ export class EthereumSigner {
   }
 }
 
+// Release message format produced by default; the vault reports its active version in get_metadata
+export const RELEASE_DIGEST_VERSION = 5;
+
+/**
+ * Bridge id of a vault, the hex of its contract hash ("hash-..."), as v5 release messages carry it
+ */
+export function vaultBridgeId(vaultContract: string): string {
+  return vaultContract.replace(/^hash-/, '').toLowerCase();
+}
+
+/**
+ * Build the release message validators sign, without needing a signing key
+ * Shared by CasperSigner and the proof replay tester
+ *
+ * v1: "{sourceChain}|{sourceTxHash}|{amount}|{nonce}|{logIndex}" + recipient
+ * v2: version byte + v1 message
+ * v3: v2 message + "|{sourceSender}|{memo}", absent fields as empty strings
+ * v4: v3 message + "|{sourceBlock}", the source chain block of the burn
+ * v5: v4 message + "|{bridgeId}", the vault the proof is for (see vaultBridgeId)
+ * The recipient must be the formatted account hash ("account-hash-...").
+ */
+export function createReleaseMessage(
+  sourceChain: string,
+  sourceTxHash: string,
+  amount: string,
+  recipient: string,
+  nonce: string,
+  logIndex: number,
+  digestVersion: number = RELEASE_DIGEST_VERSION,
+  sourceSender?: string,
+  memo?: string,
+  sourceBlock?: number,
+  bridgeId?: string
+): Uint8Array {
+  // Match the Rust contract format
+  const message = `${sourceChain}|${sourceTxHash}|${amount}|${nonce}|${logIndex}`;
+  let metadata = digestVersion >= 3 ? `|${sourceSender ?? ''}|${memo ?? ''}` : '';
+  if (digestVersion >= 4) {
+    metadata += `|${sourceBlock ?? 0}`;
+  }
+  if (digestVersion >= 5) {
+    if (!bridgeId) {
+      throw new Error(`Release digest v${digestVersion} needs the vault's bridge id`);
+    }
+    metadata += `|${bridgeId}`;
+  }
+
+  const prefix = digestVersion >= 2 ? Uint8Array.from([digestVersion]) : new Uint8Array(0);
+  const messageBytes = new TextEncoder().encode(message);
+  const recipientBytes = new TextEncoder().encode(recipient);
+  const metadataBytes = new TextEncoder().encode(metadata);
+
+  const combined = new Uint8Array(
+    prefix.length + messageBytes.length + recipientBytes.length + metadataBytes.length
+  );
+  combined.set(prefix, 0);
+  combined.set(messageBytes, prefix.length);
+  combined.set(recipientBytes, prefix.length + messageBytes.length);
+  combined.set(metadataBytes, prefix.length + messageBytes.length + recipientBytes.length);
+
+  return combined;
+}
+
+/**
+ * Human-readable summary of a release proof, for wallets and signer tooling
+ * that show a validator what it is signing before it signs
+ */
+export interface ReleasePreview {
+  lines: string[]; // "Label: value" rows in display order
+  messageHex: string; // exact bytes the signature covers
+}
+
+const formatMotes = (motes: string): string => `${motesToCspr(motes)} CSPR`;
+
+/**
+ * Render the fields of createReleaseMessage alongside the message it builds
+ * Relayer and validator fees are vault config deducted from the amount, and
+ * release proofs carry no expiry, so neither appears in the signed message
+ */
+export function describeReleaseMessage(
+  sourceChain: string,
+  sourceTxHash: string,
+  amount: string,
+  recipient: string,
+  nonce: string,
+  logIndex: number,
+  digestVersion: number = RELEASE_DIGEST_VERSION,
+  sourceSender?: string,
+  memo?: string,
+  sourceBlock?: number,
+  bridgeId?: string
+): ReleasePreview {
+  const message = createReleaseMessage(
+    sourceChain, sourceTxHash, amount, recipient, nonce, logIndex, digestVersion, sourceSender, memo, sourceBlock, bridgeId
+  );
+
+  const lines = [
+    `Action: Release CSPR on Casper`,
+    `Amount: ${formatMotes(amount)} (${amount} motes, before vault fees)`,
+    `Recipient: ${recipient}`,
+    `Source: ${sourceChain} tx ${sourceTxHash} (log ${logIndex})`,
+    `Nonce: ${nonce}`,
+  ];
+  if (digestVersion >= 3) {
+    lines.push(`Source sender: ${sourceSender || '(none)'}`);
+    lines.push(`Memo: ${memo || '(none)'}`);
+  }
+  if (digestVersion >= 4) {
+    lines.push(`Source block: ${sourceBlock ?? 0}`);
+  }
+  if (digestVersion >= 5) {
+    lines.push(`Vault: ${bridgeId}`);
+  }
+  lines.push(`Digest version: ${digestVersion}`);
+
+  return { lines, messageHex: Buffer.from(message).toString('hex') };
+}
+
+/**
+ * Build the message validators sign to authorize a vault admin action,
+ * e.g. pause_with_signatures signs action "PAUSE"
+ *
+ * Format: "{action}|{bridgeId}|{adminNonce}", nonce from get_admin_nonce
+ */
+export function createAdminMessage(action: string, bridgeId: string, adminNonce: string): Uint8Array {
+  return new TextEncoder().encode(`${action}|${bridgeId}|${adminNonce}`);
+}
+
+/**
+ * Build the message validators sign to approve the vault's pending upgrade
+ * proposal (approve_upgrade)
+ *
+ * Format: "UPGRADE|{bridgeId}|{adminNonce}|{proposalHash}", hash as hex blake2b-256
+ * of the serialized proposal returned by get_upgrade_proposal
+ */
+export function createUpgradeMessage(bridgeId: string, adminNonce: string, proposalHash: string): Uint8Array {
+  return new TextEncoder().encode(
+    `UPGRADE|${bridgeId}|${adminNonce}|${proposalHash.replace(/^0x/, '').toLowerCase()}`
+  );
+}
+
+/**
+ * Build the message the new vault's validators sign to let it take over the
+ * previous vault's funds and state (import_from_previous)
+ *
+ * Format: "MIGRATE|{bridgeId}|{adminNonce}|{previousVault}", the previous vault as the
+ * hex of its contract hash
+ */
+export function createMigrationMessage(bridgeId: string, adminNonce: string, previousVault: string): Uint8Array {
+  return new TextEncoder().encode(
+    `MIGRATE|${bridgeId}|${adminNonce}|${previousVault.replace(/^(hash-|0x)/, '').toLowerCase()}`
+  );
+}
+
+/**
+ * Digest of a validator set and its threshold (rotate_validator_set): hex
+ * blake2b-256 of the members' 32-byte Ed25519 public keys in the given order,
+ * which must be ascending, then requiredSignatures as u32 big-endian
+ */
+export function validatorSetDigest(publicKeys: string[], requiredSignatures: number): string {
+  const threshold = Buffer.alloc(4);
+  threshold.writeUInt32BE(requiredSignatures);
+  const preimage = Buffer.concat([...publicKeys.map((key) => Buffer.from(key, 'hex')), threshold]);
+  return Buffer.from(blake2b(preimage, { dkLen: 32 })).toString('hex');
+}
+
+/**
+ * Build the message the outgoing validators sign to hand the vault to a new
+ * validator set (rotate_validator_set)
+ *
+ * Format: "ROTATE_SET|{bridgeId}|{adminNonce}|{validatorSetDigest}"
+ */
+export function createValidatorSetMessage(bridgeId: string, adminNonce: string, setDigest: string): Uint8Array {
+  return new TextEncoder().encode(`ROTATE_SET|${bridgeId}|${adminNonce}|${setDigest}`);
+}
+
+/**
+ * Build the message validators sign to settle a lock's escrow:
+ * action "DELIVERED" once the destination minted it (confirm_delivery),
+ * "REFUND" to return it to the sender (refund_lock)
+ *
+ * Format: "{action}|{bridgeId}|{nonce}"
+ */
+export function createEscrowMessage(action: 'DELIVERED' | 'REFUND', bridgeId: string, nonce: string): Uint8Array {
+  return new TextEncoder().encode(`${action}|${bridgeId}|${nonce}`);
+}
+
+/**
+ * Build the heartbeat message validators sign to attest a source chain's
+ * latest finalized block (attest_chain_head)
+ *
+ * Format: "HEAD|{bridgeId}|{chain}|{blockNumber}"
+ */
+export function createChainHeadMessage(bridgeId: string, chain: string, blockNumber: string): Uint8Array {
+  return new TextEncoder().encode(`HEAD|${bridgeId}|${chain}|${blockNumber}`);
+}
+
+/**
+ * Build the message validators sign to checkpoint a finalized source chain
+ * block on Casper (submit_checkpoint)
+ *
+ * Format: "CHECKPOINT|{bridgeId}|{chain}|{blockNumber}|{blockHash}"
+ */
+export function createCheckpointMessage(
+  bridgeId: string,
+  chain: string,
+  blockNumber: string,
+  blockHash: string
+): Uint8Array {
+  return new TextEncoder().encode(`CHECKPOINT|${bridgeId}|${chain}|${blockNumber}|${blockHash}`);
+}
+
+/**
+ * Build the message validators of a previous vault sign to attest its
+ * export_config snapshot for a replacement vault (init_from_snapshot)
+ *
+ * Format: "RESTORE|{bridgeId}|{snapshotHash}", bridgeId of the previous vault and hash as
+ * hex blake2b-256 of the snapshot bytes; a quorum of the previous vault's current validators must sign
+ */
+export function createSnapshotMessage(bridgeId: string, snapshotHash: string): Uint8Array {
+  return new TextEncoder().encode(`RESTORE|${bridgeId}|${snapshotHash.replace(/^0x/, '').toLowerCase()}`);
+}
+
+/**
+ * Normalize a Casper recipient (public key hex or "account-hash-...") to the
+ * formatted account hash the contract uses in release messages
+ */
+export function toAccountHashString(recipient: string): string {
+  if (recipient.startsWith('account-hash-')) {
+    return recipient.toLowerCase();
+  }
+  return CLPublicKey.fromHex(recipient).toAccountHashStr();
+}
+
+/**
+ * Casper account hash of a raw Ed25519 public key: blake2b-256("ed25519" || 0 || key)
+ */
+export function ed25519AccountHash(publicKey: Uint8Array | number[]): string {
+  const preimage = Buffer.concat([Buffer.from('ed25519'), Buffer.from([0]), Buffer.from(publicKey)]);
+  return `account-hash-${Buffer.from(blake2b(preimage, { dkLen: 32 })).toString('hex')}`;
+}
+
 /**
  * Casper Signature Generation (Ed25519)
  *
@@ -113,28 +358,38 @@ export class CasperSigner {
 
   /**
    * Create message for Casper signatures
-   * MUST match the contract's get_message_hash function!
+   * MUST match the contract's release_message function!
+   *
+   * Contract format: see createReleaseMessage
    *
-   * Contract format: "{sourceChain}|{sourceTxHash}|{amount}|{nonce}" + debug-formatted recipient
+   * The log index tells apart several burns emitted by the same Ethereum transaction.
    */
   createMessage(
     sourceChain: string,
     sourceTxHash: string,
     amount: string,
     recipient: string,
-    nonce: string
+    nonce: string,
+    logIndex: number,
+    digestVersion: number = RELEASE_DIGEST_VERSION,
+    sourceSender?: string,
+    memo?: string,
+    sourceBlock?: number,
+    bridgeId?: string
   ): Uint8Array {
-    // Match the Rust contract format
-    const message = `${sourceChain}|${sourceTxHash}|${amount}|${nonce}`;
-
-    // Convert to bytes
-    let messageBytes = new TextEncoder().encode(message);
-
-    // Append recipient (in Rust, we use Debug format - for now just append as-is)
-    const recipientBytes = new TextEncoder().encode(recipient);
-    const combined = new Uint8Array(messageBytes.length + recipientBytes.length);
-    combined.set(messageBytes, 0);
-    combined.set(recipientBytes, messageBytes.length);
+    const combined = createReleaseMessage(
+      sourceChain,
+      sourceTxHash,
+      amount,
+      recipient,
+      nonce,
+      logIndex,
+      digestVersion,
+      sourceSender,
+      memo,
+      sourceBlock,
+      bridgeId
+    );
 
     logger.debug('Created Casper message', {
       sourceChain,
@@ -142,6 +397,8 @@ export class CasperSigner {
       amount,
       recipient,
       nonce,
+      logIndex,
+      digestVersion,
       messageHex: this.bytesToHex(combined)
     });
 
@@ -222,6 +479,6 @@ export class CasperSigner {
  *
  * // For Casper (Ed25519):
  * const casperSigner = new CasperSigner(process.env.CASPER_PRIVATE_KEY_HEX!);
- * const message = casperSigner.createMessage('ethereum', '0xdef...', '1000000', 'account-hash-...', '42');
+ * const message = casperSigner.createMessage('ethereum', '0xdef...', '1000000', 'account-hash-...', '42', 0);
  * const signature = await casperSigner.signMessage(message);
  */
@@ -0,0 +1,176 @@
+/**
+ * Transfer Store
+ *
+ * Tracks each bridge transfer this relayer sees through its lifecycle:
+ *
+ *   locked -> attested (k of N signatures) -> submitted -> released
+ *                                                |-> failed (retried back to attested/submitted)
+ *
+ * Casper locks are keyed by their deploy hash, Ethereum burns by
+ * `${sourceChain}_${nonce}_${logIndex}` as in the proof cache. Records are kept in memory
+ * and, when a path is configured, rewritten to a JSON file on every change
+ * so history survives restarts. Queried through GET /transfers.
+ */
+
+import * as fs from 'fs';
+import { Logger } from './logger';
+
+const logger = Logger.getInstance();
+
+export type TransferStatus = 'locked' | 'attested' | 'submitted' | 'released' | 'failed';
+
+export const TRANSFER_STATUSES: TransferStatus[] = ['locked', 'attested', 'submitted', 'released', 'failed'];
+
+export interface TransferRecord {
+  id: string;
+  sourceChain: string;
+  destinationChain: string;
+  sourceTxHash: string;
+  sender: string;
+  recipient: string;
+  amount: string; // source chain base units
+  status: TransferStatus;
+  signatures: number; // collected so far
+  requiredSignatures: number; // vault threshold when attested, 0 if unknown
+  destinationTxHash?: string;
+  error?: string; // last failure
+  history: Array<{ status: TransferStatus; at: number }>;
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface TransferQuery {
+  user?: string; // sender or recipient
+  status?: TransferStatus;
+  chain?: string; // source or destination chain
+  limit?: number;
+}
+
+// Position of each status in the lifecycle; a record only moves forward, except out of failed
+const STAGE: Record<TransferStatus, number> = { locked: 0, attested: 1, submitted: 2, released: 3, failed: 3 };
+
+// Most records returned by one query
+const MAX_QUERY_LIMIT = 500;
+
+export class TransferStore {
+  private records: Map<string, TransferRecord> = new Map();
+  private path: string;
+
+  constructor(path: string = '') {
+    this.path = path;
+    if (path && fs.existsSync(path)) {
+      const stored = JSON.parse(fs.readFileSync(path, 'utf8')) as TransferRecord[];
+      for (const record of stored) {
+        this.records.set(record.id, record);
+      }
+      logger.info('Loaded transfer history', { path, transfers: this.records.size });
+    }
+  }
+
+  /**
+   * Record a lock or burn seen on its source chain; a transfer seen again keeps its progress
+   */
+  locked(transfer: Omit<TransferRecord, 'status' | 'signatures' | 'requiredSignatures' | 'history' | 'createdAt' | 'updatedAt'>): void {
+    if (this.records.has(transfer.id)) {
+      return;
+    }
+    const now = Date.now();
+    this.records.set(transfer.id, {
+      ...transfer,
+      status: 'locked',
+      signatures: 0,
+      requiredSignatures: 0,
+      history: [{ status: 'locked', at: now }],
+      createdAt: now,
+      updatedAt: now,
+    });
+    this.save();
+  }
+
+  attested(id: string, signatures: number, requiredSignatures: number): void {
+    this.move(id, 'attested', (record) => {
+      record.signatures = signatures;
+      record.requiredSignatures = requiredSignatures;
+    });
+  }
+
+  submitted(id: string, destinationTxHash: string): void {
+    this.move(id, 'submitted', (record) => {
+      record.destinationTxHash = destinationTxHash;
+    });
+  }
+
+  released(id: string, destinationTxHash?: string): void {
+    this.move(id, 'released', (record) => {
+      record.destinationTxHash = destinationTxHash ?? record.destinationTxHash;
+    });
+  }
+
+  failed(id: string, error: string): void {
+    this.move(id, 'failed', (record) => {
+      record.error = error;
+    });
+  }
+
+  get(id: string): TransferRecord | undefined {
+    return this.records.get(id);
+  }
+
+  /**
+   * Matching transfers, most recently updated first
+   */
+  query(filter: TransferQuery): TransferRecord[] {
+    const user = filter.user?.toLowerCase();
+    const limit = Math.min(filter.limit ?? 100, MAX_QUERY_LIMIT);
+    return Array.from(this.records.values())
+      .filter((record) => !user || record.sender.toLowerCase() === user || record.recipient.toLowerCase() === user)
+      .filter((record) => !filter.status || record.status === filter.status)
+      .filter((record) => !filter.chain || record.sourceChain === filter.chain || record.destinationChain === filter.chain)
+      .sort((a, b) => b.updatedAt - a.updatedAt)
+      .slice(0, limit);
+  }
+
+  /**
+   * Transfers not released yet, failed ones included, that have not moved for maxAgeMs
+   */
+  stuck(maxAgeMs: number, now: number = Date.now()): TransferRecord[] {
+    return Array.from(this.records.values()).filter(
+      (record) => record.status !== 'released' && record.updatedAt < now - maxAgeMs
+    );
+  }
+
+  // Apply a transition, ignoring unknown transfers and moves backwards in the lifecycle
+  private move(id: string, status: TransferStatus, update: (record: TransferRecord) => void): void {
+    const record = this.records.get(id);
+    if (!record || record.status === 'released') {
+      return;
+    }
+    const retrying = record.status === 'failed' && status !== 'failed';
+    if (!retrying && STAGE[status] < STAGE[record.status]) {
+      logger.debug('Ignoring out-of-order transfer transition', { id, from: record.status, to: status });
+      return;
+    }
+
+    update(record);
+    if (record.status !== status) {
+      record.history.push({ status, at: Date.now() });
+    }
+    record.status = status;
+    record.updatedAt = Date.now();
+    this.save();
+  }
+
+  // Write through a temporary file so a crash never leaves a truncated history
+  private save(): void {
+    if (!this.path) {
+      return;
+    }
+    try {
+      const temporary = `${this.path}.tmp`;
+      fs.writeFileSync(temporary, JSON.stringify(Array.from(this.records.values())));
+      fs.renameSync(temporary, this.path);
+    } catch (error: any) {
+      logger.error('Failed to persist transfer history', { path: this.path, error: error.message });
+    }
+  }
+}
@@ -0,0 +1,68 @@
+import { convertDecimals, csprToMotes, formatUnits, parseAmount, parseUnits } from './units';
+
+describe('convertDecimals', () => {
+  // 1.5 motes expressed in wei-scale (18 decimals) units
+  const oneAndAHalfMotes = 1_500_000_000n;
+
+  it('rescales without loss when the value divides evenly', () => {
+    expect(convertDecimals(2_000_000_000n, 18, 9)).toBe(2n);
+    expect(convertDecimals(2n, 9, 18)).toBe(2_000_000_000n);
+  });
+
+  it('rejects lost precision in exact mode', () => {
+    expect(() => convertDecimals(oneAndAHalfMotes, 18, 9)).toThrow(RangeError);
+  });
+
+  it('rounds down in floor mode', () => {
+    expect(convertDecimals(oneAndAHalfMotes, 18, 9, 'floor')).toBe(1n);
+    expect(convertDecimals(999_999_999n, 18, 9, 'floor')).toBe(0n);
+  });
+
+  it('rounds up in ceil mode', () => {
+    expect(convertDecimals(oneAndAHalfMotes, 18, 9, 'ceil')).toBe(2n);
+    expect(convertDecimals(1n, 18, 9, 'ceil')).toBe(1n);
+  });
+
+  it('rounds halves up in half-up mode', () => {
+    expect(convertDecimals(oneAndAHalfMotes, 18, 9, 'half-up')).toBe(2n);
+    expect(convertDecimals(1_499_999_999n, 18, 9, 'half-up')).toBe(1n);
+  });
+});
+
+describe('parseUnits', () => {
+  it('parses whole and fractional amounts', () => {
+    expect(parseUnits('10.5', 9)).toBe(10_500_000_000n);
+    expect(parseUnits('.5', 9)).toBe(500_000_000n);
+    expect(parseUnits('10.', 9)).toBe(10_000_000_000n);
+    expect(csprToMotes('0.000000001')).toBe(1n);
+  });
+
+  it('rejects sub-mote input', () => {
+    expect(() => parseUnits('0.0000000001', 9)).toThrow(RangeError);
+    expect(() => csprToMotes('1.0000000005')).toThrow(RangeError);
+    expect(csprToMotes('1.0000000005', 'floor')).toBe(1_000_000_000n);
+  });
+
+  it('rejects malformed input', () => {
+    for (const input of ['', '-1', '1,000', '1e9', '1.2.3']) {
+      expect(() => parseUnits(input, 9)).toThrow(SyntaxError);
+    }
+  });
+});
+
+describe('formatUnits', () => {
+  it('trims trailing zeros or rounds to fixed fraction digits', () => {
+    expect(formatUnits(10_500_000_000n, 9)).toBe('10.5');
+    expect(formatUnits(10_000_000_000n, 9)).toBe('10');
+    expect(formatUnits(1_005_000_000n, 9, 2, 'half-up')).toBe('1.01');
+    expect(formatUnits(-1_500_000_000n, 9)).toBe('-1.5');
+  });
+});
+
+describe('parseAmount', () => {
+  it('reads the symbol from the token table', () => {
+    expect(parseAmount('250 USDC', { USDC: 6 })).toEqual({ symbol: 'USDC', decimals: 6, units: 250_000_000n });
+    expect(parseAmount('1.5', undefined, 'cspr').units).toBe(1_500_000_000n);
+    expect(() => parseAmount('1 DOGE')).toThrow(RangeError);
+  });
+});
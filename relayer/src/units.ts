@@ -0,0 +1,139 @@
+/**
+ * Amount Units
+ *
+ * Converts between integer base units (motes, wei, token units) and decimal
+ * strings without going through floating point or the host locale:
+ *
+ *   parseUnits('10.5', CSPR_DECIMALS)        // 10500000000n motes
+ *   formatUnits(10500000000n, CSPR_DECIMALS) // "10.5"
+ *   parseAmount('250 USDC', { USDC: 6 })     // { symbol: 'USDC', decimals: 6, units: 250000000n }
+ *
+ * Input always uses "." as the decimal separator; digit grouping such as
+ * "1,000" is rejected rather than guessed. Every lossy step takes an
+ * explicit rounding mode, where 'exact' throws instead of dropping digits.
+ */
+
+export const CSPR_DECIMALS = 9;
+export const WCSPR_DECIMALS = 18; // wCSPR on Ethereum is a standard 18-decimal ERC20
+
+// Units the parsers recognise without a custom table, keyed by upper-case symbol
+export const DEFAULT_TOKEN_DECIMALS: Record<string, number> = {
+  CSPR: CSPR_DECIMALS,
+  MOTES: 0,
+  WCSPR: WCSPR_DECIMALS,
+};
+
+export type Rounding = 'exact' | 'floor' | 'ceil' | 'half-up';
+
+export interface ParsedAmount {
+  symbol: string; // upper-case, as found in the token table
+  decimals: number;
+  units: bigint; // amount in base units
+}
+
+const DECIMAL_PATTERN = /^(\d+)(?:\.(\d*))?$|^\.(\d+)$/;
+
+// Divide a non-negative value by 10^digits under the given rounding mode
+function shiftDown(value: bigint, digits: number, rounding: Rounding): bigint {
+  if (digits <= 0) {
+    return value * 10n ** BigInt(-digits);
+  }
+  const divisor = 10n ** BigInt(digits);
+  const quotient = value / divisor;
+  const remainder = value % divisor;
+  if (remainder === 0n) {
+    return quotient;
+  }
+  switch (rounding) {
+    case 'exact':
+      throw new RangeError('Amount has more precision than the target unit allows');
+    case 'floor':
+      return quotient;
+    case 'ceil':
+      return quotient + 1n;
+    case 'half-up':
+      return remainder * 2n >= divisor ? quotient + 1n : quotient;
+  }
+}
+
+/**
+ * Parse a non-negative decimal string ("10.5", "0.000000001") into base units
+ */
+export function parseUnits(value: string, decimals: number, rounding: Rounding = 'exact'): bigint {
+  const match = DECIMAL_PATTERN.exec(value.trim());
+  if (!match) {
+    throw new SyntaxError(`Invalid amount "${value}": expected digits with an optional "." fraction`);
+  }
+  const whole = match[1] ?? '0';
+  const fraction = match[2] ?? match[3] ?? '';
+  const scaled = BigInt(whole + fraction);
+  return shiftDown(scaled * 10n ** BigInt(decimals), fraction.length, rounding);
+}
+
+/**
+ * Render base units as a decimal string with trailing zeros trimmed,
+ * or with exactly fractionDigits digits (rounded) when given
+ */
+export function formatUnits(
+  units: bigint,
+  decimals: number,
+  fractionDigits?: number,
+  rounding: Rounding = 'floor'
+): string {
+  if (units < 0n) {
+    return `-${formatUnits(-units, decimals, fractionDigits, rounding)}`;
+  }
+  const digits = fractionDigits ?? decimals;
+  const value = shiftDown(units, decimals - digits, rounding);
+  if (digits === 0) {
+    return value.toString();
+  }
+
+  const divisor = 10n ** BigInt(digits);
+  let fraction = (value % divisor).toString().padStart(digits, '0');
+  if (fractionDigits === undefined) {
+    fraction = fraction.replace(/0+$/, '');
+  }
+  return fraction ? `${value / divisor}.${fraction}` : `${value / divisor}`;
+}
+
+/**
+ * Rescale base units between tokens with different decimals, e.g. wei to motes
+ */
+export function convertDecimals(
+  units: bigint,
+  fromDecimals: number,
+  toDecimals: number,
+  rounding: Rounding = 'exact'
+): bigint {
+  return shiftDown(units, fromDecimals - toDecimals, rounding);
+}
+
+/**
+ * Parse human input such as "10.5 CSPR" or "250 USDC" against a token table
+ * A bare number is read as defaultSymbol when one is given
+ */
+export function parseAmount(
+  input: string,
+  tokens: Record<string, number> = DEFAULT_TOKEN_DECIMALS,
+  defaultSymbol?: string,
+  rounding: Rounding = 'exact'
+): ParsedAmount {
+  const match = /^\s*(\S+?)\s*([A-Za-z][A-Za-z0-9]*)?\s*$/.exec(input);
+  const symbol = (match?.[2] ?? defaultSymbol)?.toUpperCase();
+  if (!match || !symbol) {
+    throw new SyntaxError(`Invalid amount "${input}": expected "<number> <symbol>"`);
+  }
+  const table = Object.fromEntries(Object.entries(tokens).map(([key, decimals]) => [key.toUpperCase(), decimals]));
+  const decimals = table[symbol];
+  if (decimals === undefined) {
+    throw new RangeError(`Unknown token "${symbol}"`);
+  }
+  return { symbol, decimals, units: parseUnits(match[1], decimals, rounding) };
+}
+
+export const csprToMotes = (cspr: string, rounding: Rounding = 'exact'): bigint =>
+  parseUnits(cspr, CSPR_DECIMALS, rounding);
+
+export const motesToCspr = (motes: bigint | string, fractionDigits?: number): string =>
+  formatUnits(BigInt(motes), CSPR_DECIMALS, fractionDigits);
@@ -0,0 +1,151 @@
+/**
+ * Vault Config Check
+ *
+ * Compares what this relayer assumes about the vault (chains it relays,
+ * release digest version, bridge id, remote token addresses) with the vault's named
+ * keys. A relayer that disagrees would sign proofs the vault rejects, so it
+ * refuses to run until its configuration or the vault is fixed.
+ */
+
+import { blake2b } from '@noble/hashes/blake2b';
+import { CasperClient } from 'casper-js-sdk';
+
+// AssetId::Native serialized, the only asset the vault locks so far
+const NATIVE_ASSET_BYTES = Uint8Array.from([0]);
+
+export interface RelayerExpectations {
+  chains: string[]; // source/destination chains this relayer handles
+  digestVersion: number; // release digest version it signs
+  bridgeId: string; // vault identity it signs v5+ release messages for
+  tokens: Record<string, string>; // chain -> remote token address for native CSPR
+}
+
+export interface VaultConfigView {
+  supportedChains: string[];
+  digestVersion: number;
+  previousDigestVersion: number;
+  previousDigestDeadline: number; // ms; the previous version is accepted until then
+  bridgeId: string;
+  tokens: Record<string, string | null>; // per expected chain, null if unmapped
+  validators: string[]; // account hashes of active validators
+  requiredSignatures: number; // validator signatures a release needs
+  maxSignatures: number; // largest signature batch release_cspr accepts
+}
+
+export class StaleConfigError extends Error {
+  constructor(public readonly problems: string[]) {
+    super(`Relayer configuration disagrees with the vault:\n  - ${problems.join('\n  - ')}`);
+    this.name = 'StaleConfigError';
+  }
+}
+
+/**
+ * Dictionary item key of the vault's token_map for native CSPR on a chain:
+ * hex blake2b-256 of the serialized asset and chain name
+ */
+export function tokenMapItem(chain: string): string {
+  const name = new TextEncoder().encode(chain);
+  const length = new Uint8Array(4);
+  new DataView(length.buffer).setUint32(0, name.length, true);
+
+  const preimage = new Uint8Array(NATIVE_ASSET_BYTES.length + length.length + name.length);
+  preimage.set(NATIVE_ASSET_BYTES, 0);
+  preimage.set(length, NATIVE_ASSET_BYTES.length);
+  preimage.set(name, NATIVE_ASSET_BYTES.length + length.length);
+  return Buffer.from(blake2b(preimage, { dkLen: 32 })).toString('hex');
+}
+
+/**
+ * Read the settings relevant to expectations from the vault's named keys
+ */
+export async function readVaultConfig(
+  client: CasperClient,
+  vaultContract: string,
+  expected: RelayerExpectations
+): Promise<VaultConfigView> {
+  const stateRootHash = await client.nodeClient.getStateRootHash();
+  const readKey = async (name: string): Promise<any> => {
+    const stored = await client.nodeClient.getBlockState(stateRootHash, vaultContract, [name]);
+    return stored.CLValue!.value();
+  };
+
+  const tokens: Record<string, string | null> = {};
+  for (const chain of Object.keys(expected.tokens)) {
+    try {
+      const item = await client.nodeClient.getDictionaryItemByName(
+        stateRootHash,
+        vaultContract,
+        'token_map',
+        tokenMapItem(chain)
+      );
+      tokens[chain] = item.CLValue!.value();
+    } catch (error: any) {
+      // Missing dictionary items are reported as query errors
+      tokens[chain] = null;
+    }
+  }
+
+  return {
+    supportedChains: (await readKey('supported_chains')).map((chain: any) => chain.value()),
+    digestVersion: Number((await readKey('digest_version')).toString()),
+    previousDigestVersion: Number((await readKey('previous_digest_version')).toString()),
+    previousDigestDeadline: Number((await readKey('previous_digest_deadline')).toString()),
+    bridgeId: String(await readKey('bridge_id')),
+    tokens,
+    validators: (await readKey('validator_list')).map(
+      (key: any) => `account-hash-${Buffer.from(key.value().data).toString('hex')}`
+    ),
+    requiredSignatures: Number((await readKey('required_signatures')).toString()),
+    maxSignatures: Number((await readKey('max_signatures')).toString()),
+  };
+}
+
+/**
+ * List every way the vault disagrees with what the relayer expects
+ */
+export function compareVaultConfig(
+  vault: VaultConfigView,
+  expected: RelayerExpectations,
+  now: number = Date.now()
+): string[] {
+  const problems: string[] = [];
+
+  for (const chain of expected.chains) {
+    if (!vault.supportedChains.includes(chain)) {
+      problems.push(`chain "${chain}" is not supported by the vault (supports ${vault.supportedChains.join(', ')})`);
+    }
+  }
+
+  const previousAccepted = vault.previousDigestVersion === expected.digestVersion && now < vault.previousDigestDeadline;
+  if (vault.digestVersion !== expected.digestVersion && !previousAccepted) {
+    problems.push(`relayer signs digest version ${expected.digestVersion} but the vault accepts ${vault.digestVersion}`);
+  }
+  if (vault.bridgeId !== expected.bridgeId) {
+    problems.push(`relayer signs for bridge id ${expected.bridgeId} but the vault's is ${vault.bridgeId}`);
+  }
+
+  for (const [chain, token] of Object.entries(expected.tokens)) {
+    const mapped = vault.tokens[chain];
+    if (mapped && mapped.toLowerCase() !== token.toLowerCase()) {
+      problems.push(`vault maps native CSPR on ${chain} to ${mapped}, relayer uses ${token}`);
+    }
+  }
+
+  return problems;
+}
+
+/**
+ * Read the vault config and throw StaleConfigError on any disagreement
+ */
+export async function requireCurrentVaultConfig(
+  client: CasperClient,
+  vaultContract: string,
+  expected: RelayerExpectations
+): Promise<VaultConfigView> {
+  const vault = await readVaultConfig(client, vaultContract, expected);
+  const problems = compareVaultConfig(vault, expected);
+  if (problems.length > 0) {
+    throw new StaleConfigError(problems);
+  }
+  return vault;
+}
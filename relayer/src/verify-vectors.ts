@@ -0,0 +1,83 @@
+/**
+ * Conformance Vector Verifier
+ *
+ * Checks an implementation of the release proof format against published
+ * vectors: each vector's message bytes must match exactly, and replaying it
+ * under the file's rules and validator set must give the recorded outcome.
+ * Alternative relayers and the EVM side can port this check, or run it:
+ *
+ *   npm run verify-vectors -- [vectors.json]
+ *
+ * Defaults to vectors/release-vectors.json.
+ */
+
+import * as fs from 'fs';
+import { createReleaseMessage } from './signature-utils';
+import { ArchivedProof, replayProof } from './replay-tester';
+
+export interface ConformanceVector extends ArchivedProof {
+  name: string;
+  message_hex: string; // expected release message, hex
+}
+
+export interface ConformanceVectorFile {
+  description: string;
+  rules: { required_signatures: number; max_signatures: number };
+  validators: string[]; // hex Ed25519 public keys
+  vectors: ConformanceVector[];
+}
+
+/**
+ * Verify every vector, returning a description of each failure
+ */
+export function verifyVectors(file: ConformanceVectorFile): string[] {
+  const rules = {
+    requiredSignatures: file.rules.required_signatures,
+    maxSignatures: file.rules.max_signatures,
+    validators: new Set(file.validators.map((key) => key.toLowerCase())),
+  };
+  const failures: string[] = [];
+
+  for (const vector of file.vectors) {
+    const message = createReleaseMessage(
+      vector.source_chain,
+      vector.source_tx_hash,
+      vector.amount,
+      vector.recipient,
+      vector.nonce,
+      vector.log_index,
+      vector.digest_version ?? 1,
+      vector.source_sender,
+      vector.memo,
+      vector.source_block,
+      vector.bridge_id
+    );
+    if (Buffer.from(message).toString('hex') !== vector.message_hex) {
+      failures.push(`${vector.name}: message bytes differ`);
+      continue;
+    }
+
+    const result = replayProof(vector, rules);
+    if (result.accepted !== vector.accepted) {
+      failures.push(
+        `${vector.name}: expected ${vector.accepted ? 'accepted' : 'rejected'}, got ` +
+          `${result.accepted ? 'accepted' : `rejected (${result.reason})`}`
+      );
+    }
+  }
+
+  return failures;
+}
+
+if (require.main === module) {
+  const path = process.argv[2] ?? 'vectors/release-vectors.json';
+  const file = JSON.parse(fs.readFileSync(path, 'utf8')) as ConformanceVectorFile;
+  const failures = verifyVectors(file);
+
+  for (const failure of failures) {
+    console.log(failure);
+  }
+
+  console.log(`Verified ${file.vectors.length} vectors, ${failures.length} failed`);
+  process.exit(failures.length > 0 ? 1 : 0);
+}
@@ -0,0 +1,159 @@
+/**
+ * Watchtower
+ *
+ * Independently checks every release the vault executes against Ethereum:
+ * the proof's source transaction must contain an AssetBurned log from the
+ * wrapper contract with the same log index, nonce, amount and recipient,
+ * and the same burner and block where the proof carries them.
+ * When one does not, a guardian key halts the vault (set_operating_mode
+ * to Halted) or disputes the release if it was queued (dispute_release),
+ * within a block of detection.
+ *
+ * Safeguards: dry-run mode logs the action instead of submitting it, and at
+ * most maxActionsPerHour actions are submitted, so a bug here cannot spam
+ * the vault. Ethereum RPC failures are not treated as missing burns.
+ */
+
+import { Some } from 'ts-results';
+import { CasperClient, CLValueBuilder, DeployUtil, RuntimeArgs } from 'casper-js-sdk';
+import { Logger } from './logger';
+import { CasperSigner } from './signature-utils';
+import { ReleaseExecution } from './casper-monitor';
+import { submitDeploy, waitForDeploy } from './deploy-tracker';
+import { BurnEvidence } from './burn-evidence';
+import { ProofCache } from './proof-cache';
+
+const logger = Logger.getInstance();
+
+// Operating mode the vault treats as paused
+const MODE_HALTED = 2;
+
+export type WatchtowerAction = 'halt' | 'dispute';
+
+export interface WatchtowerConfig {
+  casperRpcUrl: string;
+  networkName: string;
+  vaultContract: string;
+  ethereumRpcUrl: string;
+  chainId: number;
+  wrapperContract: string;
+  guardianKeyHex: string; // registered with add_guardian
+  action: WatchtowerAction;
+  dryRun: boolean;
+  maxActionsPerHour: number;
+}
+
+export class Watchtower {
+  private config: WatchtowerConfig;
+  private evidence: BurnEvidence;
+  private casperClient: CasperClient;
+  private actionTimes: number[] = [];
+
+  constructor(config: WatchtowerConfig) {
+    this.config = config;
+    this.evidence = new BurnEvidence(config.ethereumRpcUrl, config.chainId, config.wrapperContract);
+    this.casperClient = new CasperClient(config.casperRpcUrl);
+  }
+
+  /**
+   * Apply reloaded non-key settings; the guardian key is fixed for the process lifetime
+   */
+  updateConfig(config: WatchtowerConfig): void {
+    this.config = { ...config, guardianKeyHex: this.config.guardianKeyHex };
+    this.evidence = new BurnEvidence(config.ethereumRpcUrl, config.chainId, config.wrapperContract);
+    this.casperClient = new CasperClient(config.casperRpcUrl);
+  }
+
+  /**
+   * Verify one executed release and respond if its burn does not exist
+   */
+  async checkRelease(release: ReleaseExecution): Promise<void> {
+    const transferId = ProofCache.releaseTransferId(release.sourceChain, release.nonce, release.logIndex);
+    const log = Logger.forTransfer(transferId);
+
+    if (release.sourceChain !== 'ethereum') {
+      log.warn('Watchtower cannot verify releases from this chain', { sourceChain: release.sourceChain });
+      return;
+    }
+
+    let mismatches: string[];
+    try {
+      mismatches = await this.evidence.mismatches(release);
+    } catch (error: any) {
+      log.warn('Watchtower could not read the source transaction, not acting', { error: error.message });
+      return;
+    }
+
+    if (mismatches.length === 0) {
+      log.debug('Watchtower verified release against its burn', { deployHash: release.deployHash });
+      return;
+    }
+
+    log.error('🚨 Release has no matching burn on Ethereum', { release, mismatches });
+    await this.respond(release, transferId);
+  }
+
+  private async respond(release: ReleaseExecution, transferId: string): Promise<void> {
+    const log = Logger.forTransfer(transferId);
+    const now = Date.now();
+    this.actionTimes = this.actionTimes.filter((time) => time > now - 60 * 60 * 1000);
+
+    const [entryPoint, args]: [string, RuntimeArgs] =
+      this.config.action === 'halt'
+        ? [
+            'set_operating_mode',
+            RuntimeArgs.fromMap({
+              mode: CLValueBuilder.u8(MODE_HALTED),
+              reason: CLValueBuilder.option(
+                Some(CLValueBuilder.string(`watchtower: release ${transferId} has no matching burn`))
+              ),
+            }),
+          ]
+        : [
+            'dispute_release',
+            RuntimeArgs.fromMap({
+              source_chain: CLValueBuilder.string(release.sourceChain),
+              nonce: CLValueBuilder.u64(release.nonce),
+              log_index: CLValueBuilder.u32(release.logIndex),
+            }),
+          ];
+
+    if (this.config.dryRun) {
+      log.warn('Watchtower dry run, not submitting', { entryPoint });
+      return;
+    }
+    if (this.actionTimes.length >= this.config.maxActionsPerHour) {
+      log.error('Watchtower action limit reached, not submitting', {
+        entryPoint,
+        maxActionsPerHour: this.config.maxActionsPerHour,
+      });
+      return;
+    }
+    this.actionTimes.push(now);
+
+    try {
+      const guardian = new CasperSigner(this.config.guardianKeyHex);
+      const session = DeployUtil.ExecutableDeployItem.newStoredContractByHash(
+        Uint8Array.from(Buffer.from(this.config.vaultContract.replace('hash-', ''), 'hex')),
+        entryPoint,
+        args
+      );
+      const deploy = DeployUtil.makeDeploy(
+        new DeployUtil.DeployParams(guardian.getPublicKeyCL(), this.config.networkName, 1, 1800000),
+        session,
+        DeployUtil.standardPayment(3_000_000_000)
+      );
+
+      const deployHash = await submitDeploy(this.casperClient, DeployUtil.signDeploy(deploy, guardian.getKeyPair()));
+      log.warn('Watchtower submitted guardian action', { entryPoint, deployHash });
+
+      const outcome = await waitForDeploy(this.casperClient, deployHash);
+      if (!outcome.success) {
+        throw outcome.error!;
+      }
+      log.warn('Watchtower guardian action executed', { entryPoint, deployHash });
+    } catch (error: any) {
+      log.error('Watchtower guardian action failed', { entryPoint, error: error.message });
+    }
+  }
+}